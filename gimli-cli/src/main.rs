@@ -0,0 +1,180 @@
+use gimli_rs::{derive_key_into, GimliHasher};
+use gimli_rs::gimli_common::Key;
+use gimli_rs::gimli_stream::{GimliDecryptReader, GimliEncryptWriter, NONCE_PREFIX_SIZE};
+use structopt::StructOpt;
+use structopt::clap::arg_enum;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::io::prelude::*;
+use rand::prelude::*;
+use std::process;
+use zeroize::Zeroize;
+
+// Context for deriving the file encryption key from the passphrase. Changing it changes every key.
+const KEY_CONTEXT: &str = "gimli_rs cli 2026-10-18 file encryption key";
+
+arg_enum! {
+    #[derive(Debug)]
+    enum GimliMode {
+        Hash,
+        Encrypt,
+        Decrypt,
+    }
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Gimli-rs",
+    about = "An implementation of the gimli cipher with hashing and AEAD functionality"
+)]
+struct Opt {
+    /// Input.
+    #[structopt(
+        short = "i",
+        long = "input"
+        )]
+    input: String,
+
+    /// Enable to parse input as a file path
+    #[structopt(
+        short = "f",
+        long = "is_file"
+        )]
+    is_file: bool,
+
+    /// Mode of operation.
+    #[structopt(
+        short = "m",
+        long = "mode",
+        possible_values = &GimliMode::variants(),
+        default_value = "hash",
+        case_insensitive = true
+        )]
+    mode: GimliMode,
+
+    /// Output. Defaults to std out.
+    #[structopt(
+        short = "o",
+        long = "out",
+        )]
+    output: Option<String>,
+
+    /// Crypto Key.
+    #[structopt(
+        short = "k",
+        long = "key",
+        required_if("mode", "encrypt"),
+        required_if("mode", "decrypt"),
+        default_value = "",
+        )]
+    key: String,
+
+        /// Associated data.
+    #[structopt(
+        short = "a",
+        long = "associated_data",
+        required_if("mode", "encrypt"),
+        required_if("mode", "decrypt"),
+        default_value = "",
+        )]
+    ad: String,
+
+    /// Hash length. Required for Hash mode.
+    #[structopt(
+        short = "l",
+        long = "length",
+        default_value = "32",
+        requires_if("mode", "hash"),
+        )]
+    out_length: u64,
+}
+
+
+
+
+fn main() {
+    let mut opt = Opt::from_args();
+
+    let input: Box<dyn Read> = match opt.is_file {
+        true => Box::new(BufReader::new(File::open(&opt.input).expect("Error opening input file."))),
+        false => Box::new(io::Cursor::new(opt.input.clone().into_bytes())),
+    };
+    let output: Box<dyn Write> = match &opt.output {
+        Some(file_path) => Box::new(BufWriter::new(File::create(file_path).expect("Failed to open output file"))),
+        None => Box::new(HexWriter{ writer: io::stdout() }),
+    };
+
+    match opt.mode {
+        GimliMode::Hash => {
+            hash(input, output, opt.out_length as usize).unwrap_or_else(|e| exit_with_error(e));
+        },
+        GimliMode::Encrypt => {
+            let key = derive_key(&mut opt.key);
+            encrypt(input, output, &key, opt.ad.as_bytes()).unwrap_or_else(|e| exit_with_error(e));
+        },
+        GimliMode::Decrypt => {
+            let key = derive_key(&mut opt.key);
+            decrypt(input, output, &key, opt.ad.as_bytes()).unwrap_or_else(|e| exit_with_error(e));
+        },
+    }
+
+    // Scrubs the passphrase once the key has been derived from it.
+    fn derive_key(passphrase: &mut String) -> Key{
+        let mut key_array = [0; 32];
+        derive_key_into(KEY_CONTEXT, passphrase.as_bytes(), &mut key_array);
+        passphrase.zeroize();
+        let key = Key::new(key_array);
+        key_array.zeroize();
+        key
+    }
+
+    fn hash(mut input: Box<dyn Read>, mut output: Box<dyn Write>, out_length: usize) -> io::Result<()>{
+        let mut hasher = GimliHasher::new();
+        io::copy(&mut input, &mut hasher)?;
+        output.write_all(&hasher.finalize(out_length))?;
+        output.flush()
+    }
+
+    fn encrypt(mut input: Box<dyn Read>, mut output: Box<dyn Write>, key: &Key, ad: &[u8]) -> io::Result<()>{
+        let mut rng = rand::thread_rng();
+        let mut nonce_prefix = [0u8; NONCE_PREFIX_SIZE];
+        rng.fill_bytes(&mut nonce_prefix);
+        output.write_all(&nonce_prefix)?;
+        let mut writer = GimliEncryptWriter::new(output, key, &nonce_prefix, ad);
+        io::copy(&mut input, &mut writer)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    fn decrypt(mut input: Box<dyn Read>, mut output: Box<dyn Write>, key: &Key, ad: &[u8]) -> io::Result<()>{
+        let mut nonce_prefix = [0; NONCE_PREFIX_SIZE];
+        input.read_exact(&mut nonce_prefix)?;
+        let mut reader = GimliDecryptReader::new(input, key, &nonce_prefix, ad);
+        io::copy(&mut reader, &mut output)?;
+        output.flush()
+    }
+
+    fn exit_with_error(e: io::Error) -> ! {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+
+}
+
+// Prints everything written to it as hex.
+struct HexWriter<W: Write>{
+    writer: W,
+}
+
+impl<W: Write> Write for HexWriter<W>{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>{
+        for byte in buf.iter(){
+            write!(self.writer, "{:02x?}", byte)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()>{
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::gimli_common;
+use crate::gimli_decrypt::decrypt_in_place_detached;
+use crate::gimli_encrypt::encrypt_in_place_detached;
+use aead::consts::{U0, U16, U32};
+use aead::{AeadCore, AeadInPlace, Key, KeyInit, KeySizeUser, Nonce, Tag};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Gimli AEAD with a 256 bit key, 128 bit nonce and 128 bit tag, usable through the RustCrypto `aead` traits.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct GimliCipher{
+    key: gimli_common::Key,
+}
+
+impl KeySizeUser for GimliCipher{
+    type KeySize = U32;
+}
+
+impl KeyInit for GimliCipher{
+    fn new(key: &Key<Self>) -> Self{
+        let mut key_array = [0; 32];
+        key_array.copy_from_slice(key);
+        let cipher = GimliCipher{
+            key: key_array.into(),
+        };
+        key_array.zeroize();
+        cipher
+    }
+}
+
+impl AeadCore for GimliCipher{
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+// The aead crate's sizes are fixed by the type parameters above, so the conversions cannot fail.
+impl AeadInPlace for GimliCipher{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> aead::Result<Tag<Self>>{
+        let nonce = gimli_common::Nonce::from_slice(nonce).map_err(|_| aead::Error)?;
+        let tag = encrypt_in_place_detached(&self.key, &nonce, associated_data, buffer);
+        Ok((*tag.as_bytes()).into())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> aead::Result<()>{
+        let nonce = gimli_common::Nonce::from_slice(nonce).map_err(|_| aead::Error)?;
+        let tag = gimli_common::Tag::from_slice(tag).map_err(|_| aead::Error)?;
+        decrypt_in_place_detached(&self.key, &nonce, associated_data, buffer, &tag)
+            .map_err(|_| aead::Error)
+    }
+}
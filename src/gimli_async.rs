@@ -0,0 +1,164 @@
+use crate::gimli_common::Key;
+use crate::gimli_stream::{GimliStreamDecryptor, GimliStreamEncryptor, NONCE_PREFIX_SIZE, SEGMENT_SIZE};
+use std::cmp::min;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use zeroize::Zeroizing;
+
+/// Async counterpart of `GimliEncryptWriter`. The last segment is written on `shutdown`;
+/// a stream that is never shut down fails to decrypt.
+pub struct GimliAsyncEncryptWriter<W: AsyncWrite + Unpin>{
+    writer: W,
+    encryptor: GimliStreamEncryptor,
+    associated_data: Vec<u8>,
+    buffer: Zeroizing<Vec<u8>>,
+    pending: Zeroizing<Vec<u8>>,
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin> GimliAsyncEncryptWriter<W>{
+    pub fn new(writer: W, key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], associated_data: &[u8]) -> Self{
+        GimliAsyncEncryptWriter{
+            writer,
+            encryptor: GimliStreamEncryptor::new(key, nonce_prefix),
+            associated_data: associated_data.to_vec(),
+            buffer: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16)),
+            pending: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16)),
+            written: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W{
+        self.writer
+    }
+
+    fn seal_segment(&mut self, last: bool) -> io::Result<()>{
+        self.encryptor.seal_buffered(&mut self.buffer, &self.associated_data, last)?;
+        std::mem::swap(&mut self.buffer, &mut self.pending);
+        self.buffer.clear();
+        self.written = 0;
+        Ok(())
+    }
+
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>{
+        while self.written < self.pending.len() {
+            let n = ready!(Pin::new(&mut self.writer).poll_write(cx, &self.pending[self.written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()))
+            }
+            self.written += n;
+        }
+        self.pending.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for GimliAsyncEncryptWriter<W>{
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>>{
+        let this = &mut *self;
+        ready!(this.poll_pending(cx))?;
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0))
+        }
+        // A full segment is only sealed once more data arrives, as it may still turn out to be the last one.
+        if this.buffer.len() == SEGMENT_SIZE {
+            this.seal_segment(false)?;
+        }
+        let take = min(buf.len(), SEGMENT_SIZE - this.buffer.len());
+        this.buffer.extend_from_slice(&buf[..take]);
+        Poll::Ready(Ok(take))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>{
+        let this = &mut *self;
+        ready!(this.poll_pending(cx))?;
+        Pin::new(&mut this.writer).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>{
+        let this = &mut *self;
+        ready!(this.poll_pending(cx))?;
+        if !this.encryptor.complete {
+            this.seal_segment(true)?;
+            ready!(this.poll_pending(cx))?;
+        }
+        Pin::new(&mut this.writer).poll_shutdown(cx)
+    }
+}
+
+/// Async counterpart of `GimliDecryptReader`. Plain text is only returned once its segment has been verified.
+pub struct GimliAsyncDecryptReader<R: AsyncRead + Unpin>{
+    reader: R,
+    decryptor: GimliStreamDecryptor,
+    associated_data: Vec<u8>,
+    input: Vec<u8>,
+    output: Zeroizing<Vec<u8>>,
+    position: usize,
+    eof: bool,
+    failed: Option<io::ErrorKind>,
+}
+
+impl<R: AsyncRead + Unpin> GimliAsyncDecryptReader<R>{
+    pub fn new(reader: R, key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], associated_data: &[u8]) -> Self{
+        GimliAsyncDecryptReader{
+            reader,
+            decryptor: GimliStreamDecryptor::new(key, nonce_prefix),
+            associated_data: associated_data.to_vec(),
+            input: Vec::with_capacity(SEGMENT_SIZE + 17),
+            output: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE)),
+            position: 0,
+            eof: false,
+            failed: None,
+        }
+    }
+
+    pub fn into_inner(self) -> R{
+        self.reader
+    }
+
+    // Reading one byte past the segment tells us whether it is the last one. Bytes read before an
+    // error stay in `input`, so the caller may retry.
+    fn poll_fill(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>{
+        let mut chunk = [0; 1024];
+        while !self.eof && self.input.len() <= SEGMENT_SIZE + 16 {
+            let wanted = min(chunk.len(), SEGMENT_SIZE + 17 - self.input.len());
+            let mut read_buf = ReadBuf::new(&mut chunk[..wanted]);
+            ready!(Pin::new(&mut self.reader).poll_read(cx, &mut read_buf))?;
+            match read_buf.filled().len() {
+                0 => self.eof = true,
+                _ => self.input.extend_from_slice(read_buf.filled()),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for GimliAsyncDecryptReader<R>{
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>>{
+        let this = &mut *self;
+        if let Some(kind) = this.failed {
+            return Poll::Ready(Err(io::Error::new(kind, "Stream decryption already failed")))
+        }
+        while this.position == this.output.len() {
+            if this.decryptor.complete {
+                return Poll::Ready(Ok(()))
+            }
+            ready!(this.poll_fill(cx))?;
+            this.position = 0;
+            // Authentication and format errors end the stream; errors from the inner reader do not.
+            if let Err(e) = this.decryptor.open_buffered(&mut this.input, &mut this.output, &this.associated_data) {
+                let e: io::Error = e.into();
+                this.failed = Some(e.kind());
+                this.output.clear();
+                return Poll::Ready(Err(e))
+            }
+        }
+        let take = min(buf.remaining(), this.output.len() - this.position);
+        buf.put_slice(&this.output[this.position..this.position + take]);
+        this.position += take;
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,20 +1,360 @@
-fn rotate(x: u32, bits: usize) -> u32 {
-    if bits == 0 {
-        return x;
-    };
-    return (x << bits) | (x >> (32 - bits));
+use crate::gimli_error::GimliError;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+fn array_from_slice<const N: usize>(bytes: &[u8]) -> Result<[u8; N], GimliError> {
+    let mut array = [0; N];
+    if bytes.len() != N {
+        return Err(GimliError::InvalidLength { expected: N, actual: bytes.len() })
+    }
+    array.copy_from_slice(bytes);
+    Ok(array)
+}
+
+fn array_from_hex<const N: usize>(hex: &str) -> Result<[u8; N], GimliError> {
+    fn nibble(c: u8) -> Result<u8, GimliError> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(GimliError::InvalidHex),
+        }
+    }
+    let hex = hex.as_bytes();
+    if hex.len() != 2 * N {
+        return Err(GimliError::InvalidHexLength { expected: 2 * N, actual: hex.len() })
+    }
+    let mut array = [0; N];
+    for (byte, pair) in array.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+    }
+    Ok(array)
+}
+
+fn write_hex(f: &mut fmt::Formatter, name: &str, bytes: &[u8]) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+    write!(f, ")")
+}
+
+/// 256 bit AEAD key. Wiped from memory when dropped and never shown by `Debug`.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Key([u8; 32]);
+
+impl Key{
+    pub const fn new(bytes: [u8; 32]) -> Self{
+        Key(bytes)
+    }
+
+    /// Fails unless `bytes` is exactly 32 bytes long.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, GimliError>{
+        array_from_slice(bytes).map(Key)
+    }
+
+    /// Parses 64 hex digits.
+    pub fn from_hex(hex: &str) -> Result<Self, GimliError>{
+        array_from_hex(hex).map(Key)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32]{
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Key{
+    fn from(bytes: [u8; 32]) -> Self{
+        Key(bytes)
+    }
+}
+
+impl fmt::Debug for Key{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "Key(<redacted>)")
+    }
+}
+
+/// 128 bit AEAD nonce. Wiped from memory when dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Nonce([u8; 16]);
+
+impl Nonce{
+    pub const fn new(bytes: [u8; 16]) -> Self{
+        Nonce(bytes)
+    }
+
+    /// Fails unless `bytes` is exactly 16 bytes long.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, GimliError>{
+        array_from_slice(bytes).map(Nonce)
+    }
+
+    /// Parses 32 hex digits.
+    pub fn from_hex(hex: &str) -> Result<Self, GimliError>{
+        array_from_hex(hex).map(Nonce)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16]{
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Nonce{
+    fn from(bytes: [u8; 16]) -> Self{
+        Nonce(bytes)
+    }
+}
+
+impl fmt::Debug for Nonce{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write_hex(f, "Nonce", &self.0)
+    }
+}
+
+/// Authentication tag produced by the gimli AEAD. Compared in constant time.
+#[derive(Clone, Copy)]
+pub struct Tag([u8; 16]);
+
+impl Tag{
+    pub const fn new(bytes: [u8; 16]) -> Self{
+        Tag(bytes)
+    }
+
+    /// Fails unless `bytes` is exactly 16 bytes long.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, GimliError>{
+        array_from_slice(bytes).map(Tag)
+    }
+
+    /// Parses 32 hex digits.
+    pub fn from_hex(hex: &str) -> Result<Self, GimliError>{
+        array_from_hex(hex).map(Tag)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16]{
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Tag{
+    fn from(bytes: [u8; 16]) -> Self{
+        Tag(bytes)
+    }
+}
+
+impl PartialEq for Tag{
+    fn eq(&self, other: &Self) -> bool{
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for Tag{}
+
+impl fmt::Debug for Tag{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write_hex(f, "Tag", &self.0)
+    }
+}
+
+/// The 384 bit gimli state. Byte `i` of the state is byte `i % 4` of word `i / 4` in little endian order,
+/// whatever the byte order of the host. Keyed states hold secrets, so the state is wiped when dropped.
+#[derive(Clone, Default, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct GimliState{
+    words: [u32; 12],
+}
+
+impl GimliState{
+    pub fn new() -> Self{
+        GimliState{
+            words: [0; 12],
+        }
+    }
+
+    pub fn from_words(words: [u32; 12]) -> Self{
+        GimliState{
+            words,
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8; 48]) -> Self{
+        let mut state = GimliState::new();
+        state.xor_bytes(0, bytes);
+        state
+    }
+
+    pub fn words(&self) -> &[u32; 12]{
+        &self.words
+    }
+
+    pub fn to_bytes(&self) -> [u8; 48]{
+        let mut bytes = [0; 48];
+        self.extract_bytes(0, &mut bytes);
+        bytes
+    }
+
+    pub fn permute(&mut self){
+        gimli(&mut self.words);
+    }
+
+    /// Mutable access to the words, for running other permutations on the state.
+    pub fn words_mut(&mut self) -> &mut [u32; 12]{
+        &mut self.words
+    }
+
+    pub fn byte(&self, index: usize) -> u8{
+        (self.words[index / 4] >> (8 * (index % 4))) as u8
+    }
+
+    pub fn xor_byte(&mut self, index: usize, value: u8){
+        self.words[index / 4] ^= (value as u32) << (8 * (index % 4));
+    }
+
+    /// XORs `data` into the state bytes starting at `offset`.
+    pub fn xor_bytes(&mut self, offset: usize, data: &[u8]){
+        let mut done = 0;
+        if offset % 4 == 0 {
+            for (word, chunk) in self.words[offset / 4..].iter_mut().zip(data.chunks_exact(4)) {
+                *word ^= u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                done += 4;
+            }
+        }
+        for (i, byte) in data.iter().enumerate().skip(done) {
+            self.xor_byte(offset + i, *byte);
+        }
+    }
+
+    /// Copies the state bytes starting at `offset` into `output`.
+    pub fn extract_bytes(&self, offset: usize, output: &mut [u8]){
+        let mut done = 0;
+        if offset % 4 == 0 {
+            for (word, chunk) in self.words[offset / 4..].iter().zip(output.chunks_exact_mut(4)) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+                done += 4;
+            }
+        }
+        for (i, byte) in output.iter_mut().enumerate().skip(done) {
+            *byte = self.byte(offset + i);
+        }
+    }
+
+    /// Duplex encryption of `buffer` against the state bytes starting at `offset`: the plain text is absorbed and
+    /// replaced by the cipher text.
+    pub fn encrypt_bytes(&mut self, offset: usize, buffer: &mut [u8]){
+        let mut done = 0;
+        if offset % 4 == 0 {
+            for (word, chunk) in self.words[offset / 4..].iter_mut().zip(buffer.chunks_exact_mut(4)) {
+                *word ^= u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                chunk.copy_from_slice(&word.to_le_bytes());
+                done += 4;
+            }
+        }
+        for (i, byte) in buffer.iter_mut().enumerate().skip(done) {
+            self.xor_byte(offset + i, *byte);
+            *byte = self.byte(offset + i);
+        }
+    }
+
+    /// Duplex decryption of `buffer` against the state bytes starting at `offset`: the cipher text replaces those
+    /// bytes and is replaced by the plain text.
+    pub fn decrypt_bytes(&mut self, offset: usize, buffer: &mut [u8]){
+        let mut done = 0;
+        if offset % 4 == 0 {
+            for (word, chunk) in self.words[offset / 4..].iter_mut().zip(buffer.chunks_exact_mut(4)) {
+                let cipher_text = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                chunk.copy_from_slice(&(cipher_text ^ *word).to_le_bytes());
+                *word = cipher_text;
+                done += 4;
+            }
+        }
+        for (i, byte) in buffer.iter_mut().enumerate().skip(done) {
+            *byte ^= self.byte(offset + i);
+            self.xor_byte(offset + i, *byte);
+        }
+    }
 }
 
+// Without std there is no runtime detection, so AVX2 is only used when the build targets it.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+/// The gimli permutation. Uses the fastest implementation the CPU supports.
 pub fn gimli(state: &mut [u32; 12]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { crate::gimli_simd::gimli_avx2(state) }
+        }
+        return unsafe { crate::gimli_simd::gimli_sse2(state) }
+    }
+    #[allow(unreachable_code)]
+    gimli_scalar(state)
+}
+
+/// Permutes two independent states. Interleaved on AVX2 CPUs.
+pub fn gimli_x2(states: &mut [[u32; 12]; 2]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { crate::gimli_simd::gimli_x2_avx2(states) }
+        }
+    }
+    states.iter_mut().for_each(gimli);
+}
+
+/// Permutes four independent states. Interleaved on AVX2 CPUs.
+pub fn gimli_x4(states: &mut [[u32; 12]; 4]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { crate::gimli_simd::gimli_x4_avx2(states) }
+        }
+    }
+    states.iter_mut().for_each(gimli);
+}
+
+/// Permutes eight independent states. Interleaved on AVX2 CPUs.
+pub fn gimli_x8(states: &mut [[u32; 12]; 8]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { crate::gimli_simd::gimli_x8_avx2(states) }
+        }
+    }
+    states.iter_mut().for_each(gimli);
+}
+
+/// Portable implementation of the gimli permutation.
+pub fn gimli_scalar(state: &mut [u32; 12]) {
+    gimli_rounds(state, 24, 1);
+}
+
+/// Applies rounds `start_round` down to `end_round` of the permutation, both inclusive.
+/// `gimli_rounds(state, 24, 1)` is the full permutation; splitting the range, as in
+/// `gimli_rounds(state, 24, 13)` followed by `gimli_rounds(state, 12, 1)`, gives the same result.
+/// Panics unless `24 >= start_round >= end_round >= 1`.
+pub fn gimli_rounds(state: &mut [u32; 12], start_round: u32, end_round: u32) {
+    assert!(start_round <= 24 && start_round >= end_round && end_round >= 1, "gimli rounds run from start_round down to end_round, within 24 down to 1");
     //12*32bit = 384bit
     let mut x;
     let mut y;
     let mut z;
 
-    for round in (1..=24).rev() {
+    for round in (end_round..=start_round).rev() {
         for column in 0..=3 {
-            x = rotate(state[column], 24);
-            y = rotate(state[4 + column], 9);
+            x = state[column].rotate_left(24);
+            y = state[4 + column].rotate_left(9);
             z = state[8 + column];
 
             state[8 + column] = x ^ (z << 1) ^ ((y & z) << 2);
@@ -46,4 +386,100 @@ pub fn gimli(state: &mut [u32; 12]) {
             state[0] ^= 0x9e377900 | round;
         }
     }
-}
\ No newline at end of file
+}
+
+/// Inverse of the gimli permutation: `gimli_inverse` undoes `gimli` and the other way round.
+pub fn gimli_inverse(state: &mut [u32; 12]) {
+    gimli_inverse_rounds(state, 24, 1);
+}
+
+/// Undoes rounds `start_round` down to `end_round` of the permutation, applying their inverses from `end_round` up.
+/// Panics unless `24 >= start_round >= end_round >= 1`.
+pub fn gimli_inverse_rounds(state: &mut [u32; 12], start_round: u32, end_round: u32) {
+    assert!(start_round <= 24 && start_round >= end_round && end_round >= 1, "gimli rounds run from start_round down to end_round, within 24 down to 1");
+    for round in end_round..=start_round {
+        if (round & 3) == 0 {
+            state[0] ^= 0x9e377900 | round;
+        }
+        if (round & 3) == 2 {
+            state.swap(0, 2);
+            state.swap(1, 3);
+        }
+        if (round & 3) == 0 {
+            state.swap(0, 1);
+            state.swap(2, 3);
+        }
+
+        for column in 0..=3 {
+            // Every output bit depends only on input bits of the same or lower position, so each
+            // pass over the SP-box equations fixes at least one more bit, from the bottom up.
+            let (x_out, y_out, z_out) = (state[column], state[4 + column], state[8 + column]);
+            let (mut x, mut y, mut z) = (0u32, 0u32, 0u32);
+            for _ in 0..32 {
+                x = z_out ^ (z << 1) ^ ((y & z) << 2);
+                y = y_out ^ x ^ ((x | z) << 1);
+                z = x_out ^ y ^ ((x & y) << 3);
+            }
+            state[column] = x.rotate_right(24);
+            state[4 + column] = y.rotate_right(9);
+            state[8 + column] = z;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn next_byte<I>(input: &mut I, expected: usize, read: usize) -> Result<u8, GimliError>
+where
+    I: Iterator<Item = Result<u8, io::Error>> + ?Sized,
+{
+    match input.next() {
+        Some(byte) => Ok(byte?),
+        None => Err(GimliError::LengthMismatch { expected, actual: read }),
+    }
+}
+
+/// Compares `expected` and `received` in constant time. Unless they match, `plain_text` is zeroed and
+/// `TagMismatch` returned. Every tag check goes through here.
+pub(crate) fn verify_tag(expected: &[u8], received: &[u8], plain_text: &mut [u8]) -> Result<(), GimliError> {
+    let valid = expected.ct_eq(received);
+    for byte in plain_text.iter_mut() {
+        byte.conditional_assign(&0, !valid);
+    }
+    match bool::from(valid) {
+        true => Ok(()),
+        false => Err(GimliError::TagMismatch),
+    }
+}
+
+/// Shortest variable length tag `GimliMac` and `GimliStrobe` accept. Shorter tags are cheap to guess.
+pub const MIN_TAG_SIZE: usize = 16;
+
+/// Checks a tag of any length, 16 bytes at a time. `expected` is handed a zeroed buffer for each chunk of the tag
+/// and fills it with the expected bytes. Every chunk goes through `verify_tag` and the expected bytes are wiped
+/// afterwards. Tags shorter than `MIN_TAG_SIZE` never verify, so whoever sends the tag can not shorten it.
+pub(crate) fn verify_tag_chunks(tag: &[u8], mut expected: impl FnMut(&mut [u8])) -> Result<(), GimliError> {
+    let mut block = [0; 16];
+    let mut result = match tag.len() < MIN_TAG_SIZE {
+        true => Err(GimliError::TagMismatch),
+        false => Ok(()),
+    };
+    for chunk in tag.chunks(block.len()) {
+        let block = &mut block[..chunk.len()];
+        block.fill(0);
+        expected(block);
+        result = result.and(verify_tag(block, chunk, &mut []));
+    }
+    block.zeroize();
+    result
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn read_block<I>(input: &mut I, block: &mut [u8], expected: usize, read: usize) -> Result<(), GimliError>
+where
+    I: Iterator<Item = Result<u8, io::Error>> + ?Sized,
+{
+    for (i, b) in block.iter_mut().enumerate() {
+        *b = next_byte(input, expected, read + i)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Gimli hash and AEAD with a reduced number of rounds, for reproducing attacks on round-reduced gimli.
+//! Nothing in this module is secure. It is only compiled with the `cryptanalysis` feature, and that feature
+//! refuses to build unless `--cfg gimli_cryptanalysis` is also passed to rustc, for example with
+//! `RUSTFLAGS="--cfg gimli_cryptanalysis" cargo build --features cryptanalysis`.
+
+use crate::gimli_common::{gimli_rounds, verify_tag, GimliState, Key, Nonce};
+use crate::gimli_duplex::{aead_init, GimliDuplex, GimliPadding, GimliPermutation};
+use crate::gimli_error::GimliError;
+use alloc::{vec, vec::Vec};
+
+/// Rounds `start_round` down to `end_round` of the gimli permutation, for use in a `GimliDuplex`.
+/// Permuting panics unless `24 >= start_round >= end_round >= 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReducedRounds{
+    pub start_round: u32,
+    pub end_round: u32,
+}
+
+impl GimliPermutation for ReducedRounds{
+    fn permute(&self, state: &mut GimliState){
+        gimli_rounds(state.words_mut(), self.start_round, self.end_round);
+    }
+}
+
+/// Gimli-Hash where every permutation runs rounds `start_round` down to `end_round`.
+pub fn reduced_round_hash(input: &[u8], output_len: usize, start_round: u32, end_round: u32) -> Vec<u8>{
+    let mut duplex = GimliDuplex::with_permutation(ReducedRounds{ start_round, end_round });
+    duplex.absorb(input);
+    duplex.pad(GimliPadding::HASH);
+    let mut output = vec![0; output_len];
+    duplex.squeeze(&mut output);
+    output
+}
+
+/// Gimli AEAD encryption where every permutation runs rounds `start_round` down to `end_round`.
+/// Returns the cipher text followed by the 16 byte tag.
+pub fn reduced_round_aead_encrypt(key: &Key, nonce: &Nonce, associated_data: &[u8], message: &[u8], start_round: u32, end_round: u32) -> Vec<u8>{
+    let mut duplex = aead_init(key, nonce, associated_data, ReducedRounds{ start_round, end_round });
+    let mut output = message.to_vec();
+    duplex.encrypt(&mut output);
+    duplex.pad(GimliPadding::AEAD);
+
+    let mut tag = [0; 16];
+    duplex.squeeze(&mut tag);
+    output.extend_from_slice(&tag);
+    output
+}
+
+/// Gimli AEAD decryption where every permutation runs rounds `start_round` down to `end_round`.
+pub fn reduced_round_aead_decrypt(key: &Key, nonce: &Nonce, associated_data: &[u8], cipher_text: &[u8], start_round: u32, end_round: u32) -> Result<Vec<u8>, GimliError>{
+    if cipher_text.len() < 16 {
+        return Err(GimliError::TruncatedCiphertext)
+    }
+    let (cipher_text, tag) = cipher_text.split_at(cipher_text.len() - 16);
+    let mut duplex = aead_init(key, nonce, associated_data, ReducedRounds{ start_round, end_round });
+    let mut output = cipher_text.to_vec();
+    duplex.decrypt(&mut output);
+    duplex.pad(GimliPadding::AEAD);
+
+    let mut expected = [0; 16];
+    duplex.squeeze(&mut expected);
+    verify_tag(&expected, tag, &mut output)?;
+    Ok(output)
+}
@@ -1,181 +1,141 @@
-use crate::gimli_common::gimli;
+use crate::gimli_common::{verify_tag, Key, Nonce, Tag};
+use crate::gimli_duplex::{aead_init, Gimli, GimliDuplex, GimliPadding};
+#[cfg(feature = "std")]
+use crate::gimli_common::read_block;
+use crate::gimli_error::GimliError;
+#[cfg(feature = "std")]
 use std::io;
+use zeroize::Zeroize;
 
+/// Block level AEAD decryption. Whole 16 byte blocks may be decrypted as they arrive, the tail is decrypted and
+/// the tag checked by `finalize`. No decrypted block may be released before `finalize` succeeds.
+pub struct GimliAeadDecryptor{
+    duplex: GimliDuplex,
+    decrypted: usize,
+}
+
+impl GimliAeadDecryptor{
+    pub fn new(key: &Key, nonce: &Nonce, associated_data: &[u8]) -> Self{
+        GimliAeadDecryptor{
+            duplex: aead_init(key, nonce, associated_data, Gimli),
+            decrypted: 0,
+        }
+    }
+
+    /// Decrypts `blocks` in place. Panics if the length is not a multiple of 16.
+    pub fn decrypt_blocks(&mut self, blocks: &mut [u8]){
+        assert_eq!(blocks.len() % 16, 0, "decrypt_blocks requires whole 16 byte blocks");
+        self.duplex.decrypt(blocks);
+        self.decrypted += blocks.len();
+    }
+
+    /// Takes the whole message, whose leading bytes were already passed through `decrypt_blocks`. Decrypts the
+    /// rest in place and checks `tag`. On failure all of `message` is zeroed, including the earlier blocks.
+    /// Panics if `message` is shorter than what `decrypt_blocks` has seen.
+    pub fn finalize(mut self, message: &mut [u8], tag: &Tag) -> Result<(), GimliError>{
+        assert!(message.len() >= self.decrypted, "finalize requires the whole message");
+        self.duplex.decrypt(&mut message[self.decrypted..]);
+        self.duplex.pad(GimliPadding::AEAD);
+
+        let mut expected = [0; 16];
+        self.duplex.squeeze(&mut expected);
+        let result = verify_tag(&expected, tag.as_bytes(), message);
+        expected.zeroize();
+        result
+    }
+}
+
+/// Decrypts a cipher text iterator. No plain text is yielded until the tag of the whole message has been verified.
+#[cfg(feature = "std")]
 pub struct GimliAeadDecryptIter{
-    state: [u32; 12],
-    cipher_message_len: usize,
+    decryptor: Option<GimliAeadDecryptor>,
+    cipher_text_len: usize,
     cipher_message: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
-    output_buffer: Vec<u8>,
+    output: Vec<u8>,
+    position: usize,
 }
 
+#[cfg(feature = "std")]
 impl GimliAeadDecryptIter{
-    pub fn new(key: [u8; 32],
-               nonce: [u8; 16],
-               cipher_text_len: usize,
+    pub fn new(key: &Key,
+               nonce: &Nonce,
+               associated_data: &[u8],
                cipher_text: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
-               mut associated_data: &[u8]) -> Self{
-
-        let message_len = cipher_text_len - 16;
-        let mut state: [u32; 12] = [0; 12];
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-
-        // Init state with key and nonce plus first permute
-        state_8[..16].clone_from_slice(&nonce);
-        state_8[16..48].clone_from_slice(&key);
-        gimli(&mut state);
-
-        // Handle associated data
-        while associated_data.len() >= 16 {
-            let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-            for i in 0..16 {
-                state_8[i] ^= associated_data[i]
-            }
-            gimli(&mut state);
-            associated_data = &associated_data[16 as usize..];
+               cipher_text_len: usize) -> Result<Self, GimliError>{
+        if cipher_text_len < 16 {
+            return Err(GimliError::TruncatedCiphertext);
         }
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for i in 0..associated_data.len() {
-            state_8[i] ^= associated_data[i]
-        }
-        state_8[associated_data.len() as usize] ^= 1;
-        state_8[47] ^= 1;
-        gimli(&mut state);
 
-        GimliAeadDecryptIter{
-            state: state,
-            cipher_message_len: message_len,
+        Ok(GimliAeadDecryptIter{
+            decryptor: Some(GimliAeadDecryptor::new(key, nonce, associated_data)),
+            cipher_text_len,
             cipher_message: cipher_text,
-            output_buffer: Vec::new(),
-        }
+            output: Vec::new(),
+            position: 0,
+        })
+    }
+
+    fn fill_buffer(&mut self, decryptor: GimliAeadDecryptor) -> Result<(), GimliError> {
+        let message_len = self.cipher_text_len - 16;
+        self.output = vec![0; message_len];
+        read_block(&mut self.cipher_message, &mut self.output, self.cipher_text_len, 0)?;
+        let mut tag = [0; 16];
+        read_block(&mut self.cipher_message, &mut tag, self.cipher_text_len, message_len)?;
+        decryptor.finalize(&mut self.output, &Tag::new(tag))
     }
 }
 
+#[cfg(feature = "std")]
+impl Drop for GimliAeadDecryptIter{
+    fn drop(&mut self){
+        self.output.zeroize();
+    }
+}
+
+#[cfg(feature = "std")]
 impl Iterator for GimliAeadDecryptIter{
-    type Item = u8;
+    type Item = Result<u8, GimliError>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.output_buffer.len() > 0{
-            return Some(self.output_buffer.remove(0))
-        }
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(self.state.as_mut_ptr() as *mut u8, 48)};
-
-        if self.cipher_message_len >= 16 {
-            for i in 0..16 {
-                let current_byte = self.cipher_message.next().unwrap().expect("Read error on input");
-                self.output_buffer.push(state_8[i] ^ current_byte);
-                state_8[i] = current_byte;
-                self.cipher_message_len -=1;
-            }
-            gimli(&mut self.state);
-            return Some(self.output_buffer.remove(0))
-        }
-
-        if self.cipher_message_len <= 15 && self.cipher_message_len > 0 {
-            for i in 0..self.cipher_message_len {
-                let current_byte = self.cipher_message.next().unwrap().expect("Read error on input");
-                self.output_buffer.push(state_8[i] ^ current_byte);
-                state_8[i] = current_byte;
-            }
-            state_8[self.cipher_message_len as usize] ^= 1;
-            state_8[47] ^= 1;
-            gimli(&mut self.state);
-            let state_8 = unsafe {std::slice::from_raw_parts_mut(self.state.as_mut_ptr() as *mut u8, 48)};
-            self.cipher_message_len = 0;
-            // Handle tag
-            let mut result: u32 = 0;
-            for i in 0..16 {
-                let current_byte = self.cipher_message.next().unwrap().expect("Read error on input");
-                result |= (current_byte ^ state_8[i]) as u32;
-            }
-            result = result.overflowing_sub(1).0;
-            result = result >> 16;
-            assert_ne!(result, 0); // Need a better way to express an error than panic
-            match self.output_buffer.len() {
-                0 => return None,
-                _ => return Some(self.output_buffer.remove(0)),
+        if let Some(decryptor) = self.decryptor.take() {
+            if let Err(e) = self.fill_buffer(decryptor) {
+                self.output.zeroize();
+                return Some(Err(e))
             }
         }
-        None
+        let byte = self.output.get(self.position).copied()?;
+        self.position += 1;
+        Some(Ok(byte))
     }
 }
 
+#[cfg(feature = "std")]
 pub fn gimli_aead_decrypt(
+    key: &Key,
+    nonce: &Nonce,
+    associated_data: &[u8],
     mut cipher_text: impl Iterator<Item = Result<u8, io::Error>>,
     cipher_text_len: usize,
-    mut associated_data: &[u8],
-    nonce: &[u8; 16],
-    key: &[u8; 32],
-) -> Result<Vec<u8>, &'static str> {
+) -> Result<Vec<u8>, GimliError> {
     if cipher_text_len < 16 {
-        return Err("Cipher text too short");
-    }
-
-    let mut cipher_message_len = cipher_text_len - 16;
-    let mut output: Vec<u8> = Vec::new();
-    let mut state: [u32; 12] = [0; 12];
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-
-    // Init state with key and nonce plus first permute
-    state_8[..16].clone_from_slice(nonce);
-    state_8[16..48].clone_from_slice(key);
-    gimli(&mut state);
-
-    // Handle associated data
-    while associated_data.len() >= 16 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for i in 0..16 {
-            state_8[i] ^= associated_data[i]
-        }
-        gimli(&mut state);
-        associated_data = &associated_data[16 as usize..];
-    }
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-    for i in 0..associated_data.len() {
-        state_8[i] ^= associated_data[i]
+        return Err(GimliError::TruncatedCiphertext);
     }
-    state_8[associated_data.len() as usize] ^= 1;
-    state_8[47] ^= 1;
-    gimli(&mut state);
-    
 
-    // Handle cipher text
-    while cipher_message_len >= 16 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for j in 0..16 {
-            let current_byte = cipher_text.next().unwrap().expect("Read error on input");
-            output.push(state_8[j] ^ current_byte);
-            state_8[j] = current_byte;
-        }
-        gimli(&mut state);
-        cipher_message_len-=16;
-    }
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-
-    for i in 0..cipher_message_len {
-        let current_byte = cipher_text.next().unwrap().expect("Read error on input");
-        output.push(state_8[i] ^ current_byte);
-        state_8[i] = current_byte;
-    }
-    state_8[cipher_message_len as usize] ^= 1;
-    state_8[47] ^= 1;
-    gimli(&mut state);
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-
-    // Handle tag
-    let mut result: u32 = 0;
-    for i in 0..16 {
-        let current_byte = cipher_text.next().unwrap().expect("Read error on input");
-        result |= (current_byte ^ state_8[i]) as u32
-    }
-    result = result.overflowing_sub(1).0;
-    result = result >> 16;
-
-    for i in 0..output.len() {
-        output[i] &= result as u8; // Valid. Only the first 8 bits of result are possibly non-zero.
-    }
+    let message_len = cipher_text_len - 16;
+    let mut output: Vec<u8> = vec![0; message_len];
+    read_block(&mut cipher_text, &mut output, cipher_text_len, 0)?;
+    let mut tag = [0; 16];
+    read_block(&mut cipher_text, &mut tag, cipher_text_len, message_len)?;
+    decrypt_in_place_detached(key, nonce, associated_data, &mut output, &Tag::new(tag))?;
+    Ok(output)
+}
 
-    if result != 0 {
-        return Ok(output);
-    } else {
-        return Err("Invalid result tag");
-    }
-}
\ No newline at end of file
+/// Decrypts `buffer` in place if `tag` is valid. On failure the buffer is zeroed.
+pub fn decrypt_in_place_detached(
+    key: &Key,
+    nonce: &Nonce,
+    associated_data: &[u8],
+    buffer: &mut [u8],
+    tag: &Tag,
+) -> Result<(), GimliError> {
+    GimliAeadDecryptor::new(key, nonce, associated_data).finalize(buffer, tag)
+}
@@ -0,0 +1,91 @@
+use crate::{GimliHasher, GimliXofReader};
+use digest::core_api::BlockSizeUser;
+use digest::consts::{U32, U64};
+use digest::{ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader};
+
+/// Gimli-Hash with a fixed 32 byte output, usable as a RustCrypto `Digest`.
+#[derive(Clone, Default)]
+pub struct GimliHash256{
+    hasher: GimliHasher,
+}
+
+/// Gimli-Hash as an extendable output function, usable as a RustCrypto `ExtendableOutput`.
+#[derive(Clone, Default)]
+pub struct GimliHashXof{
+    hasher: GimliHasher,
+}
+
+impl HashMarker for GimliHash256{}
+
+impl OutputSizeUser for GimliHash256{
+    type OutputSize = U32;
+}
+
+// The permutation only absorbs 16 bytes at a time, but HMAC needs a block at
+// least as large as the digest so that hashed keys fit. 64 bytes is four rate
+// blocks.
+impl BlockSizeUser for GimliHash256{
+    type BlockSize = U64;
+}
+
+impl Update for GimliHash256{
+    fn update(&mut self, data: &[u8]){
+        self.hasher.update(data);
+    }
+}
+
+impl FixedOutput for GimliHash256{
+    fn finalize_into(self, out: &mut Output<Self>){
+        self.hasher.finalize_xof().squeeze(out);
+    }
+}
+
+impl Reset for GimliHash256{
+    fn reset(&mut self){
+        self.hasher = GimliHasher::new();
+    }
+}
+
+impl FixedOutputReset for GimliHash256{
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>){
+        core::mem::take(&mut self.hasher).finalize_xof().squeeze(out);
+    }
+}
+
+impl HashMarker for GimliHashXof{}
+
+impl BlockSizeUser for GimliHashXof{
+    type BlockSize = U64;
+}
+
+impl Update for GimliHashXof{
+    fn update(&mut self, data: &[u8]){
+        self.hasher.update(data);
+    }
+}
+
+impl ExtendableOutput for GimliHashXof{
+    type Reader = GimliXofReader;
+
+    fn finalize_xof(self) -> GimliXofReader{
+        self.hasher.finalize_xof()
+    }
+}
+
+impl Reset for GimliHashXof{
+    fn reset(&mut self){
+        self.hasher = GimliHasher::new();
+    }
+}
+
+impl ExtendableOutputReset for GimliHashXof{
+    fn finalize_xof_reset(&mut self) -> GimliXofReader{
+        core::mem::take(&mut self.hasher).finalize_xof()
+    }
+}
+
+impl XofReader for GimliXofReader{
+    fn read(&mut self, buffer: &mut [u8]){
+        self.squeeze(buffer);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::gimli_common::{GimliState, Key, Nonce};
+use core::cmp::min;
+use zeroize::Zeroize;
+
+/// Bytes of the state that data passes through. The other 32 bytes are the capacity, which is never read or written directly.
+pub const RATE: usize = 16;
+
+/// Padding and domain separation applied by `GimliDuplex::pad`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GimliPadding{
+    /// XORed into the state at the current position.
+    pub first: u8,
+    /// XORed into the last byte of the rate.
+    pub rate_end: u8,
+    /// XORed into the last byte of the capacity, to separate uses that share a padding rule.
+    pub domain: u8,
+}
+
+impl GimliPadding{
+    /// Gimli-Hash: 0x1F after the input and 0x80 at the end of the rate.
+    pub const HASH: GimliPadding = GimliPadding{ first: 0x1F, rate_end: 0x80, domain: 0 };
+    /// Gimli AEAD: 0x01 after the associated data or message and 0x01 in the last capacity byte.
+    pub const AEAD: GimliPadding = GimliPadding{ first: 0x01, rate_end: 0, domain: 0x01 };
+    /// `GimliMac`: the hash padding, with 0x03 in the last capacity byte.
+    pub const MAC: GimliPadding = GimliPadding{ first: 0x1F, rate_end: 0x80, domain: 0x03 };
+    /// `derive_key`, after the context string.
+    pub const DERIVE_KEY_CONTEXT: GimliPadding = GimliPadding{ first: 0x1F, rate_end: 0x80, domain: 0x04 };
+    /// `derive_key`, after the input key material.
+    pub const DERIVE_KEY_MATERIAL: GimliPadding = GimliPadding{ first: 0x1F, rate_end: 0x80, domain: 0x05 };
+}
+
+/// The permutation run by a `GimliDuplex`.
+pub trait GimliPermutation{
+    fn permute(&self, state: &mut GimliState);
+}
+
+/// The full 24 round gimli permutation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gimli;
+
+impl GimliPermutation for Gimli{
+    fn permute(&self, state: &mut GimliState){
+        state.permute();
+    }
+}
+
+/// Duplex sponge over the gimli permutation, the building block of the hash and AEAD in this crate.
+///
+/// Absorbing, encrypting and decrypting permute as soon as the rate is full; squeezing only permutes once more output
+/// is needed. Calls of any length may be mixed, so `absorb(a); absorb(b)` is the same as `absorb(a || b)`.
+/// The permutation is a type parameter so reduced round variants share the same padding rules.
+#[derive(Clone, Default, Zeroize)]
+pub struct GimliDuplex<P: GimliPermutation = Gimli>{
+    state: GimliState,
+    position: usize,
+    #[zeroize(skip)]
+    permutation: P,
+}
+
+impl GimliDuplex{
+    /// A duplex over the all zero state, as used by Gimli-Hash.
+    pub fn new() -> Self{
+        GimliDuplex::with_permutation(Gimli)
+    }
+
+    /// Loads the nonce into the rate and the key into the capacity, then permutes. This is how the AEAD starts.
+    pub fn keyed(key: &Key, nonce: &Nonce) -> Self{
+        GimliDuplex::keyed_with_permutation(key, nonce, Gimli)
+    }
+}
+
+impl<P: GimliPermutation> GimliDuplex<P>{
+    /// `new` with another permutation.
+    pub fn with_permutation(permutation: P) -> Self{
+        GimliDuplex{
+            state: GimliState::new(),
+            position: 0,
+            permutation,
+        }
+    }
+
+    /// `keyed` with another permutation.
+    pub fn keyed_with_permutation(key: &Key, nonce: &Nonce, permutation: P) -> Self{
+        let mut duplex = GimliDuplex::with_permutation(permutation);
+        duplex.state.xor_bytes(0, nonce.as_bytes());
+        duplex.state.xor_bytes(RATE, key.as_bytes());
+        duplex.permute();
+        duplex
+    }
+
+    /// Position within the rate where the next byte is absorbed or squeezed.
+    pub fn position(&self) -> usize{
+        self.position
+    }
+
+    // Permutes first if the rate was used up by squeezing.
+    fn make_room(&mut self){
+        if self.position == RATE {
+            self.permute();
+        }
+    }
+
+    fn advance(&mut self, len: usize){
+        self.position += len;
+        if self.position == RATE {
+            self.permute();
+        }
+    }
+
+    /// XORs `data` into the rate.
+    pub fn absorb(&mut self, mut data: &[u8]){
+        while !data.is_empty() {
+            self.make_room();
+            let take = min(data.len(), RATE - self.position);
+            self.state.xor_bytes(self.position, &data[..take]);
+            self.advance(take);
+            data = &data[take..];
+        }
+    }
+
+    /// Fills `output` from the rate.
+    pub fn squeeze(&mut self, mut output: &mut [u8]){
+        while !output.is_empty() {
+            self.make_room();
+            let take = min(output.len(), RATE - self.position);
+            self.state.extract_bytes(self.position, &mut output[..take]);
+            self.position += take;
+            output = &mut output[take..];
+        }
+    }
+
+    /// Absorbs the plain text in `buffer` and replaces it with the cipher text.
+    pub fn encrypt(&mut self, mut buffer: &mut [u8]){
+        while !buffer.is_empty() {
+            self.make_room();
+            let take = min(buffer.len(), RATE - self.position);
+            let (chunk, rest) = buffer.split_at_mut(take);
+            self.state.encrypt_bytes(self.position, chunk);
+            self.advance(take);
+            buffer = rest;
+        }
+    }
+
+    /// Replaces the cipher text in `buffer` with the plain text, absorbing the cipher text.
+    pub fn decrypt(&mut self, mut buffer: &mut [u8]){
+        while !buffer.is_empty() {
+            self.make_room();
+            let take = min(buffer.len(), RATE - self.position);
+            let (chunk, rest) = buffer.split_at_mut(take);
+            self.state.decrypt_bytes(self.position, chunk);
+            self.advance(take);
+            buffer = rest;
+        }
+    }
+
+    /// Ends the current input with `padding` and permutes. Using different padding or domain bytes for
+    /// different kinds of input keeps them from being confused with each other.
+    pub fn pad(&mut self, padding: GimliPadding){
+        self.make_room();
+        self.state.xor_byte(self.position, padding.first);
+        self.state.xor_byte(RATE - 1, padding.rate_end);
+        self.state.xor_byte(47, padding.domain);
+        self.permute();
+    }
+
+    /// Permutes and clears the rate. Without the cleared bytes the permutation can not be inverted, so a
+    /// compromised state does not reveal earlier states. The next call permutes again before using the rate.
+    pub fn ratchet(&mut self){
+        self.permute();
+        let mut rate = [0; RATE];
+        self.state.extract_bytes(0, &mut rate);
+        self.state.xor_bytes(0, &rate);
+        rate.zeroize();
+        self.position = RATE;
+    }
+
+    /// Permutes the state and starts again at the beginning of the rate.
+    pub fn permute(&mut self){
+        self.permutation.permute(&mut self.state);
+        self.position = 0;
+    }
+
+    // For callers that run the permutation on the state themselves.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn state_mut(&mut self) -> &mut GimliState{
+        &mut self.state
+    }
+}
+
+// Starts an AEAD message: keys the duplex and absorbs the padded associated data.
+pub(crate) fn aead_init<P: GimliPermutation>(key: &Key, nonce: &Nonce, associated_data: &[u8], permutation: P) -> GimliDuplex<P>{
+    let mut duplex = GimliDuplex::keyed_with_permutation(key, nonce, permutation);
+    duplex.absorb(associated_data);
+    duplex.pad(GimliPadding::AEAD);
+    duplex
+}
@@ -1,154 +1,243 @@
-use crate::gimli_common::gimli;
+use crate::gimli_common::{Key, Nonce, Tag};
+use crate::gimli_duplex::{aead_init, Gimli, GimliDuplex, GimliPadding};
+#[cfg(feature = "alloc")]
+use crate::gimli_duplex::GimliPermutation;
+#[cfg(feature = "alloc")]
+use crate::gimli_common::{gimli, gimli_x2, gimli_x4, gimli_x8, GimliState};
+#[cfg(feature = "std")]
+use crate::gimli_common::read_block;
+#[cfg(feature = "std")]
+use crate::gimli_error::GimliError;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "alloc")]
+use zeroize::Zeroize;
 
+/// Block level AEAD encryption. Whole 16 byte blocks may be encrypted as they arrive, the tail and tag are produced by `finalize`.
+pub struct GimliAeadEncryptor{
+    duplex: GimliDuplex,
+}
+
+impl GimliAeadEncryptor{
+    pub fn new(key: &Key, nonce: &Nonce, associated_data: &[u8]) -> Self{
+        GimliAeadEncryptor{
+            duplex: aead_init(key, nonce, associated_data, Gimli),
+        }
+    }
+
+    /// Encrypts `blocks` in place. Panics if the length is not a multiple of 16.
+    pub fn encrypt_blocks(&mut self, blocks: &mut [u8]){
+        assert_eq!(blocks.len() % 16, 0, "encrypt_blocks requires whole 16 byte blocks");
+        self.duplex.encrypt(blocks);
+    }
+
+    /// Encrypts the rest of the message in place and returns the tag. `buffer` may have any length.
+    pub fn finalize(mut self, buffer: &mut [u8]) -> Tag{
+        self.duplex.encrypt(buffer);
+        self.duplex.pad(GimliPadding::AEAD);
+        let mut tag = [0; 16];
+        self.duplex.squeeze(&mut tag);
+        Tag::new(tag)
+    }
+}
+
+#[cfg(feature = "std")]
 pub struct GimliAeadEncryptIter{
-    state: [u32; 12],
+    encryptor: Option<GimliAeadEncryptor>,
     message_len: usize,
+    read: usize,
     message: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
-    output_buffer: Vec<u8>,
-    complete: bool,
-    last_blocksize: usize,
+    output_buffer: [u8; 32],
+    output_len: usize,
+    position: usize,
 }
 
+#[cfg(feature = "std")]
 impl GimliAeadEncryptIter{
-    pub fn new(key: [u8; 32],
-               nonce: [u8; 16],
-               message_len: usize,
+    pub fn new(key: &Key,
+               nonce: &Nonce,
+               associated_data: &[u8],
                message: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
-               mut associated_data: &[u8]) -> Self{
-        let mut state: [u32; 12] = [0; 12];
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        state_8[..16].clone_from_slice(&nonce);
-        state_8[16..48].clone_from_slice(&key);
-        gimli(&mut state);
-
-        while associated_data.len() >= 16 {
-            let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-            for i in 0..16 {
-                state_8[i] ^= associated_data[i]
-            }
-            gimli(&mut state);
-            associated_data = &associated_data[16 as usize..];
-        }
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for i in 0..associated_data.len() {
-            state_8[i] ^= associated_data[i]
+               message_len: usize) -> Self{
+        GimliAeadEncryptIter{
+            encryptor: Some(GimliAeadEncryptor::new(key, nonce, associated_data)),
+            message_len,
+            read: 0,
+            message,
+            output_buffer: [0; 32],
+            output_len: 0,
+            position: 0,
         }
-        state_8[associated_data.len() as usize] ^= 1;
-        state_8[47] ^= 1;
-        gimli(&mut state);
+    }
 
-        GimliAeadEncryptIter{
-            state: state,
-            message_len: message_len,
-            message: message,
-            output_buffer: Vec::new(),
-            complete: false,
-            last_blocksize: 0
+    fn fill_buffer(&mut self, mut encryptor: GimliAeadEncryptor) -> Result<(), GimliError> {
+        let remaining = self.message_len - self.read;
+        let block_len = remaining.min(16);
+        read_block(&mut self.message, &mut self.output_buffer[..block_len], self.message_len, self.read)?;
+        self.read += block_len;
+        self.output_len = block_len;
+        self.position = 0;
+
+        if remaining >= 16 {
+            encryptor.encrypt_blocks(&mut self.output_buffer[..16]);
+            self.encryptor = Some(encryptor);
+        } else {
+            let tag = encryptor.finalize(&mut self.output_buffer[..block_len]);
+            self.output_buffer[block_len..block_len + 16].copy_from_slice(tag.as_bytes());
+            self.output_len += 16;
         }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for GimliAeadEncryptIter{
+    fn drop(&mut self){
+        self.output_buffer.zeroize();
     }
 }
 
+#[cfg(feature = "std")]
 impl Iterator for GimliAeadEncryptIter{
-    type Item = u8;
+    type Item = Result<u8, GimliError>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.output_buffer.len() > 0{
-            return Some(self.output_buffer.remove(0))
-        }
-
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(self.state.as_mut_ptr() as *mut u8, 48)};
-        if self.message_len >= 16 {
-            for i in 0..16 {
-                state_8[i] ^= self.message.next().unwrap().expect("Read error on input");
-                self.output_buffer.push(state_8[i]);
-                self.message_len -=1;
+        if self.position == self.output_len {
+            let encryptor = self.encryptor.take()?;
+            if let Err(e) = self.fill_buffer(encryptor) {
+                self.output_len = 0;
+                self.position = 0;
+                return Some(Err(e))
             }
-            gimli(&mut self.state);
-            return Some(self.output_buffer.remove(0))
         }
+        self.position += 1;
+        Some(Ok(self.output_buffer[self.position - 1]))
+    }
+}
 
-        if self.message_len < 16 && self.message_len > 0 {
-            self.last_blocksize = self.message_len;
-            for i in 0..self.message_len {
-                let foo = self.message.next().unwrap().expect("Read error on input");
-                state_8[i] ^= foo;
-                self.output_buffer.push(state_8[i]);
-                self.message_len -=1;
-            }
-            return Some(self.output_buffer.remove(0))
-        }
+#[cfg(feature = "std")]
+pub fn gimli_aead_encrypt(
+    key: &Key,
+    nonce: &Nonce,
+    associated_data: &[u8],
+    mut message: impl Iterator<Item = Result<u8, io::Error>>,
+    message_len: usize,
+) -> Result<Vec<u8>, GimliError> {
+    let mut output: Vec<u8> = vec![0; message_len + 16];
+    read_block(&mut message, &mut output[..message_len], message_len, 0)?;
+    let tag = encrypt_in_place_detached(key, nonce, associated_data, &mut output[..message_len]);
+    output[message_len..].copy_from_slice(tag.as_bytes());
+    Ok(output)
+}
 
-        if self.message_len == 0 && self.complete == false{
-            state_8[self.last_blocksize as usize] ^= 1;
-            state_8[47] ^= 1;
-            gimli(&mut self.state); 
-            let state_8 = unsafe {std::slice::from_raw_parts_mut(self.state.as_mut_ptr() as *mut u8, 48)};
-            for i in 0..16 {
-                self.output_buffer.push(state_8[i]);
-            }
-            self.complete = true;
-            return Some(self.output_buffer.remove(0))
-        }
+/// Encrypts `buffer` in place and returns the authentication tag.
+pub fn encrypt_in_place_detached(
+    key: &Key,
+    nonce: &Nonce,
+    associated_data: &[u8],
+    buffer: &mut [u8],
+) -> Tag {
+    GimliAeadEncryptor::new(key, nonce, associated_data).finalize(buffer)
+}
 
-        return None
+/// One message of a batch passed to `encrypt_batch_in_place_detached`.
+#[cfg(feature = "alloc")]
+pub struct GimliAeadBatchItem<'a>{
+    pub key: &'a Key,
+    pub nonce: &'a Nonce,
+    pub associated_data: &'a [u8],
+    pub buffer: &'a mut [u8],
+}
 
+/// Encrypts many independent messages in place and returns their tags in the same order.
+/// Up to eight messages share each call to the permutation; the output is the same as
+/// calling `encrypt_in_place_detached` on every item.
+#[cfg(feature = "alloc")]
+pub fn encrypt_batch_in_place_detached(items: &mut [GimliAeadBatchItem]) -> Vec<Tag> {
+    let mut tags = vec![Tag::new([0; 16]); items.len()];
+    match items.len() {
+        0..=1 => encrypt_batch(items, &mut tags, |states: &mut [[u32; 12]; 1]| gimli(&mut states[0])),
+        2 => encrypt_batch(items, &mut tags, gimli_x2),
+        3..=4 => encrypt_batch(items, &mut tags, gimli_x4),
+        _ => encrypt_batch(items, &mut tags, gimli_x8),
     }
+    tags
 }
 
-pub fn gimli_aead_encrypt(
-    mut message: impl Iterator<Item = Result<u8, io::Error>>,
-    mut message_len: usize,
-    mut associated_data: &[u8],
-    nonce: &[u8; 16],
-    key: &[u8; 32],
-) -> Vec<u8> {
-    let mut output: Vec<u8> = Vec::new();
-    let mut state: [u32; 12] = [0; 12];
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-
-    // Init state with key and nonce plus first permute
-    state_8[..16].clone_from_slice(nonce);
-    state_8[16..48].clone_from_slice(key);
-    gimli(&mut state);
-
-    while associated_data.len() >= 16 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for i in 0..16 {
-            state_8[i] ^= associated_data[i]
+// Leaves the permutation to `encrypt_batch`, which runs it on all lanes at once.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default)]
+struct Deferred;
+
+#[cfg(feature = "alloc")]
+impl GimliPermutation for Deferred{
+    fn permute(&self, _state: &mut GimliState){}
+}
+
+// Runs `N` lanes; a lane picks up the next item as soon as its current one is done, so messages
+// of different lengths keep the lanes busy.
+#[cfg(feature = "alloc")]
+fn encrypt_batch<const N: usize>(items: &mut [GimliAeadBatchItem], tags: &mut [Tag], permute: impl Fn(&mut [[u32; 12]; N])) {
+    let mut lanes: [Option<(usize, usize)>; N] = [None; N];
+    let mut duplexes: [GimliDuplex<Deferred>; N] = core::array::from_fn(|_| GimliDuplex::with_permutation(Deferred));
+    let mut states = [[0; 12]; N];
+    let mut next_item = 0;
+    loop {
+        let mut finished = [false; N];
+        let mut active = false;
+        for (((lane, duplex), words), finished) in lanes.iter_mut().zip(duplexes.iter_mut()).zip(states.iter_mut()).zip(finished.iter_mut()) {
+            if lane.is_none() && next_item < items.len() {
+                *lane = Some((next_item, 0));
+                next_item += 1;
+            }
+            if let Some((item, step)) = lane {
+                *finished = batch_step(duplex, &mut items[*item], *step);
+                *words = *duplex.state_mut().words();
+                *step += 1;
+                active = true;
+            }
         }
-        gimli(&mut state);
-        associated_data = &associated_data[16 as usize..];
-    }
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-    for i in 0..associated_data.len() {
-        state_8[i] ^= associated_data[i]
-    }
-    state_8[associated_data.len() as usize] ^= 1;
-    state_8[47] ^= 1;
-    gimli(&mut state);
-
-    while message_len >= 16 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        for i in 0..16 {
-            state_8[i] ^= message.next().unwrap().expect("Read error on input");
-            output.push(state_8[i]);
-            message_len -=1;
+        if !active {
+            states.zeroize();
+            return
+        }
+        permute(&mut states);
+        for (((lane, duplex), words), finished) in lanes.iter_mut().zip(duplexes.iter_mut()).zip(states.iter()).zip(finished.iter()) {
+            if let Some((item, _)) = *lane {
+                *duplex.state_mut().words_mut() = *words;
+                if *finished {
+                    let mut tag = [0; 16];
+                    duplex.squeeze(&mut tag);
+                    tags[item] = Tag::new(tag);
+                    *lane = None;
+                }
+            }
         }
-        gimli(&mut state);
     }
+}
 
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-    for i in 0..message_len {
-        state_8[i] ^= message.next().unwrap().expect("Read error on input");
-        output.push(state_8[i]);
-    }
-    state_8[message_len as usize] ^= 1;
-    state_8[47] ^= 1;
-    gimli(&mut state);
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-    for i in 0..16 {
-        output.push(state_8[i]);
+// Feeds `duplex` up to permutation number `step` of `item`. Returns true when the tag can be read after this permutation.
+#[cfg(feature = "alloc")]
+fn batch_step(duplex: &mut GimliDuplex<Deferred>, item: &mut GimliAeadBatchItem, step: usize) -> bool {
+    let associated_blocks = item.associated_data.len() / 16;
+    let message_blocks = item.buffer.len() / 16;
+    if step == 0 {
+        *duplex = GimliDuplex::keyed_with_permutation(item.key, item.nonce, Deferred);
+    } else if step <= associated_blocks {
+        duplex.absorb(&item.associated_data[(step - 1) * 16..step * 16]);
+    } else if step == associated_blocks + 1 {
+        duplex.absorb(&item.associated_data[associated_blocks * 16..]);
+        duplex.pad(GimliPadding::AEAD);
+    } else {
+        let block = step - associated_blocks - 2;
+        if block < message_blocks {
+            duplex.encrypt(&mut item.buffer[block * 16..(block + 1) * 16]);
+        } else {
+            duplex.encrypt(&mut item.buffer[message_blocks * 16..]);
+            duplex.pad(GimliPadding::AEAD);
+            return true
+        }
     }
-
-    return output;
-}
\ No newline at end of file
+    false
+}
@@ -0,0 +1,80 @@
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Errors returned by the gimli hash and AEAD functions.
+#[derive(Debug)]
+pub enum GimliError {
+    /// The authentication tag does not match the cipher text and associated data.
+    TagMismatch,
+    /// The cipher text is too short to contain an authentication tag.
+    TruncatedCiphertext,
+    /// The input ended before the declared number of bytes was read.
+    LengthMismatch { expected: usize, actual: usize },
+    /// A key, nonce or tag was built from input of the wrong length.
+    InvalidLength { expected: usize, actual: usize },
+    /// A key, nonce or tag was built from a string containing a non hex digit.
+    InvalidHex,
+    /// A key, nonce or tag was built from a hex string with the wrong number of digits.
+    InvalidHexLength { expected: usize, actual: usize },
+    /// The stream has already processed its last segment or exhausted its segment counter.
+    StreamFinished,
+    /// Reading the input failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+}
+
+impl fmt::Display for GimliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GimliError::TagMismatch => write!(f, "Invalid result tag"),
+            GimliError::TruncatedCiphertext => write!(f, "Cipher text too short"),
+            GimliError::LengthMismatch { expected, actual } => {
+                write!(f, "Input length mismatch: expected {} bytes, got {}", expected, actual)
+            }
+            GimliError::InvalidLength { expected, actual } => {
+                write!(f, "Invalid length: expected {} bytes, got {}", expected, actual)
+            }
+            GimliError::InvalidHex => write!(f, "Invalid hex digit"),
+            GimliError::InvalidHexLength { expected, actual } => {
+                write!(f, "Invalid hex length: expected {} digits, got {}", expected, actual)
+            }
+            GimliError::StreamFinished => write!(f, "Stream already finished"),
+            #[cfg(feature = "std")]
+            GimliError::Io(e) => write!(f, "Read error on input: {}", e),
+        }
+    }
+}
+
+// core::error::Error is what sets the minimum Rust version of 1.81 in Cargo.toml.
+impl Error for GimliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            GimliError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for GimliError {
+    fn from(e: io::Error) -> Self {
+        GimliError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<GimliError> for io::Error {
+    fn from(e: GimliError) -> Self {
+        match e {
+            GimliError::Io(e) => e,
+            GimliError::TruncatedCiphertext | GimliError::LengthMismatch { .. } => io::Error::new(io::ErrorKind::UnexpectedEof, e),
+            GimliError::InvalidLength { .. } | GimliError::InvalidHex | GimliError::InvalidHexLength { .. } => {
+                io::Error::new(io::ErrorKind::InvalidInput, e)
+            }
+            _ => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! HMAC (RFC 2104) and HKDF (RFC 5869) over 32 byte Gimli-Hash.
+//!
+//! The HMAC block is 64 bytes, the block size `GimliHash256` reports (see `gimli_digest` for why), so
+//! `hmac::SimpleHmac<GimliHash256>` and `hkdf::SimpleHkdf<GimliHash256>` compute the same values as this module.
+use crate::gimli_common::verify_tag;
+use crate::gimli_error::GimliError;
+use crate::GimliHasher;
+use zeroize::Zeroize;
+
+/// HMAC block size in bytes.
+pub const BLOCK_SIZE: usize = 64;
+/// Length of the HMAC output and of the HKDF pseudo random key.
+pub const OUTPUT_SIZE: usize = 32;
+/// Longest output `hkdf_expand` can produce.
+pub const MAX_HKDF_OUTPUT: usize = 255 * OUTPUT_SIZE;
+
+/// HMAC-Gimli-Hash. Keys may have any length; keys longer than the block are hashed first.
+#[derive(Clone)]
+pub struct GimliHmac{
+    inner: GimliHasher,
+    outer: GimliHasher,
+}
+
+impl GimliHmac{
+    pub fn new(key: &[u8]) -> Self{
+        let mut block = [0; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            let mut hasher = GimliHasher::new();
+            hasher.update(key);
+            hasher.finalize_xof().squeeze(&mut block[..OUTPUT_SIZE]);
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = GimliHasher::new();
+        let mut outer = GimliHasher::new();
+        block.iter_mut().for_each(|byte| *byte ^= 0x36);
+        inner.update(&block);
+        block.iter_mut().for_each(|byte| *byte ^= 0x36 ^ 0x5c);
+        outer.update(&block);
+        block.zeroize();
+        GimliHmac{
+            inner,
+            outer,
+        }
+    }
+
+    /// Absorbs `input`. May be called any number of times.
+    pub fn update(&mut self, input: &[u8]){
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> [u8; OUTPUT_SIZE]{
+        let GimliHmac{ inner, mut outer } = self;
+        let mut inner_hash = [0; OUTPUT_SIZE];
+        inner.finalize_xof().squeeze(&mut inner_hash);
+        outer.update(&inner_hash);
+        inner_hash.zeroize();
+
+        let mut output = [0; OUTPUT_SIZE];
+        outer.finalize_xof().squeeze(&mut output);
+        output
+    }
+
+    /// Checks `tag` in constant time. Only full length tags are accepted.
+    pub fn verify(self, tag: &[u8]) -> Result<(), GimliError>{
+        let mut expected = self.finalize();
+        let result = verify_tag(&expected, tag, &mut []);
+        expected.zeroize();
+        result
+    }
+}
+
+/// HKDF-Extract: condenses `input_key_material` into a pseudo random key. An empty salt means 32 zero bytes.
+pub fn hkdf_extract(salt: &[u8], input_key_material: &[u8]) -> [u8; OUTPUT_SIZE]{
+    let mut hmac = match salt.is_empty() {
+        true => GimliHmac::new(&[0; OUTPUT_SIZE]),
+        false => GimliHmac::new(salt),
+    };
+    hmac.update(input_key_material);
+    hmac.finalize()
+}
+
+/// HKDF-Expand: fills `output` with key material bound to `info`. `output` may be at most `MAX_HKDF_OUTPUT` bytes.
+pub fn hkdf_expand(pseudo_random_key: &[u8], info: &[u8], output: &mut [u8]) -> Result<(), GimliError>{
+    if output.len() > MAX_HKDF_OUTPUT {
+        return Err(GimliError::InvalidLength{ expected: MAX_HKDF_OUTPUT, actual: output.len() });
+    }
+
+    let keyed = GimliHmac::new(pseudo_random_key);
+    let mut block = [0; OUTPUT_SIZE];
+    for (counter, chunk) in (1..=255u8).zip(output.chunks_mut(OUTPUT_SIZE)) {
+        let mut hmac = keyed.clone();
+        if counter > 1 {
+            hmac.update(&block);
+        }
+        hmac.update(info);
+        hmac.update(&[counter]);
+        block = hmac.finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    block.zeroize();
+    Ok(())
+}
+
+/// HKDF extract and expand in one call.
+pub fn hkdf(salt: &[u8], input_key_material: &[u8], info: &[u8], output: &mut [u8]) -> Result<(), GimliError>{
+    let mut pseudo_random_key = hkdf_extract(salt, input_key_material);
+    let result = hkdf_expand(&pseudo_random_key, info, output);
+    pseudo_random_key.zeroize();
+    result
+}
@@ -0,0 +1,256 @@
+//! Vectorised gimli permutation for x86_64. Each row of the state (x, y, z) lives in one 128 bit register,
+//! so the four columns of a round are computed at once.
+//! The batched permutations wipe their copies of the states before returning, as the batch encryptor passes them
+//! keyed states.
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+#[cfg(target_arch = "x86_64")]
+use zeroize::Zeroize;
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn rotate_left<const LEFT: i32, const RIGHT: i32>(v: __m128i) -> __m128i{
+    _mm_or_si128(_mm_slli_epi32::<LEFT>(v), _mm_srli_epi32::<RIGHT>(v))
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn sp_box(x: &mut __m128i, y: &mut __m128i, z: &mut __m128i){
+    let a = rotate_left::<24, 8>(*x);
+    let b = rotate_left::<9, 23>(*y);
+    let c = *z;
+
+    *z = _mm_xor_si128(_mm_xor_si128(a, _mm_slli_epi32::<1>(c)), _mm_slli_epi32::<2>(_mm_and_si128(b, c)));
+    *y = _mm_xor_si128(_mm_xor_si128(b, a), _mm_slli_epi32::<1>(_mm_or_si128(a, c)));
+    *x = _mm_xor_si128(_mm_xor_si128(c, b), _mm_slli_epi32::<3>(_mm_and_si128(a, b)));
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn permute(state: &mut [u32; 12]){
+    let pointer = state.as_mut_ptr() as *mut __m128i;
+    let mut x = _mm_loadu_si128(pointer);
+    let mut y = _mm_loadu_si128(pointer.add(1));
+    let mut z = _mm_loadu_si128(pointer.add(2));
+
+    // Rounds come in groups of four: small swap and constant, nothing, big swap, nothing.
+    for round in (1..=6u32).rev().map(|r| r * 4) {
+        sp_box(&mut x, &mut y, &mut z);
+        x = _mm_shuffle_epi32::<0b10_11_00_01>(x);
+        x = _mm_xor_si128(x, _mm_set_epi32(0, 0, 0, (0x9e377900 | round) as i32));
+
+        sp_box(&mut x, &mut y, &mut z);
+
+        sp_box(&mut x, &mut y, &mut z);
+        x = _mm_shuffle_epi32::<0b01_00_11_10>(x);
+
+        sp_box(&mut x, &mut y, &mut z);
+    }
+
+    _mm_storeu_si128(pointer, x);
+    _mm_storeu_si128(pointer.add(1), y);
+    _mm_storeu_si128(pointer.add(2), z);
+}
+
+/// SSE2 permutation.
+///
+/// # Safety
+/// The CPU must support SSE2, which every x86_64 CPU does.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+pub unsafe fn gimli_sse2(state: &mut [u32; 12]){
+    permute(state)
+}
+
+/// The SSE2 permutation compiled with the VEX encoded AVX2 instruction set, which avoids register copies.
+///
+/// # Safety
+/// The CPU must support AVX2, check with `is_x86_feature_detected!("avx2")`.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn gimli_avx2(state: &mut [u32; 12]){
+    permute(state)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn rotate_left_256<const LEFT: i32, const RIGHT: i32>(v: __m256i) -> __m256i{
+    _mm256_or_si256(_mm256_slli_epi32::<LEFT>(v), _mm256_srli_epi32::<RIGHT>(v))
+}
+
+// Rotating each word left by 24 is a byte shuffle.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn rotate_left_24_256(v: __m256i) -> __m256i{
+    let mask = _mm256_setr_epi8(
+        1, 2, 3, 0, 5, 6, 7, 4, 9, 10, 11, 8, 13, 14, 15, 12,
+        1, 2, 3, 0, 5, 6, 7, 4, 9, 10, 11, 8, 13, 14, 15, 12,
+    );
+    _mm256_shuffle_epi8(v, mask)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn sp_box_256(x: &mut __m256i, y: &mut __m256i, z: &mut __m256i){
+    let a = rotate_left_24_256(*x);
+    let b = rotate_left_256::<9, 23>(*y);
+    let c = *z;
+
+    *z = _mm256_xor_si256(_mm256_xor_si256(a, _mm256_slli_epi32::<1>(c)), _mm256_slli_epi32::<2>(_mm256_and_si256(b, c)));
+    *y = _mm256_xor_si256(_mm256_xor_si256(b, a), _mm256_slli_epi32::<1>(_mm256_or_si256(a, c)));
+    *x = _mm256_xor_si256(_mm256_xor_si256(c, b), _mm256_slli_epi32::<3>(_mm256_and_si256(a, b)));
+}
+
+// Two states side by side: the low 128 bits of each register hold a row of the first state,
+// the high 128 bits the same row of the second.
+#[cfg(target_arch = "x86_64")]
+#[derive(Clone, Copy, Zeroize)]
+struct StatePair{
+    x: __m256i,
+    y: __m256i,
+    z: __m256i,
+}
+
+#[cfg(target_arch = "x86_64")]
+impl StatePair{
+    #[inline(always)]
+    unsafe fn load(first: &[u32; 12], second: &[u32; 12]) -> Self{
+        let first = first.as_ptr() as *const __m128i;
+        let second = second.as_ptr() as *const __m128i;
+        StatePair{
+            x: _mm256_loadu2_m128i(second, first),
+            y: _mm256_loadu2_m128i(second.add(1), first.add(1)),
+            z: _mm256_loadu2_m128i(second.add(2), first.add(2)),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, first: &mut [u32; 12], second: &mut [u32; 12]){
+        let first = first.as_mut_ptr() as *mut __m128i;
+        let second = second.as_mut_ptr() as *mut __m128i;
+        _mm256_storeu2_m128i(second, first, self.x);
+        _mm256_storeu2_m128i(second.add(1), first.add(1), self.y);
+        _mm256_storeu2_m128i(second.add(2), first.add(2), self.z);
+    }
+
+    #[inline(always)]
+    unsafe fn sp_box(&mut self){
+        sp_box_256(&mut self.x, &mut self.y, &mut self.z);
+    }
+
+    #[inline(always)]
+    unsafe fn small_swap_and_constant(&mut self, round: u32){
+        self.x = _mm256_shuffle_epi32::<0b10_11_00_01>(self.x);
+        let constant = (0x9e377900 | round) as i32;
+        self.x = _mm256_xor_si256(self.x, _mm256_setr_epi32(constant, 0, 0, 0, constant, 0, 0, 0));
+    }
+
+    #[inline(always)]
+    unsafe fn big_swap(&mut self){
+        self.x = _mm256_shuffle_epi32::<0b01_00_11_10>(self.x);
+    }
+}
+
+/// Permutes two independent states at once.
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn gimli_x2_avx2(states: &mut [[u32; 12]; 2]){
+    let [first, second] = states;
+    let mut pair = StatePair::load(first, second);
+    for round in (1..=6u32).rev().map(|r| r * 4) {
+        pair.sp_box();
+        pair.small_swap_and_constant(round);
+        pair.sp_box();
+        pair.sp_box();
+        pair.big_swap();
+        pair.sp_box();
+    }
+    pair.store(first, second);
+    pair.zeroize();
+}
+
+/// Permutes four independent states at once, as two interleaved pairs.
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn gimli_x4_avx2(states: &mut [[u32; 12]; 4]){
+    let [first, second, third, fourth] = states;
+    let mut low = StatePair::load(first, second);
+    let mut high = StatePair::load(third, fourth);
+    for round in (1..=6u32).rev().map(|r| r * 4) {
+        low.sp_box();
+        high.sp_box();
+        low.small_swap_and_constant(round);
+        high.small_swap_and_constant(round);
+        low.sp_box();
+        high.sp_box();
+        low.sp_box();
+        high.sp_box();
+        low.big_swap();
+        high.big_swap();
+        low.sp_box();
+        high.sp_box();
+    }
+    low.store(first, second);
+    high.store(third, fourth);
+    low.zeroize();
+    high.zeroize();
+}
+
+/// Permutes eight independent states at once. The states are interleaved so that register `i` holds word `i`
+/// of every state; the swaps then only rename registers.
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn gimli_x8_avx2(states: &mut [[u32; 12]; 8]){
+    let mut interleaved = [[0u32; 8]; 12];
+    for (lane, state) in states.iter().enumerate() {
+        for (word, value) in state.iter().enumerate() {
+            interleaved[word][lane] = *value;
+        }
+    }
+    let mut w = [_mm256_setzero_si256(); 12];
+    for (register, words) in w.iter_mut().zip(interleaved.iter()) {
+        *register = _mm256_loadu_si256(words.as_ptr() as *const __m256i);
+    }
+
+    for round in (1..=24u32).rev() {
+        for column in 0..4 {
+            let mut x = w[column];
+            let mut y = w[4 + column];
+            let mut z = w[8 + column];
+            sp_box_256(&mut x, &mut y, &mut z);
+            w[column] = x;
+            w[4 + column] = y;
+            w[8 + column] = z;
+        }
+        if (round & 3) == 0 {
+            w.swap(0, 1);
+            w.swap(2, 3);
+            w[0] = _mm256_xor_si256(w[0], _mm256_set1_epi32((0x9e377900 | round) as i32));
+        }
+        if (round & 3) == 2 {
+            w.swap(0, 2);
+            w.swap(1, 3);
+        }
+    }
+
+    for (register, words) in w.iter().zip(interleaved.iter_mut()) {
+        _mm256_storeu_si256(words.as_mut_ptr() as *mut __m256i, *register);
+    }
+    for (lane, state) in states.iter_mut().enumerate() {
+        for (word, value) in state.iter_mut().enumerate() {
+            *value = interleaved[word][lane];
+        }
+    }
+    interleaved.zeroize();
+    w.zeroize();
+}
@@ -0,0 +1,394 @@
+use crate::gimli_common::{Key, Nonce, Tag};
+use crate::gimli_decrypt::decrypt_in_place_detached;
+use crate::gimli_encrypt::encrypt_in_place_detached;
+use crate::gimli_error::GimliError;
+use std::cmp::min;
+use std::io::{self, Read, Write};
+use std::iter::Peekable;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// Plain text bytes carried by every segment except the last.
+pub const SEGMENT_SIZE: usize = 4096;
+/// Length of the caller supplied nonce prefix. The remaining nonce bytes hold the segment counter and last segment flag.
+pub const NONCE_PREFIX_SIZE: usize = 11;
+
+// Segment nonce layout: prefix (11 bytes) || counter (4 bytes, big endian) || last segment flag (1 byte)
+fn segment_nonce(prefix: &[u8; NONCE_PREFIX_SIZE], counter: u32, last: bool) -> Nonce {
+    let mut nonce = [0; 16];
+    nonce[..NONCE_PREFIX_SIZE].copy_from_slice(prefix);
+    nonce[NONCE_PREFIX_SIZE..15].copy_from_slice(&counter.to_be_bytes());
+    nonce[15] = last as u8;
+    Nonce::new(nonce)
+}
+
+// Only the last segment may use the final counter value. A stream that fails here is finished, so a nonce is never reused.
+fn check_counter(counter: u32, last: bool, complete: &mut bool) -> Result<(), GimliError> {
+    if !last && counter == u32::MAX {
+        *complete = true;
+        return Err(GimliError::StreamFinished);
+    }
+    Ok(())
+}
+
+/// Encrypts a message as a sequence of independently authenticated segments (the STREAM construction).
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct GimliStreamEncryptor{
+    key: Key,
+    nonce_prefix: [u8; NONCE_PREFIX_SIZE],
+    counter: u32,
+    pub(crate) complete: bool,
+}
+
+impl GimliStreamEncryptor{
+    pub fn new(key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE]) -> Self{
+        GimliStreamEncryptor{
+            key: key.clone(),
+            nonce_prefix: *nonce_prefix,
+            counter: 0,
+            complete: false,
+        }
+    }
+
+    // Starts the stream at segment `counter`, so tests can reach the end of the counter range.
+    #[cfg(test)]
+    pub(crate) fn with_counter(key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], counter: u32) -> Self{
+        let mut stream = GimliStreamEncryptor::new(key, nonce_prefix);
+        stream.counter = counter;
+        stream
+    }
+
+    /// Encrypts `buffer` in place as the next segment and returns its tag.
+    /// Setting `last` marks the end of the stream; no further segments may follow.
+    pub fn encrypt_segment(&mut self, buffer: &mut [u8], associated_data: &[u8], last: bool) -> Result<Tag, GimliError>{
+        if self.complete {
+            return Err(GimliError::StreamFinished);
+        }
+        check_counter(self.counter, last, &mut self.complete)?;
+        let nonce = segment_nonce(&self.nonce_prefix, self.counter, last);
+        let tag = encrypt_in_place_detached(&self.key, &nonce, associated_data, buffer);
+        match last {
+            true => self.complete = true,
+            false => self.counter += 1,
+        }
+        Ok(tag)
+    }
+
+    // Encrypts the plain text in `buffer` and appends the tag, leaving a complete segment.
+    pub(crate) fn seal_buffered(&mut self, buffer: &mut Vec<u8>, associated_data: &[u8], last: bool) -> Result<(), GimliError>{
+        let tag = self.encrypt_segment(buffer, associated_data, last)?;
+        buffer.extend_from_slice(tag.as_bytes());
+        Ok(())
+    }
+}
+
+/// Decrypts and authenticates segments produced by `GimliStreamEncryptor`.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct GimliStreamDecryptor{
+    key: Key,
+    nonce_prefix: [u8; NONCE_PREFIX_SIZE],
+    counter: u32,
+    pub(crate) complete: bool,
+}
+
+impl GimliStreamDecryptor{
+    pub fn new(key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE]) -> Self{
+        GimliStreamDecryptor{
+            key: key.clone(),
+            nonce_prefix: *nonce_prefix,
+            counter: 0,
+            complete: false,
+        }
+    }
+
+    // Counterpart of GimliStreamEncryptor::with_counter.
+    #[cfg(test)]
+    pub(crate) fn with_counter(key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], counter: u32) -> Self{
+        let mut stream = GimliStreamDecryptor::new(key, nonce_prefix);
+        stream.counter = counter;
+        stream
+    }
+
+    /// Decrypts `buffer` in place if `tag` authenticates it as the next segment.
+    /// On failure the buffer is zeroed.
+    pub fn decrypt_segment(&mut self, buffer: &mut [u8], associated_data: &[u8], tag: &Tag, last: bool) -> Result<(), GimliError>{
+        if self.complete {
+            return Err(GimliError::StreamFinished);
+        }
+        check_counter(self.counter, last, &mut self.complete)?;
+        let nonce = segment_nonce(&self.nonce_prefix, self.counter, last);
+        if let Err(e) = decrypt_in_place_detached(&self.key, &nonce, associated_data, buffer, tag) {
+            self.complete = true;
+            return Err(e);
+        }
+        match last {
+            true => self.complete = true,
+            false => self.counter += 1,
+        }
+        Ok(())
+    }
+
+    // Decrypts the segment at the front of `input` into `output`. `input` must hold either one byte
+    // past a full segment, or everything up to the end of the stream, which marks the last segment.
+    pub(crate) fn open_buffered(&mut self, input: &mut Vec<u8>, output: &mut Vec<u8>, associated_data: &[u8]) -> Result<(), GimliError>{
+        if input.len() < 16 {
+            return Err(GimliError::TruncatedCiphertext);
+        }
+        let last = input.len() <= SEGMENT_SIZE + 16;
+        let segment_len = min(input.len(), SEGMENT_SIZE + 16);
+        let message_len = segment_len - 16;
+        let tag = Tag::from_slice(&input[message_len..segment_len])?;
+        output.clear();
+        output.extend_from_slice(&input[..message_len]);
+        input.drain(..segment_len);
+        self.decrypt_segment(output, associated_data, &tag, last)
+    }
+}
+
+/// Yields the cipher text of each segment, followed by its tag.
+pub struct GimliStreamEncryptIter{
+    encryptor: GimliStreamEncryptor,
+    message: Peekable<Box<dyn Iterator<Item = Result<u8, io::Error>>>>,
+    associated_data: Vec<u8>,
+}
+
+impl GimliStreamEncryptIter{
+    pub fn new(key: &Key,
+               nonce_prefix: &[u8; NONCE_PREFIX_SIZE],
+               message: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
+               associated_data: &[u8]) -> Self{
+        GimliStreamEncryptIter{
+            encryptor: GimliStreamEncryptor::new(key, nonce_prefix),
+            message: message.peekable(),
+            associated_data: associated_data.to_vec(),
+        }
+    }
+
+    fn next_segment(&mut self) -> Result<Vec<u8>, GimliError>{
+        let mut segment = Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16));
+        while segment.len() < SEGMENT_SIZE {
+            match self.message.next() {
+                Some(byte) => segment.push(byte?),
+                None => break,
+            }
+        }
+        let last = self.message.peek().is_none();
+        let tag = self.encryptor.encrypt_segment(&mut segment, &self.associated_data, last)?;
+        segment.extend_from_slice(tag.as_bytes());
+        Ok(std::mem::take(&mut segment))
+    }
+}
+
+impl Iterator for GimliStreamEncryptIter{
+    type Item = Result<Vec<u8>, GimliError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.encryptor.complete {
+            return None
+        }
+        let segment = self.next_segment();
+        if segment.is_err() {
+            self.encryptor.complete = true;
+        }
+        Some(segment)
+    }
+}
+
+/// Yields the plain text of each segment, but only once the segment's tag has been verified.
+pub struct GimliStreamDecryptIter{
+    decryptor: GimliStreamDecryptor,
+    cipher_text: Peekable<Box<dyn Iterator<Item = Result<u8, io::Error>>>>,
+    associated_data: Vec<u8>,
+}
+
+impl GimliStreamDecryptIter{
+    pub fn new(key: &Key,
+               nonce_prefix: &[u8; NONCE_PREFIX_SIZE],
+               cipher_text: Box<dyn Iterator<Item = Result<u8, io::Error>>>,
+               associated_data: &[u8]) -> Self{
+        GimliStreamDecryptIter{
+            decryptor: GimliStreamDecryptor::new(key, nonce_prefix),
+            cipher_text: cipher_text.peekable(),
+            associated_data: associated_data.to_vec(),
+        }
+    }
+
+    fn next_segment(&mut self) -> Result<Vec<u8>, GimliError>{
+        let mut segment = Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16));
+        while segment.len() < SEGMENT_SIZE + 16 {
+            match self.cipher_text.next() {
+                Some(byte) => segment.push(byte?),
+                None => break,
+            }
+        }
+        if segment.len() < 16 {
+            return Err(GimliError::TruncatedCiphertext);
+        }
+        let last = self.cipher_text.peek().is_none();
+        let message_len = segment.len() - 16;
+        let tag = Tag::from_slice(&segment[message_len..])?;
+        segment.truncate(message_len);
+        self.decryptor.decrypt_segment(&mut segment, &self.associated_data, &tag, last)?;
+        Ok(std::mem::take(&mut segment))
+    }
+}
+
+impl Iterator for GimliStreamDecryptIter{
+    type Item = Result<Vec<u8>, GimliError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.decryptor.complete {
+            return None
+        }
+        let segment = self.next_segment();
+        if segment.is_err() {
+            self.decryptor.complete = true;
+        }
+        Some(segment)
+    }
+}
+
+/// Encrypts everything written to it into `writer` as a STREAM of segments.
+/// `finish` must be called to write the last segment; a stream that is dropped unfinished fails to decrypt.
+pub struct GimliEncryptWriter<W: Write>{
+    writer: W,
+    encryptor: GimliStreamEncryptor,
+    associated_data: Vec<u8>,
+    buffer: Zeroizing<Vec<u8>>,
+    pending: Zeroizing<Vec<u8>>,
+    written: usize,
+}
+
+impl<W: Write> GimliEncryptWriter<W>{
+    pub fn new(writer: W, key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], associated_data: &[u8]) -> Self{
+        GimliEncryptWriter{
+            writer,
+            encryptor: GimliStreamEncryptor::new(key, nonce_prefix),
+            associated_data: associated_data.to_vec(),
+            buffer: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16)),
+            pending: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE + 16)),
+            written: 0,
+        }
+    }
+
+    fn seal_segment(&mut self, last: bool) -> io::Result<()>{
+        self.encryptor.seal_buffered(&mut self.buffer, &self.associated_data, last)?;
+        std::mem::swap(&mut self.buffer, &mut self.pending);
+        self.buffer.clear();
+        self.written = 0;
+        Ok(())
+    }
+
+    // A sealed segment stays pending until the inner writer has taken all of it, so a failed write can be retried.
+    fn write_pending(&mut self) -> io::Result<()>{
+        while self.written < self.pending.len() {
+            match self.writer.write(&self.pending[self.written..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => self.written += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+        self.pending.clear();
+        self.written = 0;
+        Ok(())
+    }
+
+    /// Writes the last segment, flushes and returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W>{
+        self.write_pending()?;
+        self.seal_segment(true)?;
+        self.write_pending()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Write for GimliEncryptWriter<W>{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>{
+        self.write_pending()?;
+        if buf.is_empty() {
+            return Ok(0)
+        }
+        // A full segment is only sealed once more data arrives, as it may still turn out to be the last one.
+        if self.buffer.len() == SEGMENT_SIZE {
+            self.seal_segment(false)?;
+        }
+        let take = min(buf.len(), SEGMENT_SIZE - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..take]);
+        Ok(take)
+    }
+
+    fn flush(&mut self) -> io::Result<()>{
+        self.write_pending()?;
+        self.writer.flush()
+    }
+}
+
+/// Decrypts a STREAM of segments read from `reader`. Plain text is only returned once its segment has been verified.
+pub struct GimliDecryptReader<R: Read>{
+    reader: R,
+    decryptor: GimliStreamDecryptor,
+    associated_data: Vec<u8>,
+    input: Vec<u8>,
+    output: Zeroizing<Vec<u8>>,
+    position: usize,
+    failed: Option<io::ErrorKind>,
+}
+
+impl<R: Read> GimliDecryptReader<R>{
+    pub fn new(reader: R, key: &Key, nonce_prefix: &[u8; NONCE_PREFIX_SIZE], associated_data: &[u8]) -> Self{
+        GimliDecryptReader{
+            reader,
+            decryptor: GimliStreamDecryptor::new(key, nonce_prefix),
+            associated_data: associated_data.to_vec(),
+            input: Vec::with_capacity(SEGMENT_SIZE + 17),
+            output: Zeroizing::new(Vec::with_capacity(SEGMENT_SIZE)),
+            position: 0,
+            failed: None,
+        }
+    }
+
+    pub fn into_inner(self) -> R{
+        self.reader
+    }
+
+    // Reading one byte past the segment tells us whether it is the last one. Bytes read before an
+    // error stay in `input`, so the caller may retry.
+    fn fill_input(&mut self) -> io::Result<()>{
+        let mut chunk = [0; 1024];
+        while self.input.len() <= SEGMENT_SIZE + 16 {
+            let wanted = min(chunk.len(), SEGMENT_SIZE + 17 - self.input.len());
+            match self.reader.read(&mut chunk[..wanted]) {
+                Ok(0) => break,
+                Ok(n) => self.input.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for GimliDecryptReader<R>{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>{
+        if let Some(kind) = self.failed {
+            return Err(io::Error::new(kind, "Stream decryption already failed"));
+        }
+        while self.position == self.output.len() {
+            if self.decryptor.complete {
+                return Ok(0)
+            }
+            self.fill_input()?;
+            self.position = 0;
+            // Authentication and format errors end the stream; errors from the inner reader do not.
+            if let Err(e) = self.decryptor.open_buffered(&mut self.input, &mut self.output, &self.associated_data) {
+                let e: io::Error = e.into();
+                self.failed = Some(e.kind());
+                self.output.clear();
+                return Err(e);
+            }
+        }
+        let take = min(buf.len(), self.output.len() - self.position);
+        buf[..take].copy_from_slice(&self.output[self.position..self.position + take]);
+        self.position += take;
+        Ok(take)
+    }
+}
@@ -0,0 +1,151 @@
+//! A protocol framework in the style of STROBE, on the gimli duplex.
+//!
+//! Both parties of a protocol keep a `GimliStrobe` and run the same sequence of operations. Every operation
+//! is framed: its flags are absorbed and padded before its data, so the transcript records what kind of
+//! operation each piece of data belonged to and where it ended. Output (`prf`, `send_enc`, `send_mac`)
+//! depends on the whole transcript so far. This is not wire compatible with STROBE over Keccak.
+use crate::gimli_common::verify_tag_chunks;
+use crate::gimli_duplex::{GimliDuplex, GimliPadding};
+use crate::gimli_error::GimliError;
+
+// Operation flags, as in STROBE
+const FLAG_I: u8 = 0x01;
+const FLAG_A: u8 = 0x02;
+const FLAG_C: u8 = 0x04;
+const FLAG_T: u8 = 0x08;
+const FLAG_M: u8 = 0x10;
+
+// Ends the data of the previous operation together with the flags of the next one
+const FRAME: GimliPadding = GimliPadding{ first: 0x01, rate_end: 0, domain: 0x02 };
+
+const PROTOCOL_LABEL: &[u8] = b"gimli_rs strobe v1";
+
+/// Which side of the conversation this party is on. Decided by the first transport operation: the party
+/// that sends first is the initiator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GimliStrobeRole{
+    Initiator,
+    Responder,
+}
+
+/// Transcript of a two party protocol. See the module documentation.
+///
+/// Every data operation takes a `more` flag. With `more` set the data continues the previous operation, which
+/// must be of the same kind, so `ad(a, false); ad(b, true)` is the same as `ad(a || b, false)`.
+#[derive(Clone)]
+pub struct GimliStrobe{
+    duplex: GimliDuplex,
+    role: Option<GimliStrobeRole>,
+    current_flags: Option<u8>,
+}
+
+impl GimliStrobe{
+    /// Starts a transcript for `protocol`. Both parties must use the same protocol name.
+    pub fn new(protocol: &[u8]) -> Self{
+        let mut strobe = GimliStrobe{
+            duplex: GimliDuplex::new(),
+            role: None,
+            current_flags: None,
+        };
+        strobe.meta_ad(PROTOCOL_LABEL, false);
+        strobe.meta_ad(protocol, true);
+        strobe
+    }
+
+    /// The role of this party, once a transport operation has decided it.
+    pub fn role(&self) -> Option<GimliStrobeRole>{
+        self.role
+    }
+
+    fn begin(&mut self, flags: u8, more: bool){
+        if more {
+            assert_eq!(self.current_flags, Some(flags), "more requires the same operation as before");
+            return
+        }
+        self.current_flags = Some(flags);
+
+        // Both parties absorb the same flags for a message: the I flag is relative to the initiator
+        let mut framed = flags;
+        if flags & FLAG_T != 0 {
+            let receiving = flags & FLAG_I != 0;
+            let role = *self.role.get_or_insert(match receiving {
+                false => GimliStrobeRole::Initiator,
+                true => GimliStrobeRole::Responder,
+            });
+            if role == GimliStrobeRole::Responder {
+                framed ^= FLAG_I;
+            }
+        }
+        self.duplex.absorb(&[framed]);
+        self.duplex.pad(FRAME);
+    }
+
+    /// Absorbs associated data.
+    pub fn ad(&mut self, data: &[u8], more: bool){
+        self.begin(FLAG_A, more);
+        self.duplex.absorb(data);
+    }
+
+    /// Absorbs metadata, such as framing or lengths, kept apart from application data.
+    pub fn meta_ad(&mut self, data: &[u8], more: bool){
+        self.begin(FLAG_M | FLAG_A, more);
+        self.duplex.absorb(data);
+    }
+
+    /// Absorbs key material. Everything after this depends on the key.
+    pub fn key(&mut self, key: &[u8], more: bool){
+        self.begin(FLAG_A | FLAG_C, more);
+        self.duplex.absorb(key);
+    }
+
+    /// Fills `output` with pseudo random bytes derived from the transcript.
+    pub fn prf(&mut self, output: &mut [u8], more: bool){
+        self.begin(FLAG_I | FLAG_A | FLAG_C, more);
+        self.duplex.squeeze(output);
+    }
+
+    /// Absorbs a message sent in the clear.
+    pub fn send_clr(&mut self, data: &[u8], more: bool){
+        self.begin(FLAG_A | FLAG_T, more);
+        self.duplex.absorb(data);
+    }
+
+    /// Absorbs a message received in the clear.
+    pub fn recv_clr(&mut self, data: &[u8], more: bool){
+        self.begin(FLAG_I | FLAG_A | FLAG_T, more);
+        self.duplex.absorb(data);
+    }
+
+    /// Encrypts `buffer` in place for sending. Only confidential after `key`; follow with `send_mac`.
+    pub fn send_enc(&mut self, buffer: &mut [u8], more: bool){
+        self.begin(FLAG_A | FLAG_C | FLAG_T, more);
+        self.duplex.encrypt(buffer);
+    }
+
+    /// Decrypts a received `buffer` in place. The plain text is unauthenticated until `recv_mac` succeeds.
+    pub fn recv_enc(&mut self, buffer: &mut [u8], more: bool){
+        self.begin(FLAG_I | FLAG_A | FLAG_C | FLAG_T, more);
+        self.duplex.decrypt(buffer);
+    }
+
+    /// Writes a tag over the transcript so far into `tag`. `recv_mac` only accepts tags of `MIN_TAG_SIZE` bytes or more.
+    pub fn send_mac(&mut self, tag: &mut [u8]){
+        self.begin(FLAG_C | FLAG_T, false);
+        tag.fill(0);
+        self.duplex.encrypt(tag);
+    }
+
+    /// Checks a tag made by the other party's `send_mac`. Tags shorter than `MIN_TAG_SIZE` never verify. On failure
+    /// the transcripts have diverged and this object should be discarded.
+    pub fn recv_mac(&mut self, tag: &[u8]) -> Result<(), GimliError>{
+        self.begin(FLAG_I | FLAG_C | FLAG_T, false);
+        // Encrypting zeros, as send_mac does, keeps both transcripts the same
+        verify_tag_chunks(tag, |expected| self.duplex.encrypt(expected))
+    }
+
+    /// Erases the rate so that a later compromise of the state does not reveal earlier output.
+    pub fn ratchet(&mut self){
+        self.begin(FLAG_C, false);
+        self.duplex.ratchet();
+    }
+}
@@ -1,64 +1,225 @@
-use crate::gimli_common::gimli;
-use crate::gimli_decrypt::{gimli_aead_decrypt, GimliAeadDecryptIter};
-use crate::gimli_encrypt::{gimli_aead_encrypt, GimliAeadEncryptIter};
-use std::cmp::min;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use crate::gimli_common::read_block;
+use crate::gimli_common::{verify_tag_chunks, Key};
+use crate::gimli_duplex::{GimliDuplex, GimliPadding};
+use crate::gimli_error::GimliError;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use core::cmp::min;
+#[cfg(feature = "std")]
 use std::io;
 
 pub mod gimli_encrypt;
 pub mod gimli_decrypt;
 pub mod gimli_common;
+pub mod gimli_duplex;
+pub mod gimli_strobe;
+pub mod gimli_hmac;
+pub mod gimli_error;
+pub mod gimli_digest;
+pub mod gimli_aead;
+#[cfg(feature = "std")]
+pub mod gimli_stream;
+pub mod gimli_simd;
+#[cfg(all(feature = "cryptanalysis", not(gimli_cryptanalysis)))]
+compile_error!("The cryptanalysis feature exposes insecure reduced-round gimli. Also pass RUSTFLAGS=\"--cfg gimli_cryptanalysis\" if this is really wanted.");
+#[cfg(all(feature = "cryptanalysis", gimli_cryptanalysis))]
+pub mod gimli_cryptanalysis;
+#[cfg(feature = "tokio")]
+pub mod gimli_async;
 
+#[cfg(feature = "std")]
 static RATE_IN_BYTES: u64 = 16;
 
-pub fn gimli_hash(mut input:  impl Iterator<Item = Result<u8, io::Error>>, mut input_byte_len: u64, mut output_byte_len: u64) -> Vec<u8> {
-    let mut state: [u32; 12] = [0; 12];
-    let mut block_size: u64 = 0;
+#[derive(Clone)]
+pub struct GimliHasher{
+    duplex: GimliDuplex,
+}
+
+impl Default for GimliHasher{
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    while input_byte_len > 0 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        block_size = min(input_byte_len, RATE_IN_BYTES);
-        for i in 0..block_size {
-            state_8[i as usize] ^= input.next().unwrap().expect("Read error on input");
+impl GimliHasher{
+    pub fn new() -> Self{
+        GimliHasher{
+            duplex: GimliDuplex::new(),
         }
-        input_byte_len -= block_size;
+    }
+
+    /// Absorbs `input` into the hash state. May be called any number of times.
+    pub fn update(&mut self, input: &[u8]){
+        self.duplex.absorb(input);
+    }
+
+    /// Pads the absorbed input and squeezes `output_byte_len` bytes of hash output.
+    #[cfg(feature = "alloc")]
+    pub fn finalize(self, output_byte_len: usize) -> Vec<u8>{
+        let mut output = vec![0; output_byte_len];
+        self.finalize_xof().squeeze(&mut output);
+        output
+    }
 
-        if block_size == RATE_IN_BYTES {
-            gimli(&mut state);
-            block_size = 0;
+    /// Pads the absorbed input and returns a reader over the unbounded hash output.
+    pub fn finalize_xof(mut self) -> GimliXofReader{
+        self.duplex.pad(GimliPadding::HASH);
+        GimliXofReader{
+            duplex: self.duplex,
         }
     }
+}
+
+/// Extendable output of Gimli-Hash. Blocks are squeezed lazily as output is read.
+#[derive(Clone)]
+pub struct GimliXofReader{
+    duplex: GimliDuplex,
+}
+
+impl GimliXofReader{
+    /// Fills `output` with the next bytes of hash output.
+    pub fn squeeze(&mut self, output: &mut [u8]){
+        self.duplex.squeeze(output);
+    }
+}
 
-    let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-    state_8[block_size as usize] ^= 0x1F;
-    state_8[(RATE_IN_BYTES - 1) as usize] ^= 0x80;
-    gimli(&mut state); // Calling gimli invalidates other references to state. ie stats_8
+/// Keyed Gimli-Hash for authenticating messages that stay in the clear. The key is absorbed and padded under
+/// its own domain before the message, so a tag can not be mistaken for a plain hash. Tags may have any length
+/// from `MIN_TAG_SIZE` bytes up.
+#[derive(Clone)]
+pub struct GimliMac{
+    duplex: GimliDuplex,
+}
 
-    let mut output: Vec<u8> = Vec::with_capacity(output_byte_len as usize);
-    while output_byte_len > 0 {
-        let state_8 = unsafe {std::slice::from_raw_parts_mut(state.as_mut_ptr() as *mut u8, 48)};
-        block_size = min(output_byte_len, RATE_IN_BYTES);
-        output.extend_from_slice(&state_8[..block_size as usize]);
-        output_byte_len -= block_size;
-        if output_byte_len > 0 {
-            gimli(&mut state);
+impl GimliMac{
+    pub fn new(key: &Key) -> Self{
+        let mut duplex = GimliDuplex::new();
+        duplex.absorb(key.as_bytes());
+        duplex.pad(GimliPadding::MAC);
+        GimliMac{
+            duplex,
         }
     }
-    return output;
+
+    /// Absorbs `input` into the MAC state. May be called any number of times.
+    pub fn update(&mut self, input: &[u8]){
+        self.duplex.absorb(input);
+    }
+
+    /// Writes the tag for the absorbed input into `tag`. `verify` only accepts tags of `MIN_TAG_SIZE` bytes or more.
+    pub fn finalize_into(mut self, tag: &mut [u8]){
+        self.duplex.pad(GimliPadding::MAC);
+        self.duplex.squeeze(tag);
+    }
+
+    /// Returns a tag of `tag_byte_len` bytes for the absorbed input.
+    #[cfg(feature = "alloc")]
+    pub fn finalize(self, tag_byte_len: usize) -> Vec<u8>{
+        let mut tag = vec![0; tag_byte_len];
+        self.finalize_into(&mut tag);
+        tag
+    }
+
+    /// Checks `tag` against the absorbed input in constant time. Tags are compared at the length given, but
+    /// tags shorter than `MIN_TAG_SIZE` never verify.
+    pub fn verify(mut self, tag: &[u8]) -> Result<(), GimliError>{
+        self.duplex.pad(GimliPadding::MAC);
+        verify_tag_chunks(tag, |expected| self.duplex.squeeze(expected))
+    }
+}
+
+/// Derives `output.len()` bytes of key material from `input_key_material`, in the style of BLAKE3's `derive_key`.
+/// `context` should be a hard coded string unique to the application and purpose, for example
+/// `"example.com 2026-10-18 session tokens"`; different contexts give independent keys. The context and the key
+/// material are each padded under their own domain, apart from plain hashing. This is not a password hash: it
+/// does nothing to slow down guessing of weak input.
+pub fn derive_key_into(context: &str, input_key_material: &[u8], output: &mut [u8]){
+    let mut duplex = GimliDuplex::new();
+    duplex.absorb(context.as_bytes());
+    duplex.pad(GimliPadding::DERIVE_KEY_CONTEXT);
+    duplex.absorb(input_key_material);
+    duplex.pad(GimliPadding::DERIVE_KEY_MATERIAL);
+    duplex.squeeze(output);
+}
+
+/// Returns `output_byte_len` bytes from `derive_key_into`.
+#[cfg(feature = "alloc")]
+pub fn derive_key(context: &str, input_key_material: &[u8], output_byte_len: usize) -> Vec<u8>{
+    let mut output = vec![0; output_byte_len];
+    derive_key_into(context, input_key_material, &mut output);
+    output
+}
+
+#[cfg(feature = "std")]
+impl io::Write for GimliHasher{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>{
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()>{
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Read for GimliXofReader{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>{
+        self.squeeze(buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn gimli_hash(mut input:  impl Iterator<Item = Result<u8, io::Error>>, input_byte_len: u64, output_byte_len: u64) -> Result<Vec<u8>, GimliError> {
+    let mut hasher = GimliHasher::new();
+    let mut block = [0u8; RATE_IN_BYTES as usize];
+    let mut read: u64 = 0;
+
+    while read < input_byte_len {
+        let block_size = min(input_byte_len - read, RATE_IN_BYTES) as usize;
+        read_block(&mut input, &mut block[..block_size], input_byte_len as usize, read as usize)?;
+        hasher.update(&block[..block_size]);
+        read += block_size as u64;
+    }
+
+    Ok(hasher.finalize(output_byte_len as usize))
 }
 
 
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests{
     use super::*;
+    use crate::gimli_common::{Key, Nonce, Tag};
+    use crate::gimli_decrypt::{gimli_aead_decrypt, GimliAeadDecryptIter};
+    use crate::gimli_encrypt::{gimli_aead_encrypt, GimliAeadEncryptIter};
     mod cipher_test;
-    use crate::tests::cipher_test::cipher_test::get_cipher_vectors;
+    mod stream_test;
+    mod state_test;
+    mod duplex_test;
+    mod strobe_test;
+    mod hmac_test;
+    mod simd_test;
+    mod tag_test;
+    #[cfg(all(feature = "cryptanalysis", gimli_cryptanalysis))]
+    mod cryptanalysis_test;
+    #[cfg(feature = "tokio")]
+    mod async_test;
+    use crate::tests::cipher_test::get_cipher_vectors;
 
     #[test]
     fn hash_test(){
         // (Plaintext, hash, hash_len)
-        let hash_vectors = vec![
+        let hash_vectors = [
             ("There's plenty for the both of us, may the best Dwarf win.", "4afb3ff784c7ad6943d49cf5da79facfa7c4434e1ce44f5dd4b28f91a84d22c8", 32, ),
             ("If anyone was to ask for my opinion, which I note they're not, I'd say we were taking the long way around.", "ba82a16a7b224c15bed8e8bdc88903a4006bc7beda78297d96029203ef08e07c", 32),
             ("Speak words we can all understand!", "8dd4d132059b72f8e8493f9afb86c6d86263e7439fc64cbb361fcbccf8b01267", 32),
@@ -73,9 +234,9 @@ mod tests{
                     .to_string()
                     .into_bytes()
                     .into_iter()
-                    .map(|x| Ok(x)),
+                    .map(Ok),
                 input_len,
-                vec.2).iter().map(|x| format!("{:02x?}", x)).collect::<String>()
+                vec.2).expect("Error in test hash").iter().map(|x| format!("{:02x?}", x)).collect::<String>()
             )
         }
     }
@@ -84,57 +245,441 @@ mod tests{
     fn test_cipher(){
 
         // Test key = 000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F
-        let key = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F];
+        let key = Key::from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F").expect("Invalid test key");
         // Test nonce = 000102030405060708090A0B0C0D0E0F
-        let nonce = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+        let nonce = Nonce::from_hex("000102030405060708090A0B0C0D0E0F").expect("Invalid test nonce");
         // (Plaintext, AD, Ciphertext)
         let cipher_vectors = get_cipher_vectors();
 
         for vec in cipher_vectors.iter(){
             let pt_len = vec.0.len();
-            let pt = vec.0.clone().into_iter().map(|x| Ok(x));
+            let pt = vec.0.clone().into_iter().map(Ok);
             let assoc_d = &vec.1;
-            let ct = vec.2.clone().into_iter().map(|x| Ok(x));
+            let ct = vec.2.clone().into_iter().map(Ok);
             let ct_len = vec.2.len();
 
             let ge_iter = GimliAeadEncryptIter::new(
-                key,
-                nonce,
-                pt_len,
+                &key,
+                &nonce,
+                assoc_d,
                 Box::new(pt.clone()),
-                assoc_d);
-            let result: Vec<u8> = ge_iter.collect();
+                pt_len);
+            let result: Vec<u8> = ge_iter.collect::<Result<_, _>>().expect("Error in test encryption");
             assert_eq!(vec.2, result);
 
 
             assert_eq!(vec.2, gimli_aead_encrypt(
-                pt.clone(),
-                pt_len,
-                assoc_d,
+                &key,
                 &nonce,
-                &key));
+                assoc_d,
+                pt.clone(),
+                pt_len).expect("Error in test encryption"));
 
             let gd_iter = GimliAeadDecryptIter::new(
-                key,
-                nonce,
-                ct_len,
-                Box::new(ct.clone()),
+                &key,
+                &nonce,
                 assoc_d,
-                );
-            let pt: Vec<u8> = gd_iter.collect();
-            assert_eq!(pt, pt);
-            assert_eq!(pt, gimli_aead_decrypt(
-                ct,
+                Box::new(ct.clone()),
                 ct_len,
-                &vec.1,
+                ).expect("Error in test decryption");
+            let pt: Vec<u8> = gd_iter.collect::<Result<_, _>>().expect("Error in test decryption");
+            assert_eq!(vec.0, pt);
+            assert_eq!(vec.0, gimli_aead_decrypt(
+                &key,
                 &nonce,
-                &key).expect("Error in test decryption"));
+                &vec.1,
+                ct,
+                ct_len).expect("Error in test decryption"));
         }
     }
-}
 
+    #[test]
+    fn test_decrypt_errors(){
+        let key = Key::new([7u8; 32]);
+        let nonce = Nonce::new([9u8; 16]);
+        let message = b"Speak words we can all understand!".to_vec();
+        let ct = gimli_aead_encrypt(&key, &nonce, b"ad", message.clone().into_iter().map(Ok), message.len())
+            .expect("Error in test encryption");
+
+        let mut tampered = ct.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        match gimli_aead_decrypt(&key, &nonce, b"ad", tampered.clone().into_iter().map(Ok), tampered.len()) {
+            Err(GimliError::TagMismatch) => {},
+            other => panic!("Expected tag mismatch, got {:?}", other),
+        }
+        let iter_result: Result<Vec<u8>, GimliError> = GimliAeadDecryptIter::new(
+            &key, &nonce, b"ad", Box::new(tampered.clone().into_iter().map(Ok)), tampered.len())
+            .expect("Error in test decryption")
+            .collect();
+        match iter_result {
+            Err(GimliError::TagMismatch) => {},
+            other => panic!("Expected tag mismatch, got {:?}", other),
+        }
+
+        let short = ct[..15].to_vec();
+        match gimli_aead_decrypt(&key, &nonce, b"ad", short.iter().copied().map(Ok), 15) {
+            Err(GimliError::TruncatedCiphertext) => {},
+            other => panic!("Expected truncated cipher text, got {:?}", other),
+        }
+        assert!(GimliAeadDecryptIter::new(&key, &nonce, b"ad", Box::new(short.into_iter().map(Ok)), 15).is_err());
+
+        match gimli_aead_decrypt(&key, &nonce, b"ad", ct[..20].iter().copied().map(Ok), ct.len()) {
+            Err(GimliError::LengthMismatch { expected, actual }) => {
+                assert_eq!(expected, ct.len());
+                assert_eq!(actual, 20);
+            },
+            other => panic!("Expected length mismatch, got {:?}", other),
+        }
+
+        let failing = ct.clone().into_iter().take(3).map(Ok).chain(std::iter::once(Err(io::Error::other("disk on fire"))));
+        match gimli_aead_decrypt(&key, &nonce, b"ad", failing, ct.len()) {
+            Err(GimliError::Io(e)) => assert_eq!(e.to_string(), "disk on fire"),
+            other => panic!("Expected io error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_length_mismatch(){
+        let input = b"short".to_vec();
+        match gimli_hash(input.clone().into_iter().map(Ok), 10, 32) {
+            Err(GimliError::LengthMismatch { expected: 10, actual: 5 }) => {},
+            other => panic!("Expected length mismatch, got {:?}", other),
+        }
+        match gimli_aead_encrypt(&Key::new([0; 32]), &Nonce::new([0; 16]), &[], input.into_iter().map(Ok), 10) {
+            Err(GimliError::LengthMismatch { expected: 10, actual: 5 }) => {},
+            other => panic!("Expected length mismatch, got {:?}", other),
+        }
+    }
 
+    #[test]
+    fn test_hasher_streaming(){
+        let message = b"It's true you don't see many Dwarf-women. And in fact, they are so alike in voice and appearance, that they are often mistaken for Dwarf-men.";
+        let expected = gimli_hash(message.iter().copied().map(Ok), message.len() as u64, 32).expect("Error in test hash");
 
+        for split in [0, 1, 15, 16, 17, 33, message.len()].iter() {
+            let mut hasher = GimliHasher::new();
+            hasher.update(&message[..*split]);
+            hasher.update(&[]);
+            hasher.update(&message[*split..]);
+            assert_eq!(expected, hasher.finalize(32));
+        }
 
+        let mut byte_hasher = GimliHasher::new();
+        for byte in message.iter() {
+            byte_hasher.update(&[*byte]);
+        }
+        assert_eq!(expected, byte_hasher.finalize(32));
+    }
 
+    #[test]
+    fn test_hasher_fork(){
+        let mut prefix = GimliHasher::new();
+        prefix.update(b"Speak words ");
+        let mut fork = prefix.clone();
+        prefix.update(b"we can all understand!");
+        fork.update(b"we cannot understand!");
 
+        assert_eq!("8dd4d132059b72f8e8493f9afb86c6d86263e7439fc64cbb361fcbccf8b01267",
+            prefix.finalize(32).iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+        let forked = b"Speak words we cannot understand!";
+        assert_eq!(gimli_hash(forked.iter().copied().map(Ok), forked.len() as u64, 32).expect("Error in test hash"),
+            fork.finalize(32));
+    }
+
+    #[test]
+    fn test_xof_reader(){
+        use std::io::Read;
+        let message = b"There's plenty for the both of us, may the best Dwarf win.";
+        let mut hasher = GimliHasher::new();
+        hasher.update(message);
+        let expected = hasher.clone().finalize(100);
+
+        let mut reader = hasher.finalize_xof();
+        let mut output = Vec::new();
+        for chunk_len in [1, 15, 16, 3, 33, 32].iter() {
+            let mut chunk = vec![0; *chunk_len];
+            reader.read_exact(&mut chunk).expect("Error reading xof");
+            output.extend_from_slice(&chunk);
+        }
+        assert_eq!(expected, output);
+        assert_eq!("4afb3ff784c7ad6943d49cf5da79facfa7c4434e1ce44f5dd4b28f91a84d22c8",
+            output[..32].iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+
+        let mut long_output = Vec::new();
+        let mut long_hasher = GimliHasher::new();
+        long_hasher.update(message);
+        long_hasher.finalize_xof().take(100_000).read_to_end(&mut long_output).expect("Error reading xof");
+        assert_eq!(100_000, long_output.len());
+        assert_eq!(expected, long_output[..100]);
+    }
+
+    #[test]
+    fn test_mac(){
+        let key = Key::new([0x42; 32]);
+        let message = b"There's plenty for the both of us, may the best Dwarf win.";
+        let mut mac = GimliMac::new(&key);
+        mac.update(message);
+        let tag = mac.clone().finalize(32);
+        assert_eq!("0c11cf5691d7cb67b179c946af6cf17235670f540ac0b392501ff62e1a3ccb6c", tag.iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+
+        for split in [0, 1, 16, 17, message.len()].iter() {
+            let mut split_mac = GimliMac::new(&key);
+            split_mac.update(&message[..*split]);
+            split_mac.update(&message[*split..]);
+            assert_eq!(tag, split_mac.finalize(32));
+        }
+        mac.clone().verify(&tag).expect("Tag should verify");
+        assert!(mac.clone().verify(&[]).is_err());
+        assert_eq!(tag[..16], mac.clone().finalize(16)[..]);
+
+        // Neither another key nor the plain hash of key and message give the same tag
+        let mut other = GimliMac::new(&Key::new([0x43; 32]));
+        other.update(message);
+        assert_ne!(tag, other.finalize(32));
+        let mut hasher = GimliHasher::new();
+        hasher.update(key.as_bytes());
+        hasher.update(message);
+        assert_ne!(tag, hasher.finalize(32));
+    }
+
+    #[test]
+    fn test_derive_key(){
+        let context = "gimli_rs 2026-10-18 derive_key test";
+        let key = derive_key(context, b"input key material", 32);
+        assert_eq!("11d8c845d085a702787853d2722aba7183a1c747e1f87f349f9133dcd28a9487", key.iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+
+        let mut longer = [0; 50];
+        derive_key_into(context, b"input key material", &mut longer);
+        assert_eq!(key[..], longer[..32]);
+
+        // Context and key material are kept apart from each other and from the plain hash
+        assert_ne!(key, derive_key("gimli_rs 2026-10-18 derive_key other", b"input key material", 32));
+        assert_ne!(key, derive_key(context, b"other key material", 32));
+        assert_ne!(key, derive_key("gimli_rs 2026-10-18 derive_key testinput", b" key material", 32));
+        let mut hasher = GimliHasher::new();
+        hasher.update(context.as_bytes());
+        hasher.update(b"input key material");
+        assert_ne!(key, hasher.finalize(32));
+    }
+
+    #[test]
+    fn test_digest_traits(){
+        use crate::gimli_digest::{GimliHash256, GimliHashXof};
+        use digest::{Digest, ExtendableOutput, Mac, XofReader};
+        use hmac::SimpleHmac;
+
+        let message = b"Speak words we can all understand!";
+        let expected = "8dd4d132059b72f8e8493f9afb86c6d86263e7439fc64cbb361fcbccf8b01267";
+        assert_eq!(expected, GimliHash256::digest(message).iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+
+        let mut digest = GimliHash256::new();
+        Digest::update(&mut digest, &message[..5]);
+        Digest::update(&mut digest, &message[5..]);
+        assert_eq!(expected, digest.finalize_reset().iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+        assert_eq!(GimliHash256::digest(b""), digest.finalize());
+
+        let mut xof = GimliHashXof::default();
+        digest::Update::update(&mut xof, message);
+        let mut reader = xof.finalize_xof();
+        let mut output = [0u8; 48];
+        reader.read(&mut output[..7]);
+        reader.read(&mut output[7..]);
+        let mut hasher = GimliHasher::new();
+        hasher.update(message);
+        assert_eq!(hasher.finalize(48), output.to_vec());
+
+        let mut mac = <SimpleHmac<GimliHash256> as Mac>::new_from_slice(&[0x0b; 100]).expect("Error creating hmac");
+        mac.update(message);
+        let tag = mac.finalize().into_bytes();
+        let mut verifier = <SimpleHmac<GimliHash256> as Mac>::new_from_slice(&[0x0b; 100]).expect("Error creating hmac");
+        verifier.update(message);
+        assert!(verifier.verify_slice(&tag).is_ok());
+    }
+
+    #[test]
+    fn test_aead_traits(){
+        use crate::gimli_aead::GimliCipher;
+        use aead::{Aead, AeadInPlace, KeyInit, Payload};
+
+        fn roundtrip<A: Aead + AeadInPlace + KeyInit>(key: &[u8], nonce: &[u8], pt: &[u8], ad: &[u8]) -> Vec<u8> {
+            let cipher = A::new_from_slice(key).expect("Error creating cipher");
+            let nonce = aead::Nonce::<A>::from_slice(nonce);
+            let ct = cipher.encrypt(nonce, Payload { msg: pt, aad: ad }).expect("Error in test encryption");
+            assert_eq!(pt, &cipher.decrypt(nonce, Payload { msg: &ct, aad: ad }).expect("Error in test decryption")[..]);
+
+            let mut tampered = ct.clone();
+            tampered[0] ^= 0x80;
+            assert!(cipher.decrypt(nonce, Payload { msg: &tampered, aad: ad }).is_err());
+            assert!(cipher.decrypt(nonce, Payload { msg: &ct[..ct.len() - 1], aad: ad }).is_err());
+
+            let mut buffer = pt.to_vec();
+            cipher.encrypt_in_place(nonce, ad, &mut buffer).expect("Error in test encryption");
+            assert_eq!(ct, buffer);
+            cipher.decrypt_in_place(nonce, ad, &mut buffer).expect("Error in test decryption");
+            assert_eq!(pt, &buffer[..]);
+            ct
+        }
+
+        let key: Vec<u8> = (0..32).collect();
+        let nonce: Vec<u8> = (0..16).collect();
+        for vec in get_cipher_vectors().iter(){
+            assert_eq!(vec.2, roundtrip::<GimliCipher>(&key, &nonce, &vec.0, &vec.1));
+        }
+    }
+
+    #[test]
+    fn test_in_place_detached(){
+        use crate::gimli_decrypt::decrypt_in_place_detached;
+        use crate::gimli_encrypt::encrypt_in_place_detached;
+
+        let key: Vec<u8> = (0..32).collect();
+        let key = Key::from_slice(&key).expect("Invalid test key");
+        let nonce = Nonce::new([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F]);
+
+        for vec in get_cipher_vectors().iter(){
+            let pt_len = vec.0.len();
+            let mut buffer = vec.0.clone();
+            let tag = encrypt_in_place_detached(&key, &nonce, &vec.1, &mut buffer);
+            assert_eq!(vec.2[..pt_len], buffer[..]);
+            assert_eq!(vec.2[pt_len..], tag.as_bytes()[..]);
+
+            decrypt_in_place_detached(&key, &nonce, &vec.1, &mut buffer, &tag).expect("Error in test decryption");
+            assert_eq!(vec.0, buffer);
+
+            let mut bad_tag = *tag.as_bytes();
+            bad_tag[15] ^= 1;
+            let mut buffer = vec.2[..pt_len].to_vec();
+            match decrypt_in_place_detached(&key, &nonce, &vec.1, &mut buffer, &Tag::new(bad_tag)) {
+                Err(GimliError::TagMismatch) => assert!(buffer.iter().all(|b| *b == 0)),
+                other => panic!("Expected tag mismatch, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_types(){
+        let key = Key::from_hex("000102030405060708090a0b0c0d0e0f101112131415161718191A1B1C1D1E1F").expect("Invalid test key");
+        let bytes: Vec<u8> = (0..32).collect();
+        assert_eq!(key.as_bytes()[..], bytes[..]);
+        assert_eq!(Key::from_slice(&bytes).expect("Invalid test key").as_bytes(), key.as_bytes());
+        assert_eq!(format!("{:?}", key), "Key(<redacted>)");
+
+        let nonce = Nonce::from_hex("000102030405060708090A0B0C0D0E0F").expect("Invalid test nonce");
+        assert_eq!(format!("{:?}", nonce), "Nonce(000102030405060708090a0b0c0d0e0f)");
+        let tag = Tag::from_slice(&bytes[..16]).expect("Invalid test tag");
+        assert_eq!(tag, Tag::from_hex("000102030405060708090a0b0c0d0e0f").expect("Invalid test tag"));
+        assert_ne!(tag, Tag::new([0; 16]));
+        assert_eq!(format!("{:?}", tag), "Tag(000102030405060708090a0b0c0d0e0f)");
+
+        match Key::from_slice(&bytes[..31]) {
+            Err(GimliError::InvalidLength { expected: 32, actual: 31 }) => {},
+            other => panic!("Expected invalid length, got {:?}", other),
+        }
+        match Nonce::from_hex("000102030405060708090A0B0C0D0E0") {
+            Err(e @ GimliError::InvalidHexLength { expected: 32, actual: 31 }) => {
+                assert_eq!("Invalid hex length: expected 32 digits, got 31", e.to_string());
+            },
+            other => panic!("Expected invalid hex length, got {:?}", other),
+        }
+        match Tag::from_hex("000102030405060708090A0B0C0D0Eg0") {
+            Err(GimliError::InvalidHex) => {},
+            other => panic!("Expected invalid hex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_zeroize(){
+        use crate::gimli_common::GimliState;
+        use zeroize::Zeroize;
+
+        let mut key = Key::new([0x42; 32]);
+        key.zeroize();
+        assert_eq!(key.as_bytes(), &[0; 32]);
+
+        let mut nonce = Nonce::from([0x24; 16]);
+        nonce.zeroize();
+        assert_eq!(nonce.as_bytes(), &[0; 16]);
+
+        let mut state = GimliState::from_words([0x9e3779b9; 12]);
+        state.zeroize();
+        assert_eq!(state.words(), &[0; 12]);
+    }
+
+    #[test]
+    fn test_batch_encryption(){
+        use crate::gimli_encrypt::{encrypt_batch_in_place_detached, encrypt_in_place_detached, GimliAeadBatchItem};
+
+        let keys: Vec<Key> = (0..20).map(|i| Key::new([i as u8; 32])).collect();
+        let nonces: Vec<Nonce> = (0..20).map(|i| Nonce::new([0x80 | i as u8; 16])).collect();
+        let ads: Vec<Vec<u8>> = (0..20).map(|i| (0..i * 5).map(|b| b as u8).collect()).collect();
+        let messages: Vec<Vec<u8>> = (0..20).map(|i| (0..(i * 37) % 70).map(|b| b as u8).collect()).collect();
+
+        for count in [0, 1, 2, 3, 5, 20] {
+            let mut buffers = messages[..count].to_vec();
+            let mut items: Vec<GimliAeadBatchItem> = buffers.iter_mut().enumerate().map(|(i, buffer)| GimliAeadBatchItem{
+                key: &keys[i],
+                nonce: &nonces[i],
+                associated_data: &ads[i],
+                buffer,
+            }).collect();
+            let tags = encrypt_batch_in_place_detached(&mut items);
+            assert_eq!(tags.len(), count);
+
+            for i in 0..count {
+                let mut expected = messages[i].clone();
+                let expected_tag = encrypt_in_place_detached(&keys[i], &nonces[i], &ads[i], &mut expected);
+                assert_eq!(buffers[i], expected);
+                assert_eq!(tags[i], expected_tag);
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_api(){
+        use crate::gimli_decrypt::GimliAeadDecryptor;
+        use crate::gimli_encrypt::GimliAeadEncryptor;
+
+        let mut key = [0; 32];
+        key.iter_mut().enumerate().for_each(|(i, k)| *k = i as u8);
+        let key = Key::new(key);
+        let mut nonce = [0; 16];
+        nonce.iter_mut().enumerate().for_each(|(i, n)| *n = i as u8);
+        let nonce = Nonce::new(nonce);
+
+        for vec in get_cipher_vectors().iter(){
+            let pt_len = vec.0.len();
+            let full = pt_len - pt_len % 16;
+
+            let mut buffer = vec.0.clone();
+            let mut encryptor = GimliAeadEncryptor::new(&key, &nonce, &vec.1);
+            for block in buffer[..full].chunks_mut(16) {
+                encryptor.encrypt_blocks(block);
+            }
+            let tag = encryptor.finalize(&mut buffer[full..]);
+            assert_eq!(vec.2[..pt_len], buffer[..]);
+            assert_eq!(vec.2[pt_len..], tag.as_bytes()[..]);
+
+            let mut decryptor = GimliAeadDecryptor::new(&key, &nonce, &vec.1);
+            decryptor.decrypt_blocks(&mut buffer[..full]);
+            decryptor.finalize(&mut buffer, &tag).expect("Error in test decryption");
+            assert_eq!(vec.0, buffer);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_block_api_rejects_partial_blocks(){
+        let mut encryptor = crate::gimli_encrypt::GimliAeadEncryptor::new(&Key::new([0; 32]), &Nonce::new([0; 16]), &[]);
+        encryptor.encrypt_blocks(&mut [0; 17]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_block_api_requires_whole_message(){
+        let mut decryptor = crate::gimli_decrypt::GimliAeadDecryptor::new(&Key::new([0; 32]), &Nonce::new([0; 16]), &[]);
+        let mut buffer = [0; 40];
+        decryptor.decrypt_blocks(&mut buffer[..32]);
+        let _ = decryptor.finalize(&mut buffer[32..], &Tag::new([0; 16]));
+    }
+}
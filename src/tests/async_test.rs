@@ -0,0 +1,112 @@
+use crate::gimli_common::Key;
+use crate::gimli_async::{GimliAsyncDecryptReader, GimliAsyncEncryptWriter};
+use crate::gimli_stream::{GimliEncryptWriter, SEGMENT_SIZE};
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{duplex, AsyncRead, AsyncReadExt, AsyncWriteExt, ReadBuf};
+
+const KEY: Key = Key::new([0x42; 32]);
+const NONCE_PREFIX: [u8; 11] = [0x24; 11];
+
+#[tokio::test]
+async fn test_async_duplex_roundtrip(){
+    for len in [0, 10, SEGMENT_SIZE, SEGMENT_SIZE + 1, 4 * SEGMENT_SIZE + 123].iter() {
+        let message: Vec<u8> = (0..*len).map(|i| (i % 253) as u8).collect();
+        let (client, server) = duplex(100);
+
+        let to_send = message.clone();
+        let sender = async move {
+            let mut writer = GimliAsyncEncryptWriter::new(client, &KEY, &NONCE_PREFIX, b"ad");
+            for chunk in to_send.chunks(333) {
+                writer.write_all(chunk).await.expect("Error in test encryption");
+            }
+            writer.shutdown().await.expect("Error in test encryption");
+        };
+        let receiver = async move {
+            let mut reader = GimliAsyncDecryptReader::new(server, &KEY, &NONCE_PREFIX, b"ad");
+            let mut plain_text = Vec::new();
+            reader.read_to_end(&mut plain_text).await.expect("Error in test decryption");
+            plain_text
+        };
+        let (_, plain_text) = tokio::join!(sender, receiver);
+        assert_eq!(message, plain_text);
+    }
+}
+
+#[tokio::test]
+async fn test_async_matches_sync_format(){
+    let message = vec![9u8; 2 * SEGMENT_SIZE + 7];
+    let mut sync_writer = GimliEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, &[]);
+    sync_writer.write_all(&message).expect("Error in test encryption");
+    let expected = sync_writer.finish().expect("Error in test encryption");
+
+    let mut writer = GimliAsyncEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, &[]);
+    writer.write_all(&message).await.expect("Error in test encryption");
+    writer.shutdown().await.expect("Error in test encryption");
+    assert_eq!(expected, writer.into_inner());
+}
+
+#[tokio::test]
+async fn test_async_rejects_tampering(){
+    let message = vec![5u8; 2 * SEGMENT_SIZE];
+    let mut writer = GimliAsyncEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, &[]);
+    writer.write_all(&message).await.expect("Error in test encryption");
+    writer.shutdown().await.expect("Error in test encryption");
+    let mut cipher_text = writer.into_inner();
+    let last = cipher_text.len() - 1;
+    cipher_text[last] ^= 1;
+
+    let (mut client, server) = duplex(64);
+    let sender = async move {
+        client.write_all(&cipher_text).await.expect("Error writing cipher text");
+        client.shutdown().await.expect("Error writing cipher text");
+    };
+    let receiver = async move {
+        let mut reader = GimliAsyncDecryptReader::new(server, &KEY, &NONCE_PREFIX, &[]);
+        let mut plain_text = Vec::new();
+        let result = reader.read_to_end(&mut plain_text).await;
+        (result, plain_text)
+    };
+    let (_, (result, plain_text)) = tokio::join!(sender, receiver);
+    assert_eq!(io::ErrorKind::InvalidData, result.expect_err("Tampered stream should fail").kind());
+    assert_eq!(message[..SEGMENT_SIZE], plain_text[..]);
+}
+
+// Fails one read once `fail_after` bytes have been returned
+struct FlakyReader{
+    data: Vec<u8>,
+    read: usize,
+    fail_after: Option<usize>,
+}
+
+impl AsyncRead for FlakyReader{
+    fn poll_read(mut self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>>{
+        if let Some(fail_after) = self.fail_after {
+            if self.read >= fail_after {
+                self.fail_after = None;
+                return Poll::Ready(Err(io::ErrorKind::TimedOut.into()))
+            }
+        }
+        let take = std::cmp::min(std::cmp::min(buf.remaining(), 500), self.data.len() - self.read);
+        buf.put_slice(&self.data[self.read..self.read + take]);
+        self.read += take;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn test_async_reader_retries_after_inner_failure(){
+    let message = vec![4u8; 2 * SEGMENT_SIZE + 10];
+    let mut writer = GimliEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, &[]);
+    writer.write_all(&message).expect("Error in test encryption");
+    let cipher_text = writer.finish().expect("Error in test encryption");
+
+    let flaky = FlakyReader{ data: cipher_text, read: 0, fail_after: Some(SEGMENT_SIZE) };
+    let mut reader = GimliAsyncDecryptReader::new(flaky, &KEY, &NONCE_PREFIX, &[]);
+    let mut plain_text = Vec::new();
+    let error = reader.read_to_end(&mut plain_text).await.expect_err("Inner reader should fail once");
+    assert_eq!(io::ErrorKind::TimedOut, error.kind());
+    reader.read_to_end(&mut plain_text).await.expect("Error in test decryption");
+    assert_eq!(message, plain_text);
+}
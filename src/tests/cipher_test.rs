@@ -1,1094 +1,1092 @@
-pub mod cipher_test{
-    pub fn get_cipher_vectors() -> Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> {
-        return vec![
-        (vec![], vec![], vec![0x14,0xDA,0x9B,0xB7,0x12,0x0B,0xF5,0x8B,0x98,0x5A,0x8E,0x00,0xFD,0xEB,0xA1,0x5B]),
-        (vec![], vec![0x00], vec![0xE8,0xD5,0x04,0x53,0xF8,0x4B,0x57,0x54,0x12,0x32,0x7D,0x7C,0x03,0x02,0xD8,0xD3]),
-        (vec![],vec![0x00,0x01,],vec![0x77,0x6F,0x82,0x9E,0xB5,0xDE,0x73,0xD4,0x00,0xEF,0x4D,0xED,0xB2,0xE2,0x77,0x2D,]),
-        (vec![],vec![0x00,0x01,0x02,],vec![0xAF,0xAD,0x61,0x1B,0xA2,0xDC,0xF5,0x1F,0x05,0xB1,0x31,0xA0,0xDA,0x08,0x11,0x2E,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,],vec![0xC4,0xEB,0x15,0x0E,0x92,0x18,0x01,0x58,0xFB,0xE8,0xB4,0x9E,0x15,0x16,0x90,0xF3,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x9D,0xC0,0x74,0xA7,0x7B,0x3F,0x8A,0x5F,0xD5,0xDB,0x9B,0xD0,0x26,0x60,0x73,0x70,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x39,0x90,0x47,0xF7,0xF8,0xA7,0x1E,0xDD,0x08,0x54,0x53,0x52,0x51,0x2B,0xAA,0xAC,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xDE,0x61,0x09,0x3D,0x85,0x70,0xD6,0x4F,0xCD,0xB8,0xC8,0x9B,0x76,0xB2,0x9E,0x24,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x65,0x8C,0xCC,0x60,0xEF,0xDD,0x2E,0xFB,0xE8,0xF9,0xCA,0x5E,0xC8,0x0D,0xF1,0x6F,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x0E,0xA7,0xA0,0x85,0xFF,0x31,0x6B,0x01,0x86,0x78,0x1C,0x82,0x0C,0x09,0xA8,0x26,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC7,0x36,0xEB,0xBF,0x1E,0x75,0x68,0x4F,0x2E,0x76,0x61,0xAF,0x02,0x81,0x67,0x14,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x47,0xD8,0xC0,0xF4,0x1B,0x1B,0xAF,0x04,0xD9,0x8D,0x9C,0xCB,0xFC,0x43,0xA3,0xDA,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0xD0,0xD3,0xB0,0x7B,0x12,0x4F,0x47,0x45,0x24,0x81,0xFF,0x18,0x0C,0xB7,0xFD,0x34,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x18,0xD0,0x64,0x07,0x2B,0x1A,0x59,0x9D,0x40,0xD4,0x70,0x1B,0xCD,0xAF,0x21,0x1B,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x09,0x60,0xE4,0x8E,0xEA,0xAA,0x2D,0x0F,0xC2,0x08,0xC1,0x4D,0x91,0x54,0xD4,0x92,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0xED,0xCB,0xDB,0x3A,0x2B,0x7C,0x74,0x0E,0xF0,0x1A,0xE1,0xBF,0x88,0xBD,0xD4,0x8C,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x47,0x17,0x6D,0x99,0x16,0x9B,0x45,0x55,0xB6,0x7B,0xD1,0x82,0x82,0xE4,0xA4,0x91,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x19,0xB6,0x5B,0xD1,0x5F,0x1C,0x2A,0x67,0x22,0x04,0x6E,0x5B,0x93,0xD8,0x2E,0x7A,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xB0,0xA0,0xD0,0xF3,0x1A,0x3A,0x74,0xF6,0xB1,0x9C,0xAA,0xA4,0xA0,0x95,0x7B,0x4A,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0xE8,0xA3,0x53,0xF0,0xE0,0x23,0x4A,0x5A,0xF6,0x8C,0xF8,0x5C,0x1A,0x01,0x30,0xAA,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x34,0xB3,0x6D,0x5B,0x79,0x07,0xCE,0x54,0xDC,0x6D,0xC3,0x93,0x55,0x29,0xF4,0xBE,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x2A,0xA6,0xE3,0xF4,0xDF,0x2D,0x9A,0xFE,0xCB,0x16,0xA0,0x71,0xA0,0xDB,0x5E,0x17,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x67,0xB5,0x76,0x34,0xA2,0x27,0xFC,0xD0,0x9D,0x2A,0xD2,0x9B,0xB1,0x36,0x27,0xA8,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x28,0xA8,0xA5,0x43,0xAD,0x0E,0x91,0x03,0x84,0x08,0xEF,0x99,0x28,0xE3,0x5B,0x37,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0xCA,0x7A,0xFB,0xD8,0x9D,0xAC,0x26,0x9D,0x16,0xC6,0x66,0xC4,0xCA,0x06,0xC4,0x68,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x8A,0x6A,0x82,0xAD,0xCC,0x0E,0x29,0x86,0x5E,0x2C,0x6E,0x21,0x05,0x12,0x11,0x4D,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x64,0x6C,0xE5,0x2D,0x7A,0xD9,0x3D,0xEA,0x40,0x4F,0x85,0xDC,0x95,0xBB,0xF1,0x18,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCD,0xAE,0xE2,0x45,0x47,0xD0,0xC7,0xDE,0xEA,0xF2,0xB1,0x29,0xC8,0x1D,0xCB,0xCE,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x90,0x61,0xF7,0x4D,0xE7,0x14,0xF2,0x32,0x0C,0x31,0x30,0x2E,0xCF,0xF5,0x75,0xC8,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x8F,0xE2,0xBA,0x44,0xC2,0x56,0x6A,0x86,0x1D,0x6C,0xF6,0x86,0xCC,0xC3,0x24,0x34,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0xFA,0xA3,0xE0,0x3E,0x50,0x36,0x96,0x85,0x6C,0x3F,0x53,0x1E,0xC5,0xAE,0xFC,0x7E,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x14,0x30,0xDD,0x76,0xDA,0xC1,0x19,0x7F,0xFF,0x90,0xBE,0x09,0x0E,0x7D,0x5A,0x0F,]),
-        (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x26,0x5B,0xCB,0x41,0x09,0x69,0x66,0x83,0x11,0x61,0x19,0xC0,0x68,0xA1,0xAC,0x4D,]),
-        (vec![0x00,],vec![],vec![0x7F,0x80,0x49,0x2C,0x31,0x7B,0x1C,0xD5,0x8A,0x1E,0xDC,0x3A,0x0D,0x3E,0x98,0x76,0xFC,]),
-        (vec![0x00,],vec![0x00,],vec![0xD4,0x4B,0xFE,0xBD,0xB1,0x38,0x2A,0x0F,0xAC,0x73,0xBC,0x9C,0xDD,0x06,0x57,0xC7,0x62,]),
-        (vec![0x00,],vec![0x00,0x01,],vec![0x8D,0x30,0xD6,0x08,0xE1,0x20,0x18,0xF1,0xE3,0xE0,0xC9,0xA6,0x2C,0x2D,0xAD,0xD1,0x66,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,],vec![0xBC,0xFC,0xDC,0x5B,0x20,0x12,0x15,0xCB,0xCD,0x33,0xBF,0xB3,0xF7,0x39,0x79,0x02,0x6D,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x2B,0x97,0xA0,0x46,0x34,0x24,0x63,0xC1,0x40,0xF1,0x47,0x31,0xF8,0xBF,0x4F,0x95,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x03,0xEF,0x53,0xFA,0x29,0x82,0x58,0x25,0x90,0x07,0x0C,0xA0,0xED,0x60,0x78,0x02,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x27,0x77,0x54,0x38,0xF5,0x63,0xA8,0x98,0x66,0xF2,0x8E,0x9E,0xF8,0x31,0x01,0x4A,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0xD4,0xB0,0x25,0x1A,0xB1,0x35,0x28,0xBD,0x4C,0x01,0xEB,0xEB,0x3A,0xFC,0x8A,0x9B,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xB1,0xF1,0x45,0x0C,0x4C,0x6E,0x0D,0xA5,0xF8,0x53,0x6B,0x08,0x22,0x9E,0xA1,0xB7,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0x16,0xA3,0x68,0x5A,0x41,0xF6,0x86,0xED,0x65,0x30,0xAE,0xD9,0x0E,0xBB,0x3B,0x45,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x65,0x07,0xB3,0xAD,0xCF,0xCD,0x49,0xD8,0x03,0x7F,0xE0,0x01,0xBE,0x58,0x27,0xAF,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0xA5,0x84,0x42,0xB7,0xF1,0x64,0x64,0x93,0x38,0xFF,0x3D,0xEE,0x88,0xCD,0xBB,0x0C,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x8D,0x51,0x8D,0xC0,0x3F,0xAB,0x91,0x98,0x03,0x36,0xB0,0x35,0x9D,0x74,0x19,0x93,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0x5D,0x1C,0x41,0x11,0xC8,0x5A,0x2C,0xE6,0xB2,0x7A,0xA4,0x69,0xD8,0xAE,0x32,0xB5,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x49,0xA2,0x8F,0x06,0x9E,0xD0,0xF1,0x2A,0xAD,0xB0,0x83,0x85,0x66,0x17,0x1E,0xC0,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0xD7,0x3C,0xDB,0xC6,0x59,0xAF,0xBC,0x7D,0xCA,0x72,0xC3,0x6A,0x5B,0x3E,0x5F,0x45,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x41,0xEF,0x53,0x08,0x50,0xD2,0x43,0x1F,0x4D,0xA1,0xB7,0x1E,0x43,0x0C,0x4C,0x49,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x5E,0x8A,0x35,0x3F,0xE9,0x46,0xD6,0x26,0x75,0x4B,0x80,0x97,0xF5,0x5F,0x06,0x16,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x1D,0x86,0x95,0x3B,0x05,0xD5,0x5E,0x17,0xB1,0x05,0x39,0x6C,0xA9,0x2B,0x7F,0x30,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x15,0x34,0x5A,0x7F,0x40,0x41,0x4F,0xCD,0xB5,0x3C,0x68,0xBE,0x1A,0x2C,0x1C,0xD5,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x97,0xFB,0x61,0x65,0x5D,0x28,0x4A,0xE8,0x78,0x29,0xD0,0xEB,0x88,0x2D,0x4D,0x90,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x2D,0x15,0xF6,0xA1,0x25,0x2D,0xFF,0x98,0xEE,0x2F,0x6B,0xBA,0x1F,0x11,0x83,0xDD,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0x27,0xFD,0x00,0xAD,0x02,0x01,0x45,0x78,0x9A,0xD6,0x2B,0x56,0x6D,0xFC,0xD8,0x7C,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x22,0xA1,0x41,0xBA,0x51,0xCA,0x65,0x28,0xA2,0xCA,0x54,0x4A,0x2D,0x5C,0x2C,0x9E,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x08,0x84,0x72,0xDE,0x89,0x76,0x0F,0x6A,0xEC,0x58,0xF0,0xA1,0x05,0x32,0x58,0xAB,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0xD6,0xF1,0x48,0x63,0x13,0x8A,0xD9,0x34,0x54,0xAC,0xE7,0x57,0xAD,0xB0,0xD4,0x56,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0xC4,0x4B,0x5C,0x99,0x51,0x78,0x97,0xEC,0xA3,0xE2,0xF8,0x71,0x3D,0x6A,0xF5,0xEB,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0xB3,0x02,0x00,0x78,0x32,0x75,0xFF,0x0E,0x13,0x60,0x48,0x13,0xF3,0x06,0x04,0xC1,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xCF,0x14,0xD4,0x7C,0x8B,0xCC,0xCC,0xCF,0x57,0x8B,0xDF,0x3C,0xB4,0xFD,0xC6,0xEB,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x74,0x64,0x4E,0xA6,0x3E,0x17,0xD2,0x52,0x59,0x61,0x7A,0xBF,0xC8,0x69,0x03,0x01,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0xD7,0x7F,0x94,0xF0,0x3C,0x56,0x32,0x58,0x09,0xC2,0x3A,0x83,0x39,0x51,0xFE,0x49,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x3F,0x87,0xC3,0xA0,0x5C,0xFE,0x15,0x1A,0xB6,0x07,0x26,0x21,0x53,0x3B,0xD0,0x24,]),
-        (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x74,0xAC,0xE7,0xFA,0x42,0xF1,0xE0,0xB6,0xC6,0xE6,0x9C,0x48,0x43,0xC8,0x81,0x67,]),
-        (vec![0x00,0x01,],vec![],vec![0x7F,0x8A,0x3A,0xEB,0x5A,0x86,0xF3,0x21,0x6A,0x8B,0x70,0xF8,0xE4,0x3D,0x02,0xEC,0x8D,0xC4,]),
-        (vec![0x00,0x01,],vec![0x00,],vec![0xD4,0x4A,0x67,0x48,0x1A,0xA7,0x9C,0x21,0x51,0x00,0xC3,0x1D,0x50,0xE9,0x56,0x43,0x73,0xE9,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,],vec![0x8D,0x72,0xCA,0x21,0x25,0x95,0x58,0xEA,0x09,0x0A,0x65,0x8E,0x15,0xE8,0xAF,0x10,0xB4,0xBB,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xD0,0x50,0x56,0x1F,0xE0,0x52,0xF3,0x26,0x8A,0x49,0xF7,0xE9,0x8E,0xDB,0x97,0x2B,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x95,0xAD,0x5F,0xA2,0xA6,0x63,0x0E,0x1D,0xBE,0x39,0x19,0x79,0x19,0xAD,0x00,0x48,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x80,0x9D,0xEF,0xB1,0x60,0x1B,0x6F,0xB8,0x83,0x55,0xF8,0x1E,0xF8,0x98,0xB0,0x05,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0xA1,0x55,0x84,0x72,0x9A,0x73,0x9D,0x67,0xCC,0xBC,0xC6,0x9B,0xA2,0xFF,0x8F,0xBE,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xC6,0x6D,0x53,0x13,0xDA,0x99,0x93,0x7E,0x37,0xF0,0xD1,0x13,0x4A,0x8C,0xD5,0x65,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0x98,0xA1,0x93,0x39,0xC8,0xD2,0xC0,0x7B,0x9D,0x4D,0x2E,0x29,0xF7,0x45,0x8D,0x15,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0xDB,0xF1,0x6F,0x33,0x2E,0xBA,0x08,0x7E,0x72,0x36,0x52,0x85,0xA9,0x91,0x3D,0x45,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xB5,0xD7,0xD9,0x6D,0x5A,0xCB,0x71,0xBF,0xF4,0xB8,0xE6,0xCB,0x99,0x57,0x5D,0x27,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x58,0x21,0xD0,0x6C,0xA3,0x05,0xFA,0x4F,0x91,0xEF,0x70,0x3B,0x7C,0x9B,0xEF,0xCB,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x0E,0xC7,0xDA,0xF6,0x5F,0x4F,0xCF,0x5E,0x0E,0xB5,0x77,0x09,0x3B,0xDA,0x3A,0x0D,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xF6,0x42,0x28,0xD3,0x98,0xC4,0x27,0x32,0x76,0xF8,0xAE,0x3F,0xDE,0x45,0x44,0xFC,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xB8,0xFD,0xD4,0x40,0xFB,0x25,0x7F,0x4F,0x91,0xCD,0xAA,0x44,0xA7,0xD8,0x53,0x9F,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0xBC,0xBC,0x5E,0xBD,0x2D,0xED,0x5B,0x38,0x98,0x73,0x5C,0x29,0x72,0x5B,0xF5,0x2B,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xD2,0x69,0xE0,0xDD,0x4A,0xEE,0xA0,0xB1,0xA6,0xD4,0x5B,0xE0,0x58,0xB0,0x6D,0x12,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0x72,0xBA,0x1B,0x93,0x1B,0x90,0xA0,0xCD,0x54,0x75,0x7C,0xFC,0xDE,0x0C,0xDC,0x5C,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x47,0xE6,0xC7,0x8C,0xC2,0x6F,0x5E,0xD7,0x93,0xE8,0x7A,0x44,0xE2,0xE7,0x75,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x64,0x14,0xEC,0x61,0xCF,0xBA,0x83,0x62,0x79,0xE4,0x80,0xB5,0x81,0x28,0xD9,0xF6,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x1B,0x65,0xC0,0xBE,0x39,0x03,0xFB,0xDC,0xB8,0x30,0x69,0x9C,0xB5,0x23,0xAE,0x5C,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0x25,0xD9,0x11,0xA7,0x71,0x50,0xF9,0x57,0xDB,0x77,0xA8,0xEE,0x3D,0x01,0x32,0xB4,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0x3B,0xC6,0x1E,0xDA,0x85,0xD7,0xF3,0x04,0x6B,0x29,0xA1,0x7D,0xF1,0xD5,0xE0,0xCA,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x50,0xD7,0x9C,0x3C,0x61,0x97,0x3D,0x8E,0xA8,0x34,0x72,0xC4,0x0E,0xAA,0xA2,0xFF,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0xAE,0x82,0x71,0xBC,0xA0,0xC6,0xA6,0xB1,0x66,0x84,0x9C,0x1B,0x98,0x81,0xB1,0xEF,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xB0,0x40,0x58,0x3F,0xE6,0x2B,0xCA,0x84,0xE6,0x24,0xFC,0x1E,0xBD,0x79,0xF8,0x48,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xDD,0xA8,0x41,0x6B,0x45,0xA8,0x4C,0x63,0x1B,0x0B,0x9F,0x7E,0x55,0x62,0xBD,0x54,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB5,0xBB,0x54,0x3E,0x4D,0xA5,0xF3,0xC0,0xEF,0x6A,0x9C,0x9D,0x46,0xB4,0x6E,0xAF,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x49,0x0E,0x61,0xBB,0x6A,0xE5,0xC6,0x7F,0xC2,0x98,0x49,0xBA,0x0C,0x0A,0x7F,0xD2,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0xF6,0x6B,0xE3,0x09,0x18,0x96,0x9E,0x0E,0xFC,0xE3,0x8A,0xDE,0xDC,0xFC,0xCF,0x35,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xC5,0xDA,0xCE,0x74,0xA0,0x7D,0x3E,0x21,0x2E,0x8C,0xED,0x18,0x4C,0xC1,0x86,0xF8,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0xCD,0x3D,0x8F,0xD1,0x85,0x1F,0x46,0xFC,0x23,0xE4,0x8B,0xCF,0x61,0xEC,0xBC,0x14,]),
-        (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x17,0xE7,0xF1,0xA2,0x10,0xB8,0x3A,0xDC,0x80,0x55,0x08,0x9D,0xD5,0x95,0x87,0xB4,]),
-        (vec![0x00,0x01,0x02,],vec![],vec![0x7F,0x8A,0x2C,0x65,0xCA,0xBB,0xEE,0x8A,0x9A,0x9A,0x95,0x9C,0xEC,0x12,0x24,0x83,0xE4,0xE4,0x96,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x8E,0xBC,0x47,0x3D,0x39,0xF3,0x94,0xB6,0x22,0xEE,0x54,0xFD,0xD2,0x24,0xBE,0x18,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x02,0x12,0x5E,0x69,0x2F,0x97,0x80,0x09,0x89,0xF3,0x5C,0x63,0x21,0xEF,0xE1,0x25,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xF2,0x80,0x5E,0xDB,0x5E,0xFE,0x5C,0xE3,0x44,0x35,0xE3,0xCA,0xAD,0x98,0x8B,0xED,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x86,0x37,0xA1,0x9B,0x87,0xB3,0xDE,0x09,0xB7,0x9A,0x0D,0xB0,0xF8,0xE8,0xB2,0x11,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xD7,0x43,0x0D,0xD3,0xBA,0xA2,0x57,0x5E,0xE4,0x84,0x28,0xF9,0x95,0xFF,0x1E,0x4C,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x03,0x0B,0xBE,0xA2,0x3B,0x61,0xC0,0x0C,0xED,0x60,0xA9,0x23,0xBD,0xCF,0x91,0x47,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x45,0x2D,0x8D,0x18,0x30,0x03,0x98,0xB2,0x67,0xA3,0x8A,0x70,0xD2,0x2F,0x3C,0x03,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x51,0xAA,0xD2,0xAF,0x4F,0x6A,0x05,0x38,0xD4,0xB7,0x77,0x28,0x61,0xF7,0x11,0xC5,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x3B,0x3B,0xC1,0xA1,0x42,0xDA,0xC7,0x5D,0xBD,0x72,0x4B,0x99,0x24,0xA8,0xA8,0xE9,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x4D,0xD5,0xD6,0xE1,0x0E,0x88,0x29,0x85,0xA4,0x52,0xE7,0xE4,0x9A,0x05,0x33,0xA1,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x6C,0x9C,0x12,0x5E,0x7C,0xE9,0x87,0xCA,0xD1,0xC3,0x2E,0x5A,0x7C,0xF3,0x05,0xAD,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0x12,0xCB,0xFC,0x34,0xEC,0x87,0xBF,0x46,0xC9,0x8A,0xAD,0x14,0xAA,0x08,0x68,0xA0,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0xE7,0x8E,0xD4,0xF9,0x9D,0xF9,0xD9,0xE6,0x54,0x8D,0x58,0x27,0x80,0x6F,0xBD,0x26,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0xB3,0xE5,0x3A,0x9F,0xC0,0x79,0x4D,0xA9,0x76,0x74,0x4B,0x07,0x2F,0xDB,0xDF,0xF0,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x2E,0xB6,0xEB,0xF3,0xFE,0x27,0x83,0x13,0x5B,0xC4,0x1A,0x85,0x84,0x63,0x7D,0x93,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0x2F,0x55,0x99,0x40,0xA0,0x69,0x57,0xD5,0xE3,0xB0,0x27,0xBA,0xCE,0x5B,0x04,0x08,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0xC2,0xA0,0xFE,0xF5,0x2C,0x98,0x3C,0x9A,0x07,0xF1,0xC6,0x7B,0x7F,0x40,0x32,0x31,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x49,0x7D,0xF3,0x65,0xDF,0x64,0x27,0x4C,0x58,0x6A,0x71,0x68,0x58,0x46,0xD0,0x61,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x6C,0xF0,0xFE,0xF6,0x41,0xBD,0xF5,0xB7,0xAA,0xBE,0xC4,0x4B,0x9C,0xBA,0x67,0xE5,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x62,0xC5,0x8A,0xC2,0xB6,0xC0,0x07,0xDB,0xD0,0x1B,0xDC,0xFA,0x49,0x7F,0xD1,0x06,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x8F,0xDD,0x07,0x0F,0xE8,0xE9,0x2A,0x61,0x2E,0x0A,0x90,0xD1,0x8D,0x8C,0x58,0x03,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0xBE,0x23,0x6C,0x5B,0x83,0xED,0x01,0x27,0x8B,0x26,0x40,0xD3,0x56,0xE5,0xF2,0x89,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x89,0x40,0x9F,0x6C,0x8C,0x10,0x1C,0x46,0xE2,0xC1,0x20,0x4E,0xFD,0x68,0x1A,0x69,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x3E,0x45,0xEA,0x44,0xE3,0x2C,0x60,0x1C,0xE5,0x40,0x6C,0xEF,0x15,0xE3,0x03,0xA9,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0x42,0x94,0x1C,0x1B,0xA0,0x12,0x75,0xE7,0x43,0x85,0x92,0xA0,0x0E,0xD4,0xE3,0x56,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0x56,0x06,0x6B,0x16,0xBB,0x40,0x2D,0x21,0x72,0x5D,0xAC,0x29,0xDA,0xBD,0xDC,0x83,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x9D,0xFA,0x99,0x41,0x45,0xF8,0x99,0x67,0x7F,0x05,0xA7,0x93,0x0C,0x65,0xC0,0x1D,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x55,0x79,0x2F,0x45,0xD4,0xC7,0xD5,0x27,0x45,0x9D,0x72,0x78,0x9D,0x51,0xA1,0xDA,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x5E,0x96,0x1B,0x5F,0x2C,0xE5,0x44,0x62,0x05,0x30,0x88,0x79,0xC2,0x6D,0x8D,0xE5,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xBD,0x62,0x04,0xF8,0x6A,0x84,0xE2,0x0D,0xA7,0xFC,0x62,0x69,0x38,0x8B,0x6E,0xC9,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0xC8,0x0F,0x25,0x20,0xD9,0xE9,0x35,0x73,0x92,0x2D,0x26,0x90,0x71,0x47,0x66,0x45,]),
-        (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x33,0xCA,0x01,0xBD,0x4E,0x54,0x9F,0xF3,0x47,0x24,0xD3,0x32,0x57,0x77,0x8F,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0x78,0x33,0xEE,0xA3,0x0E,0x11,0xFB,0x4B,0x90,0x2B,0x63,0xEB,0x0D,0x3F,0xA6,0x71,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xCA,0xBC,0xDD,0x54,0x73,0x04,0x14,0xD7,0x50,0xBD,0x60,0xC5,0x19,0x98,0x07,0x85,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0xE4,0xA7,0x24,0xE8,0x35,0x45,0xA2,0x94,0xB2,0xE8,0x33,0x62,0x03,0xB8,0xE6,0x1B,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0xD7,0x63,0x1C,0x77,0xDE,0x54,0x53,0x71,0x52,0x0A,0x3E,0x23,0x9E,0x61,0x9C,0xF8,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xC0,0x6E,0x10,0x23,0x96,0xB8,0x1B,0xC1,0x68,0xB4,0xA0,0x29,0x0D,0xD3,0x57,0x6F,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0xA8,0xC5,0xFE,0x55,0x0F,0x44,0x7E,0x9B,0xAA,0x45,0x23,0x8D,0xA3,0xB7,0x30,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0x89,0x63,0x3A,0xAC,0x35,0x3D,0x56,0x53,0x90,0xAF,0xA6,0xC2,0x41,0x3A,0xCA,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x81,0x6D,0x4C,0xF3,0xBA,0xF6,0xB3,0x25,0xEC,0x93,0x7A,0xA8,0x67,0xA3,0xA8,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0x7B,0xE7,0x14,0x98,0x69,0x62,0xA7,0xB4,0x6D,0x50,0x6B,0xD8,0x03,0xC0,0xAF,0x48,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0x26,0x04,0xF5,0x7E,0x8B,0xD3,0x99,0x89,0x9F,0xB0,0x1A,0xA2,0x46,0x8D,0x0D,0xD8,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x6E,0xDD,0xAD,0xD9,0x42,0x83,0xEF,0xCB,0x46,0x6A,0xA3,0xB3,0xF0,0xE4,0x1E,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0x7D,0xDE,0xBF,0x36,0x9C,0x62,0x25,0x94,0x2A,0xE6,0x18,0x1B,0xEC,0x32,0x50,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x04,0x46,0x01,0x40,0x58,0x39,0x71,0xA2,0xE3,0x4C,0x9D,0x93,0xD8,0xC4,0x8F,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x66,0x5D,0x4A,0x4A,0x94,0x3D,0x41,0xFA,0x8C,0x56,0xF4,0x91,0xCA,0x0C,0x53,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0xFA,0x4A,0x8E,0x43,0x5E,0x3F,0xE6,0xC4,0x0C,0xE8,0x75,0xBF,0x87,0xFF,0xF1,0xC7,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0xBA,0x42,0xCB,0x27,0x63,0x99,0x79,0xF7,0x7A,0xC8,0x53,0x3F,0xEE,0xB2,0x49,0xB4,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0xA8,0xA3,0x2F,0x1D,0x6C,0x96,0xF6,0x00,0x7D,0x45,0xE0,0xDE,0x83,0xC6,0xE5,0xA7,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xDF,0x4A,0x17,0xBF,0xA0,0xC6,0xA7,0x4E,0x6C,0x52,0xEA,0x6B,0xA3,0x5A,0x45,0xD8,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x91,0xC5,0x29,0x2F,0xBC,0xC8,0x77,0xF4,0x62,0x2C,0x22,0x2B,0xB7,0x02,0xDE,0xC9,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0xCB,0x26,0xDA,0x17,0x82,0xCB,0x0B,0xD6,0x72,0xA9,0x9B,0xFE,0xBD,0x2E,0x52,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x78,0x38,0x53,0x7C,0xDB,0x45,0x86,0x21,0x26,0xA9,0x4E,0x2C,0xF3,0xD3,0xEA,0x5F,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x60,0xEA,0xD7,0x32,0xAC,0xFD,0x8A,0x06,0x73,0x1E,0x34,0x91,0xFB,0xC7,0xDD,0x79,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0x7A,0x52,0x7F,0x28,0x7A,0x98,0xD2,0xB2,0x57,0xCD,0x6E,0x50,0x00,0x54,0xBE,0xD4,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xBD,0x2B,0xBD,0x12,0xE3,0x3D,0x11,0x3C,0xC1,0xFA,0xDB,0x6E,0x86,0x92,0xF7,0x85,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x69,0x05,0x16,0x7E,0x56,0xF0,0x2C,0x2B,0x33,0x6D,0xA3,0x9B,0xD1,0x8F,0xEF,0x37,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0xFB,0x84,0x77,0xB6,0x95,0x58,0xF8,0xD9,0x4C,0x38,0xFE,0xF5,0xC9,0xC0,0xB1,0x3C,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0xCD,0x72,0x1B,0x0B,0xB6,0x31,0x16,0x91,0x7E,0xE5,0x93,0x5C,0x4C,0xB7,0x88,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0xB5,0x29,0x8F,0x91,0xB7,0x8D,0x7E,0x03,0xD5,0xD7,0x16,0xBC,0x0A,0x38,0x11,0x11,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0xD9,0x5C,0xBE,0xBE,0xB9,0xC1,0xF0,0xB9,0xF3,0xA1,0xC7,0x96,0x6A,0x66,0x78,0xBC,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x82,0xAA,0x06,0x3E,0x91,0x0F,0xEA,0xEA,0x15,0x23,0x29,0x9A,0x59,0x1D,0x97,0x9A,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0x29,0x84,0x0E,0x32,0x5F,0xCB,0x31,0xAB,0xD7,0x8D,0x5E,0xA4,0x45,0x4B,0x80,0xF1,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x58,0xDF,0x15,0x99,0x78,0x64,0x7D,0x2D,0xD9,0xB1,0x21,0xB7,0x07,0x0A,0x85,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x88,0x8B,0xA9,0x1D,0x38,0xE9,0x66,0x35,0x02,0xA9,0x47,0xEF,0x49,0xBA,0x7F,0xA0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x4A,0xAA,0xB2,0x78,0x35,0x10,0xB9,0xD1,0x98,0x23,0x65,0x3F,0x4B,0x24,0xDF,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xBF,0xAB,0x08,0x3B,0x9F,0xF8,0x70,0x2F,0x53,0x31,0xBC,0x31,0x7F,0xC5,0x6E,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xAD,0x52,0x7A,0xC4,0x92,0x36,0x72,0xCC,0xD7,0x93,0xA1,0x18,0x7F,0xED,0x47,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0xAC,0xF0,0xA8,0x1B,0xC9,0x0B,0x56,0x74,0x5B,0xA5,0xB4,0x86,0x2F,0x6A,0xFE,0xA6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0xE9,0xDB,0x4A,0x88,0x75,0xB6,0x99,0x8F,0xFA,0xE2,0xD2,0x75,0x53,0x0F,0xF1,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0x22,0xB9,0xCA,0xBA,0x2B,0xFE,0xBA,0x8C,0x42,0x7D,0x7A,0x24,0x3D,0xA4,0xEC,0x7A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xFC,0xFA,0x43,0x71,0x3D,0xD0,0x23,0x4D,0x06,0x19,0x0D,0xCB,0x63,0xEE,0x8F,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x9D,0x2C,0x12,0x3B,0x21,0x26,0xFE,0x6C,0x64,0x52,0xFF,0x5C,0xCA,0xFA,0x16,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0x5D,0x3B,0xDD,0x20,0x68,0x32,0x37,0x15,0xC5,0x4D,0x8D,0xEF,0xE8,0x01,0x09,0x46,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0xE5,0xD6,0x27,0x3B,0x40,0x79,0xF8,0x6E,0x13,0x0F,0xFD,0xA6,0x6D,0x69,0xDF,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x37,0xA5,0x10,0x97,0xA7,0x98,0xC1,0x12,0xDB,0x23,0x6A,0xC4,0x8A,0x8C,0xA0,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0xA9,0xAB,0xF5,0x22,0x90,0xBE,0x57,0x57,0x3D,0x0A,0x76,0xC6,0xE0,0xFA,0x01,0x62,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0xEC,0xAB,0xE6,0xF4,0xE0,0x88,0x91,0x4C,0xFD,0x2A,0x97,0x62,0x84,0x17,0x22,0xB9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x3F,0xAD,0xD6,0x3D,0x25,0x0F,0xC7,0x11,0x16,0x0B,0xEE,0x00,0x53,0x8E,0x27,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0xC5,0xB4,0x22,0xA3,0xBD,0x0F,0xA2,0x34,0xE1,0x00,0xF1,0x91,0x4E,0xF3,0xF0,0x65,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xCB,0xEA,0x8C,0x73,0xFC,0xC1,0xBD,0x9C,0x06,0xA2,0xA0,0x80,0x2D,0x3B,0x5E,0xB4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCE,0x10,0x0C,0xD0,0x23,0x50,0x2F,0x3A,0xA5,0x5C,0x58,0xE3,0xDD,0x46,0xC4,0xF0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0x95,0xBF,0x4E,0xA8,0x93,0x15,0xC4,0x57,0xBD,0x20,0xBF,0xEC,0x2D,0xDC,0x1A,0x52,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0x92,0x66,0xBC,0x8F,0x20,0x35,0x12,0xAC,0x89,0xB2,0x8B,0x01,0x20,0x6E,0x1B,0x9E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x21,0x3F,0xE8,0xD7,0x00,0xCF,0x76,0x54,0x03,0xF1,0xE9,0x49,0xF1,0xE9,0x98,0xD0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0xF7,0xAF,0x3A,0x90,0xD5,0xB5,0xE3,0x7A,0xC3,0x14,0xE9,0x0D,0x84,0xA1,0x50,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0xEC,0xFD,0xDB,0x91,0x9C,0x1B,0xCA,0xCA,0xBD,0x68,0x9E,0x17,0xD6,0x85,0x08,0x01,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x78,0x0F,0x3F,0xB9,0x66,0x75,0xCD,0xF3,0x60,0xAA,0xAD,0x5E,0x4E,0x7B,0xD0,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0x02,0xE2,0xFA,0xEA,0x29,0x55,0x98,0xD6,0xB1,0xD3,0xC5,0x80,0x7D,0x7B,0xDB,0x5F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xEC,0x8E,0x15,0xC7,0x50,0xEC,0xD3,0xAE,0x6E,0xA1,0xFB,0x63,0x48,0x91,0xD1,0xF9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0xBE,0x89,0x66,0xAE,0x90,0x5D,0x18,0xD6,0x4C,0xD1,0x00,0xFE,0x4F,0x61,0xB0,0x7A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC7,0x78,0xFF,0x9D,0xC2,0x81,0xE4,0xD6,0x02,0xC4,0x75,0x83,0x6E,0x1E,0x9E,0xD8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x82,0xF0,0xB0,0x4B,0xCD,0x8F,0x27,0xC4,0x1F,0x84,0xBC,0x69,0x33,0xD8,0x82,0xDC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xCC,0x30,0x1C,0xC1,0x2B,0x45,0x3E,0xF3,0xE7,0x8C,0xE6,0x92,0xDD,0x18,0x7D,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x2D,0x1C,0x91,0xBC,0x47,0x2E,0xD9,0x2B,0x40,0x10,0x2B,0x81,0xFC,0xFC,0x5F,0x94,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0x68,0xA6,0x23,0xA1,0xDB,0x3C,0x23,0x63,0xD2,0xD3,0x7F,0xE3,0xD1,0x3C,0xD7,0xE4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0x12,0x1C,0x56,0x03,0xC5,0x1C,0x79,0x99,0x93,0x3A,0x4E,0x0F,0x7A,0xE7,0x9C,0x0E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0xE8,0xD5,0x21,0x85,0x42,0xB8,0xBA,0x8D,0x27,0xFD,0xF7,0xB8,0xF5,0x86,0x96,0x65,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xEA,0xAF,0x81,0x6B,0x70,0x6A,0xEA,0x2A,0x74,0x1A,0xED,0xF7,0x40,0x28,0x88,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0xD5,0x8B,0x25,0x65,0x03,0x35,0x32,0xA2,0x6A,0x97,0x52,0xC4,0xAA,0xD2,0xEB,0xA1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x3A,0x4F,0x0C,0x36,0x8C,0x08,0xC1,0xB4,0x64,0x2E,0x37,0xA2,0xA2,0x0A,0x11,0x97,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xFA,0x4A,0xE9,0x4D,0x95,0x24,0x3E,0xAF,0x64,0xDE,0xAB,0xE4,0x71,0x70,0x64,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x4D,0x6A,0xE8,0xE0,0xFE,0xBB,0xFD,0x11,0xD7,0xA1,0x2C,0x5F,0xE8,0xF6,0xEE,0x29,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0x78,0x2E,0x7A,0x9D,0x23,0xB0,0xD2,0x1B,0xBD,0x87,0x18,0x15,0x55,0x07,0x02,0x2E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x9C,0x76,0xD2,0x22,0x22,0xDD,0xC0,0x23,0x40,0x4B,0xA0,0xD9,0xB5,0xDE,0x57,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x0F,0x3A,0x06,0x6E,0xDD,0x6D,0x57,0xD5,0xA8,0x1D,0xFA,0x06,0x3E,0x36,0xD3,0xF7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x82,0x67,0xDA,0xC0,0xC6,0xCA,0x5F,0xC9,0xC7,0x03,0x6B,0xFF,0xA4,0xA7,0xCD,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0xC6,0x12,0xDF,0x6E,0xEE,0x2C,0xEE,0xF7,0xFB,0xD5,0xF0,0x65,0xAB,0x88,0xF3,0x5C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x5C,0xDD,0x18,0x43,0x70,0x25,0xA1,0x80,0x27,0x72,0xAC,0x56,0xFE,0x22,0x19,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xF2,0x40,0x52,0xD3,0x60,0x33,0x7C,0x84,0x7A,0x19,0x95,0x88,0xBE,0xD9,0x6A,0x56,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0x14,0x33,0x1F,0xBD,0xB3,0xD1,0x20,0x11,0xEB,0x39,0x7B,0xDF,0x70,0x9E,0x21,0x8B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0xE1,0x75,0x48,0x0E,0x2B,0xAC,0xE8,0x1D,0xAC,0xA2,0xA2,0xB8,0xE7,0xFC,0x86,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x47,0xF2,0xA8,0x9D,0x75,0x73,0xE0,0x98,0x9B,0xEA,0x11,0xF0,0x74,0xCD,0x63,0x12,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x57,0x3F,0x5D,0x42,0x1D,0xFC,0x06,0x38,0xC2,0x51,0xB7,0xAE,0x5C,0xD5,0x23,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x0D,0xB1,0x2E,0x9E,0x11,0x1E,0x3D,0x6C,0x11,0xBD,0x34,0x2E,0x57,0x71,0x60,0x53,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0xAC,0xF8,0x0D,0xCB,0x44,0x68,0xB2,0x54,0xFE,0x6F,0x5B,0x75,0xB2,0x46,0xF8,0x82,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB3,0x3E,0x7F,0xC3,0xFF,0xDC,0xB4,0x2E,0x5A,0x9C,0x98,0x7A,0xD9,0xBD,0xFA,0xB2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0xD6,0xBE,0x01,0xAB,0x13,0x24,0xA5,0x75,0x33,0x20,0x17,0xE8,0x16,0x68,0xBF,0x85,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x1F,0x53,0x19,0x3A,0x05,0x18,0x55,0x09,0x13,0x12,0xE8,0x51,0xEE,0xA1,0x46,0x00,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0x8E,0x25,0x17,0xDD,0x1A,0x2C,0xA9,0x3D,0x45,0x3A,0x13,0xC7,0x07,0xB1,0xE5,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xAE,0xBF,0x28,0xF8,0x2C,0xD3,0x1E,0xF5,0x81,0x33,0x83,0x48,0xA7,0x3A,0x49,0xA9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x60,0xF2,0xE2,0x88,0x68,0x8F,0x8C,0xF7,0x29,0x24,0xF2,0xEB,0x59,0x35,0xE0,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x50,0xF6,0xED,0x64,0xFC,0x8B,0x74,0xA5,0xA3,0x3D,0xBC,0xA4,0xE6,0xEE,0x67,0xFE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xEF,0x03,0xEE,0x0A,0xC0,0x73,0x47,0x12,0xA6,0x8D,0xCD,0x23,0x77,0xEB,0x4E,0x48,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0x96,0x7D,0x1D,0x52,0x59,0x59,0x3E,0x6B,0x85,0x21,0x04,0x2B,0xFE,0x48,0xB4,0xA1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0x76,0x60,0x55,0x71,0xA9,0x80,0xFF,0x13,0x91,0xA0,0x90,0xB6,0x7B,0x2E,0x43,0xAC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0xF2,0x1F,0x9D,0xC4,0xE3,0xAB,0x0F,0xE5,0x8D,0x9F,0x68,0x3F,0x00,0x77,0x61,0xD7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0xF9,0xE1,0xB7,0xC0,0x4D,0x7C,0x89,0xC7,0xB2,0xF1,0xF3,0x43,0xF4,0xD6,0x5F,0xED,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0x37,0x39,0x72,0x29,0x0C,0x2B,0xFE,0x47,0xFC,0x50,0x4D,0x01,0xEC,0x2F,0x8B,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x72,0x82,0xD0,0x81,0xD1,0x7C,0xD1,0xA0,0x23,0xAF,0x58,0xF9,0x14,0x96,0x59,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x23,0x06,0xE3,0x9A,0x90,0xEB,0x9D,0x46,0x3E,0x78,0xEA,0x45,0x18,0x9C,0x3C,0x24,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xF7,0x21,0x63,0x96,0x0F,0x9C,0x1E,0xF7,0x70,0x85,0x4F,0x93,0x53,0x2C,0x6B,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0x6C,0x27,0x95,0xD0,0xD0,0x38,0x78,0x22,0xC5,0xF9,0xEB,0x4B,0xE5,0x59,0x15,0x6B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x08,0xBC,0x6E,0x59,0xA7,0x76,0x16,0x9B,0xD1,0x2C,0xD3,0x16,0x3B,0x41,0x5E,0xE2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0xEC,0xF9,0x4C,0x3D,0x74,0x30,0x25,0xA6,0xF8,0x99,0xCF,0x14,0x84,0x3A,0x5A,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x03,0x0F,0x7E,0xFA,0xE5,0x1F,0xD4,0x03,0x08,0x77,0x98,0x15,0x61,0xA7,0x73,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0xC0,0x8F,0x9F,0x89,0xF9,0xD8,0x1E,0x7D,0xB2,0x72,0x4F,0x48,0x7B,0x00,0x16,0x54,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x12,0xA4,0x7A,0xF7,0x7A,0x19,0x76,0xEB,0x25,0xEF,0xE4,0x40,0xE0,0x5B,0x50,0xF1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x9E,0xAB,0xDF,0xE6,0x9A,0xD1,0x43,0xDA,0xBE,0x0B,0xA2,0xEB,0x91,0xBC,0x7D,0xEB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x72,0x70,0x95,0xD3,0xFC,0x12,0x20,0xB4,0x06,0x6B,0x3C,0x67,0x7D,0x71,0xFE,0xD2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0x94,0xFF,0x3A,0x30,0x4F,0xCC,0x69,0x71,0x3B,0xC4,0x2D,0x15,0x65,0x2E,0x39,0x84,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0xEC,0xED,0xC0,0x6D,0x64,0x1A,0xE2,0x98,0x8A,0x9F,0xEB,0xD0,0x90,0x12,0x04,0xAE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0xA6,0x44,0x66,0x85,0xCE,0x2C,0x59,0xD5,0xFE,0x16,0xC5,0x9F,0xE1,0xA4,0x62,0x63,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x57,0x55,0xA9,0xB7,0xB0,0x8A,0xB1,0x17,0x89,0x67,0x2B,0x3E,0xD6,0x4C,0x9B,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0xE6,0xC5,0xE7,0x22,0xF1,0xC3,0x5B,0x83,0x44,0x9A,0x37,0x33,0x31,0x5A,0xBF,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x53,0x71,0x0B,0x4B,0x49,0x43,0x22,0xDF,0x32,0xD7,0x48,0x25,0x9C,0xBA,0x71,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x6E,0x79,0xF3,0xEF,0x66,0xA4,0x5F,0x23,0x40,0x62,0x05,0x20,0x5E,0x16,0x9F,0xCB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x35,0x9B,0x11,0xE0,0xF7,0x1B,0xF0,0xC1,0x40,0xF0,0xDB,0x26,0x8A,0x79,0x6E,0xC0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0xF0,0xA1,0xCA,0x0B,0x7D,0xF5,0xBE,0x7F,0x3C,0x84,0x6D,0x38,0x17,0xD1,0x6E,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0xB6,0x1C,0x69,0xD4,0x92,0x87,0x9A,0xFE,0x0F,0x78,0x02,0x25,0x17,0x3F,0x23,0xBD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0x6C,0xC9,0x2C,0x63,0x14,0x10,0xA6,0xB1,0x30,0xC8,0x22,0x87,0x5F,0x39,0x79,0x5C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0xFC,0x33,0x8A,0x6A,0x3D,0x17,0xA4,0x9A,0xA2,0x6D,0x61,0x89,0x85,0xAF,0x16,0x6A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0xB2,0x52,0x44,0xFC,0x88,0x50,0x3D,0xEC,0xC1,0x74,0x37,0x0B,0x8B,0x68,0xC5,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0x9F,0xCB,0xEB,0xCD,0x2B,0x7F,0x80,0x95,0x6E,0x60,0x2C,0xE7,0xCB,0xFB,0x61,0x90,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x81,0x39,0x02,0xEA,0x5F,0xB8,0xDC,0x21,0x41,0xA4,0x8C,0xFB,0x70,0x29,0xC6,0x63,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x7E,0x46,0x16,0x79,0x95,0x53,0xCB,0xE0,0xEF,0x75,0x37,0x47,0x54,0x57,0x98,0xC4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0xF1,0x83,0xD3,0x99,0x40,0xEF,0xB4,0xDA,0x63,0xDC,0xF4,0x32,0x9E,0x00,0x48,0xBF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x4F,0xB8,0x84,0xA1,0x77,0x6E,0xA3,0xA5,0x15,0x9F,0x00,0xBC,0x22,0x0C,0x9D,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x84,0xC9,0xAE,0x69,0x77,0x94,0x6D,0x94,0x20,0x85,0x29,0x9F,0xA9,0x89,0x3D,0x5C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x40,0x53,0x9B,0x49,0xCE,0x65,0x9A,0xF1,0x67,0xB2,0xD4,0x1E,0x82,0xB7,0x26,0x59,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xD2,0x80,0x6F,0x6E,0xAA,0xAF,0x1E,0xD3,0x68,0x22,0x53,0xA1,0x90,0x8D,0x25,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x07,0xB3,0x53,0xE0,0x2E,0xB6,0x7D,0x77,0x5D,0x80,0x55,0x86,0x6F,0x17,0x63,0x9A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x33,0x38,0x6B,0xC4,0x71,0x02,0xD2,0x15,0x35,0x8F,0x0B,0xDF,0x21,0x9D,0x2F,0x68,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0xBB,0x8E,0x93,0x87,0xB7,0xBF,0x70,0xE6,0xE3,0xBF,0x44,0x3C,0xDF,0xF8,0x89,0xDC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0x7D,0xD4,0x06,0x61,0x23,0x5E,0x8C,0xEB,0x2B,0xEB,0x40,0xB6,0xE6,0xC5,0xEE,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0x8F,0xAC,0x22,0xB2,0xD5,0x71,0xEF,0xF5,0x67,0x49,0x40,0x44,0xC7,0xBC,0xC2,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0x51,0x35,0x9F,0x70,0x5A,0x58,0xCF,0x31,0xAC,0xDF,0x5A,0x12,0x56,0x09,0x4E,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0x13,0x10,0x3E,0xF8,0x61,0x1E,0x35,0x5A,0xF3,0xBB,0xEB,0x3B,0x20,0xB0,0x65,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA0,0x20,0x0A,0x04,0x1C,0x0B,0x1E,0x39,0x93,0xEE,0xD4,0xDE,0x1A,0x61,0x69,0x7F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0xA3,0x80,0xAE,0xEB,0xA6,0x4B,0xCD,0xFF,0x78,0x13,0xD5,0x97,0x13,0xFD,0x5C,0x28,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x39,0x1C,0x1E,0x47,0xCD,0x05,0x7E,0xB6,0xB0,0xB2,0xEB,0x9C,0xB4,0xF2,0x48,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0xAB,0x27,0xED,0x49,0x6C,0x29,0xC7,0x79,0x3C,0x8D,0xC0,0x0B,0xF9,0x43,0xCC,0x89,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0xCF,0x46,0x8C,0xA9,0x05,0x7E,0x12,0x62,0xF1,0x89,0xD8,0xF8,0x96,0x4D,0x54,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xD6,0x1D,0xEF,0xFE,0xA2,0x88,0x56,0xD3,0x7B,0x8E,0x5D,0x69,0x62,0x5E,0x1C,0xA7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x16,0x7A,0x43,0x3F,0x76,0xE4,0x7D,0x0D,0x77,0xAF,0xC4,0xA9,0x63,0xAA,0x26,0x0B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0xE5,0xF5,0x83,0xBD,0x57,0xB7,0x41,0x93,0xF3,0xCE,0x60,0x3E,0xDB,0x0E,0x14,0x09,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0xE2,0x5E,0xA6,0xE3,0x65,0x7E,0x76,0x01,0xE6,0x9E,0xBD,0xC9,0x94,0x09,0xF2,0x32,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0x22,0x9E,0xB1,0x30,0x1D,0xE6,0xCA,0xF8,0xAE,0xDE,0x12,0xD9,0x0B,0x97,0x5A,0xC1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xCE,0x3B,0xC6,0xFF,0x98,0xE8,0x58,0x47,0x5E,0xD5,0x72,0x90,0x91,0xF9,0xC9,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x73,0x27,0x03,0x5D,0x7F,0x70,0xFC,0x13,0x5F,0x10,0x6F,0x55,0x79,0x7D,0x93,0xE0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x97,0x12,0xEE,0xF0,0x62,0x4B,0x1C,0x91,0xAD,0x0C,0xB6,0x40,0x74,0xFA,0x3E,0xA3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x5A,0x4D,0x88,0xCF,0x18,0x00,0x5C,0xA2,0x00,0xCB,0xD5,0xF1,0x3C,0x96,0xB1,0xC7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0xDF,0x50,0xCB,0x01,0x7A,0xC4,0x8D,0x04,0x47,0x48,0xEB,0x4F,0x50,0xB8,0x22,0x4A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x4C,0xA4,0x45,0x13,0x7E,0x08,0xB0,0x2D,0x97,0x5B,0xB2,0x7F,0x94,0x95,0x88,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0x18,0x95,0x32,0x80,0xC8,0xFA,0x52,0x50,0x92,0xBB,0x0F,0x65,0x4E,0x41,0xD0,0xAD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xDE,0x15,0xA7,0x9B,0xDC,0xEE,0xDA,0x63,0xE3,0xAE,0x3E,0xE7,0x1C,0x94,0x49,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xA8,0x9C,0xB8,0x60,0xEE,0x2B,0xD1,0x1B,0xB3,0xC9,0xBC,0x89,0x9A,0x87,0x89,0xFB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xF8,0xD3,0x97,0xBA,0x21,0xFC,0x41,0x7B,0x5B,0x2F,0xEE,0x9C,0x8D,0xDE,0x73,0xE8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0x75,0x40,0x9C,0xF9,0x56,0x30,0x91,0x60,0x6A,0x23,0x69,0x46,0x3B,0x13,0x3A,0xF8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x0F,0x06,0x44,0x30,0x4A,0x78,0x78,0xD2,0xCF,0xA7,0xC7,0xFE,0x72,0x37,0x70,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xF6,0xE5,0x0A,0x2D,0x4A,0x47,0x1E,0x2E,0x2B,0xEE,0x4A,0xA8,0x81,0xCD,0xCD,0x42,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0xFB,0xBB,0x63,0xEE,0x40,0x5B,0x7B,0x20,0x0B,0x8E,0xF3,0x1B,0x37,0x76,0x4D,0x31,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x3F,0x9E,0xEC,0x85,0x5B,0xA7,0xE4,0xCF,0x4C,0xCB,0x3E,0xCB,0xE4,0xFC,0xEF,0x08,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0xF6,0x1E,0xD2,0xB7,0x26,0xE3,0x68,0x20,0xBD,0xBF,0x85,0xE3,0xD0,0xDE,0x72,0xA1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0xC5,0x10,0xB6,0x47,0x40,0x27,0x17,0xE1,0xF6,0x27,0xC6,0xDC,0x3A,0x45,0xF3,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xA5,0xBD,0x56,0xF4,0x90,0x81,0xAD,0x32,0xA6,0x51,0x1F,0xD6,0x9E,0xF8,0xA5,0x67,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0xA1,0x5E,0xB9,0x69,0x12,0x67,0x53,0x06,0x3E,0x47,0x0A,0xB0,0xD0,0xDC,0x86,0x97,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xB1,0x8F,0x0C,0x2D,0xCE,0x18,0xF5,0xD1,0x43,0x89,0x62,0xF5,0xDF,0x3B,0x1B,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0x35,0xB7,0x8F,0x7C,0xAD,0x9C,0x4B,0x0A,0x9F,0x72,0x3D,0x10,0x8B,0x14,0x48,0x50,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0xFA,0x06,0x70,0xF3,0x54,0x58,0x18,0xE8,0xF0,0x0C,0x0C,0x9D,0xC8,0x9E,0xC4,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0xCE,0xCE,0xCB,0x20,0x56,0x21,0x91,0x96,0x03,0x1E,0xCD,0x8C,0x78,0x32,0x9C,0x71,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0xAA,0x6A,0xD0,0x08,0xF7,0xFE,0xFF,0x70,0xB9,0x39,0x78,0xBE,0x15,0x5A,0xA8,0x3F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0xE1,0x16,0xA1,0x68,0x44,0x64,0xF6,0xE8,0xE8,0x6A,0x14,0x7A,0x24,0xE4,0x8D,0x44,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xA5,0x1C,0x82,0x4E,0x68,0x9E,0xF3,0xB5,0x95,0xE4,0x76,0x84,0xF9,0x48,0x2B,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x69,0xEA,0xD9,0x6A,0x57,0xDD,0x29,0xE3,0x82,0x29,0xB6,0x7C,0xF4,0xF3,0x8D,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0xF1,0x94,0xB7,0x4D,0xE6,0xA3,0x8E,0x18,0x81,0x9D,0x8B,0x2C,0x7B,0xF4,0xA8,0x0F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0x5E,0xDE,0xC7,0xC9,0x9D,0xF6,0xFC,0x57,0x59,0x8C,0x9D,0x85,0x5B,0xF0,0x86,0xBF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0xAF,0x6A,0xE0,0x9C,0xA4,0x85,0x04,0x10,0x8D,0x54,0x8B,0x5B,0xC3,0x24,0x80,0x69,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0xAF,0x86,0xC3,0xA9,0x25,0x96,0xF1,0x7A,0xF9,0xAB,0x67,0xD6,0xC8,0xFC,0xCD,0xFC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x06,0xEA,0xC9,0xE4,0xA0,0x2B,0x53,0xDE,0x71,0x1E,0xAA,0x80,0x96,0x00,0xDA,0xDF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0x4F,0x14,0x5A,0xA2,0x34,0x20,0x05,0x17,0x3C,0xAF,0x8B,0x23,0x19,0x17,0x56,0x9B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0xB4,0xFD,0x5A,0x9B,0x95,0x6E,0x2B,0x9D,0x14,0x69,0xD9,0x24,0x2E,0x03,0x71,0xBD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x6B,0xE7,0xFC,0x04,0x69,0xE4,0x52,0x6F,0xAA,0xFF,0x80,0xBC,0xAA,0xB6,0x6E,0x48,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xFD,0x46,0x84,0xAD,0xFF,0x9F,0x04,0x4E,0xF9,0x3F,0x4D,0xB3,0xA8,0x67,0xBC,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0xBC,0x35,0xB8,0x29,0x4E,0x75,0x1F,0xDE,0xCA,0xAE,0x24,0x1A,0x09,0x3A,0x52,0x56,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x32,0x5B,0xA2,0x3C,0xCF,0x3D,0xEE,0xC7,0x11,0x46,0x10,0x28,0xEC,0x58,0x9C,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x8B,0x41,0x1A,0x94,0x3E,0x18,0xC2,0xBD,0xD3,0xFA,0x42,0x15,0xC7,0x05,0x3F,0xE4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0x2A,0x0F,0xC0,0xCD,0xD3,0x47,0x79,0x96,0xB1,0x4E,0xB9,0x4F,0x9C,0x6E,0x1B,0x2D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0x94,0x91,0x64,0x19,0x28,0xB6,0x06,0x8C,0x9E,0x9F,0xA9,0xEA,0x15,0x6E,0x87,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x3F,0xC9,0xA8,0xF6,0xFC,0x90,0x3D,0x95,0x1C,0x55,0xEA,0x1F,0xD6,0xE8,0xEB,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0x57,0x6B,0x70,0x91,0xBB,0x9D,0xDD,0x43,0x3C,0x2B,0x6E,0xE2,0x61,0x81,0xB2,0x6B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x8B,0x81,0x8C,0x7D,0xE3,0x17,0x82,0xD5,0x5B,0x19,0xF2,0xD9,0xD1,0xA9,0xCF,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x7E,0x2B,0x96,0x27,0xCC,0x13,0xBC,0x56,0x66,0x4E,0x88,0x53,0x29,0xD6,0x23,0x24,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x07,0x4F,0xAD,0x34,0xB2,0x86,0xAD,0xDC,0xB5,0x4C,0xC9,0x01,0x17,0x47,0x58,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xB7,0xCD,0x8E,0x33,0x7F,0xF3,0x84,0xA6,0xE7,0x68,0x29,0xA3,0xD1,0x1C,0x42,0xC6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x15,0x97,0x1D,0xEB,0x58,0xC9,0xF4,0x50,0x4F,0xED,0x1D,0xE9,0xF2,0x02,0xE7,0x77,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xF8,0xB2,0xD1,0x8C,0xEB,0x40,0x3C,0xD7,0xEC,0xD4,0x19,0xF6,0xF8,0xD4,0x81,0xE4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0x7E,0xA8,0xD1,0x1E,0xEC,0x25,0x6C,0xDD,0x39,0x4E,0x19,0xD8,0xB4,0x42,0xDD,0x19,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xB2,0xBD,0x8A,0x43,0xAB,0xF0,0x43,0xAF,0x70,0xAF,0xDA,0xA5,0xC1,0xE8,0x62,0x4C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0x93,0x0F,0xAA,0xA9,0x8F,0xCF,0x74,0xB9,0x82,0x99,0x77,0x23,0x4C,0x90,0xE5,0x8A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x1C,0x74,0x21,0x40,0xA6,0x67,0x40,0xAB,0x42,0x3A,0x0D,0x96,0x10,0x5C,0xF0,0xCD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x6E,0xB1,0x1E,0x87,0xF3,0x7B,0xA1,0x11,0x1F,0xC7,0x13,0xD2,0xB9,0x6C,0x9C,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x5D,0xC2,0xF5,0xBE,0xF5,0x84,0xFB,0x5B,0x8D,0x73,0xE2,0x85,0x06,0x46,0xF6,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x07,0xBC,0xCE,0x9F,0x64,0xDB,0x75,0x51,0xA7,0xBD,0xCC,0x45,0x04,0x61,0x0A,0x37,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x3E,0x27,0x22,0xD9,0xDB,0x6C,0x23,0xB9,0xE9,0x12,0x67,0x56,0x58,0x15,0x8D,0x4C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x33,0x89,0xDA,0x6D,0x9A,0x19,0x1F,0xC9,0x36,0xD6,0x90,0x2A,0xF7,0x4C,0xF3,0x1C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x7B,0x08,0x9C,0xAB,0xB4,0x25,0xDB,0x25,0x87,0x3B,0x0D,0x92,0x3B,0x68,0xFB,0x42,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0x52,0x69,0xF8,0x2F,0x5C,0xA2,0xD2,0xE7,0x89,0xC3,0x0D,0x93,0xD8,0x1C,0x98,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0xB6,0xF1,0xFB,0x5B,0x72,0x5E,0x72,0xA1,0xB7,0x2F,0x3D,0x0F,0x85,0x4A,0xC9,0xE1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x0D,0xD8,0x99,0x6E,0x19,0xC5,0x51,0x1B,0x10,0xF7,0xA5,0xE0,0xDD,0x91,0xFD,0xF0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x04,0x10,0x7B,0x2B,0xD9,0x9E,0xBD,0xE7,0xCC,0x95,0x48,0x0F,0xA3,0xC4,0x3B,0x68,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xC9,0xBC,0x10,0x0B,0x84,0x61,0xE6,0x1A,0xE8,0x38,0xF2,0x07,0x0D,0xEE,0x36,0xC2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0x1D,0x69,0x42,0x8D,0xE5,0x94,0x31,0x43,0x81,0x2F,0x32,0x79,0xC0,0x9C,0x1C,0xAF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x67,0xFA,0x87,0x65,0x87,0xDA,0x66,0xE8,0x0C,0xF7,0x93,0xAB,0xBF,0xBD,0xBA,0xB3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0xE9,0x10,0x2B,0x51,0x36,0xCD,0x84,0x2D,0x94,0xBA,0x05,0xC9,0x32,0xEF,0xEA,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0x4C,0x3B,0x79,0x0A,0xCB,0xF9,0x31,0x71,0x18,0x32,0x86,0x3F,0x7F,0x4D,0x98,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xBC,0x98,0xC4,0x94,0x5C,0xED,0xF2,0xCF,0x7D,0x7B,0xAC,0x99,0x1E,0x95,0x09,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0xDE,0xCD,0x0D,0x13,0x13,0x34,0x75,0xF5,0x2C,0x2B,0xFD,0x79,0x0D,0x16,0x6D,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0xD6,0xB4,0x57,0x26,0xAC,0x79,0x1C,0xF0,0x46,0x31,0x57,0xBA,0x18,0xA9,0xBC,0xC1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x09,0x10,0xF3,0x7F,0xCD,0x15,0x87,0x81,0x48,0x32,0x88,0xEF,0x5C,0xB4,0x64,0xB7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xF0,0x0A,0x71,0x63,0x53,0x47,0xF2,0x30,0xD6,0xA4,0x6D,0x40,0x95,0xFD,0xCF,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x28,0xDE,0x6A,0x46,0xE8,0x8D,0x7F,0xC8,0xE6,0x43,0x75,0x0A,0x6A,0xAE,0x02,0x19,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xF5,0x20,0xEA,0xBE,0xF6,0xD2,0x01,0x36,0x77,0x63,0xCF,0x3E,0x04,0x92,0x93,0xCD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x7F,0x3B,0x95,0xB7,0xE6,0x01,0x7C,0x2C,0x58,0x23,0x0E,0xCA,0xD5,0x03,0x90,0x91,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0xA6,0x84,0x43,0x27,0xB0,0x75,0x7A,0x7A,0x98,0x71,0x3A,0x83,0xA0,0x80,0x82,0xA3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x4A,0x9B,0xCF,0x30,0x5D,0xFE,0xEA,0x35,0x7C,0xA4,0xB3,0x43,0x5A,0xAF,0xBB,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0x97,0x95,0xB4,0x48,0x21,0x07,0xB7,0x06,0xAE,0x52,0x35,0x25,0x52,0x78,0xD7,0x4D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x57,0xA6,0xB7,0x54,0xE2,0x28,0x26,0x5E,0xEE,0x1A,0xD4,0xF9,0x50,0x00,0xC9,0xF9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x83,0x97,0xFB,0xFF,0x50,0xE2,0x7E,0xA0,0x5B,0x7D,0xD2,0x35,0x20,0xB5,0x30,0xBA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x97,0xE6,0xBF,0x12,0xAD,0x5F,0x0F,0x6D,0x12,0xCA,0xD0,0x2C,0x65,0xC7,0x00,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0x63,0xEC,0x59,0x00,0x8F,0x34,0x60,0x6C,0x02,0x98,0x29,0x38,0xC0,0x31,0x97,0xA3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x52,0x0A,0x7D,0x90,0xFA,0x3B,0x5F,0xF1,0x05,0x41,0x6D,0xF2,0x19,0xFC,0x57,0xD2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0x11,0x17,0x13,0x42,0xFF,0x3D,0xDF,0xDB,0x3C,0xFA,0xCD,0x52,0xCC,0x79,0x9B,0x45,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x0A,0xE3,0x83,0x2C,0xA1,0x1A,0x9F,0x5E,0x5E,0xAC,0x51,0x4D,0x7D,0xD6,0x85,0xCC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xD6,0x0D,0x6F,0x78,0xF1,0xF5,0x29,0xED,0x83,0xBF,0x17,0x36,0xC2,0x7E,0x13,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x91,0x31,0x98,0x91,0xC8,0x1D,0x5F,0x84,0xFA,0xF2,0x90,0x17,0xBA,0x5E,0xD4,0xCE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x51,0x3D,0x80,0x62,0x64,0x4E,0x5C,0x3D,0x01,0xC0,0x0B,0x28,0x1F,0x35,0xBA,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x85,0x81,0xC7,0xF5,0x3D,0xD8,0x74,0x64,0x83,0x14,0x6C,0x78,0xB9,0x90,0xBA,0x4D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x35,0x8B,0x1D,0xFC,0x42,0x81,0xAE,0x80,0x58,0x03,0xAA,0x1A,0x88,0x61,0xCA,0xC1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x56,0x73,0x6A,0x97,0x05,0x47,0x8B,0x5D,0xF7,0xA0,0x3D,0xD5,0xFC,0xDD,0xE8,0x2F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xCE,0x2C,0x0F,0xF9,0x0F,0xB9,0xF2,0x8F,0x59,0x0F,0x64,0xD5,0xD6,0xD5,0x80,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0x34,0x09,0x1F,0x27,0x13,0x63,0x16,0xC5,0x1C,0x2F,0xA8,0x60,0xDA,0xD5,0x65,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xB2,0x98,0x57,0x29,0x88,0xD7,0x38,0xC9,0xC4,0xFD,0x77,0xBF,0xEF,0x86,0x51,0x00,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x96,0xBC,0x2D,0xC3,0x6F,0x2A,0xB2,0x2A,0x6B,0xD9,0x25,0x5E,0x54,0xED,0x0E,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0x43,0x03,0x3B,0x73,0x74,0x01,0x9C,0x54,0x64,0x12,0x81,0xD9,0xAF,0x04,0x7D,0x07,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0xDD,0x19,0x5D,0x6A,0x4A,0xBC,0xA3,0xA3,0x83,0xC6,0xCC,0x51,0x97,0xB0,0x73,0x19,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xF3,0x79,0xFD,0x4E,0xBB,0xAC,0x2F,0xAD,0xBA,0x42,0xE0,0x56,0xA5,0x38,0xD4,0xCE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x56,0x83,0xCE,0x88,0x73,0x1C,0x52,0x6A,0x9A,0xB6,0xF0,0x73,0xF5,0x50,0x67,0xB3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0x34,0x1D,0x39,0xFE,0x50,0xB3,0x07,0x1F,0x32,0x1B,0x80,0x5E,0x66,0x14,0xE4,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x19,0x7D,0x79,0x6C,0x31,0xEA,0x18,0x99,0x06,0xEB,0x8E,0x57,0xCE,0x01,0x28,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0x09,0x15,0x25,0xFB,0x50,0x4F,0xBF,0x00,0xF4,0xF9,0x90,0xB1,0x6D,0xDF,0x15,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x3F,0xE3,0x18,0xFF,0xFD,0xFA,0x5A,0xC5,0xD6,0x45,0xEA,0x1D,0xF4,0x26,0x96,0x9E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xD7,0xA1,0x12,0x72,0x04,0x80,0x12,0xFC,0xF2,0x93,0x89,0x3A,0x56,0xBF,0x87,0x53,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0x07,0x7E,0x87,0xDA,0x57,0x36,0xB8,0x87,0x20,0x9C,0xAD,0xEC,0x5D,0x93,0x9C,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0xDF,0x0B,0x34,0xF8,0xB7,0x98,0xD9,0x30,0x68,0x8F,0x7B,0xEA,0xA9,0x7B,0xBE,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0xB9,0x31,0x95,0xCE,0x2A,0x47,0x6E,0xB8,0x00,0x2A,0x98,0x77,0xD6,0xD9,0xF6,0x3C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0x02,0x88,0x8A,0xCC,0x58,0x79,0x53,0x43,0x50,0x2A,0xEF,0x52,0x7B,0x45,0xA2,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0xD6,0xC5,0x89,0xD6,0x8D,0x78,0xCE,0xC2,0x51,0x82,0xC4,0xA7,0x70,0x9B,0xC6,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0x47,0xB0,0xD9,0xA2,0x9F,0xCF,0x81,0x09,0xFE,0x6B,0x87,0x73,0x81,0x61,0x22,0x38,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0x54,0x2F,0xAB,0x71,0x53,0x13,0xE5,0x98,0x21,0x64,0x1E,0x3E,0x97,0xD0,0x31,0x54,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0x92,0x79,0x8B,0x75,0xC5,0x52,0x50,0xAC,0xB3,0x2A,0x69,0xA1,0x51,0x64,0x34,0x49,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x8B,0x44,0xB4,0xAC,0x79,0xC3,0xF9,0x6D,0xFE,0xED,0x6F,0x1F,0xA4,0x2A,0x4F,0xEB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xA2,0x8B,0xD0,0x99,0x16,0xFA,0xCA,0x07,0x0D,0x66,0x62,0x6D,0x10,0x92,0x4B,0xCB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x08,0x21,0xBC,0xEE,0x59,0xCA,0x7A,0xB1,0x27,0x3D,0x26,0x3B,0x7D,0xFC,0x31,0xE6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x3D,0xF9,0x72,0x0E,0xAB,0x82,0x06,0x31,0x28,0x24,0x6B,0x0C,0xE4,0x06,0x3B,0x82,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x65,0xF7,0xDE,0xE8,0x4A,0xFE,0xD4,0x70,0x0A,0x58,0x07,0x27,0x58,0x16,0x54,0x2D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0x49,0x1F,0x7F,0x08,0x47,0x80,0xA0,0x82,0xCE,0xF3,0xFA,0x38,0x67,0xAE,0x65,0xCF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0x20,0xFA,0x32,0x3E,0x1B,0x21,0x8A,0x31,0x95,0x7A,0x9C,0x33,0x0B,0x47,0x97,0xBB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1B,0xC3,0xDB,0x95,0xCC,0x94,0x1F,0x37,0xA6,0x5D,0x03,0xAF,0x50,0xAD,0xE3,0xE1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x28,0x12,0xF9,0x24,0xB1,0xC6,0xE9,0x1F,0x13,0xE4,0x79,0x24,0xC9,0x01,0xCF,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0x01,0x88,0x62,0xCB,0x80,0x17,0xF0,0xA1,0x06,0x98,0x0C,0x72,0xEA,0x93,0xBE,0xCF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0xB6,0x12,0x6B,0xF3,0x05,0x50,0x0E,0xBA,0xCD,0x2C,0xA6,0xBF,0x25,0x37,0x5F,0x22,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x90,0x5A,0xAA,0x86,0x27,0x8A,0xDC,0x72,0x58,0x9D,0xF0,0x78,0x96,0xF7,0xBC,0xCC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x55,0x45,0xD3,0x35,0xEA,0xC6,0x17,0x56,0x88,0xEF,0xC9,0xCD,0x01,0xF7,0x81,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x36,0xE6,0x9D,0xCA,0xDC,0xE5,0x1F,0x6E,0x4E,0x29,0x44,0x06,0x38,0x09,0x00,0x18,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x8D,0x9E,0x27,0x12,0x15,0xB1,0x91,0x9D,0xA5,0x64,0xBC,0x80,0x7A,0x7F,0x35,0x51,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0x7D,0xF3,0xFC,0x19,0x7E,0x36,0x53,0x9F,0xA9,0xE0,0x85,0x32,0x1E,0xD5,0xB8,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xAD,0xE7,0xA7,0xBC,0xB3,0x1D,0xF4,0x68,0x0B,0x55,0xEA,0xF3,0xB6,0x97,0x55,0x0B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xC2,0x6C,0xF7,0x7E,0x51,0x12,0x2D,0xCC,0x3A,0xA4,0xF3,0x84,0x94,0xD8,0x55,0x90,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0xB4,0xD2,0xC4,0x83,0x26,0x21,0x03,0x50,0x03,0xC4,0x45,0x33,0x2E,0xC1,0xB5,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0x0B,0x62,0x55,0xC2,0x8F,0x23,0x97,0xF7,0x6D,0xD5,0xA2,0x3E,0xB6,0xD4,0xD5,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0x2E,0xEB,0x3F,0x91,0x56,0xDB,0x16,0x0C,0x46,0x18,0x1C,0x4D,0xF7,0xA1,0xFA,0xE2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0x43,0xB2,0xC8,0x90,0xF5,0x5A,0x45,0xA0,0x80,0x41,0xFD,0x82,0x09,0x08,0x3F,0x0C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x46,0xC6,0xCB,0x92,0x2E,0xB8,0x0D,0x48,0x03,0x2B,0xB5,0xA2,0xEB,0xC6,0x14,0xA6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0xB7,0x42,0x96,0xAB,0xC3,0xBE,0x3F,0xC2,0xB1,0xCF,0x9C,0xCC,0x12,0x09,0x70,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xF6,0x1F,0x86,0x93,0x22,0xB8,0x94,0x1C,0xFE,0xD5,0x1C,0xE0,0x30,0xEA,0xA3,0x42,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0x4B,0xA7,0x2D,0xC1,0x77,0x77,0x5D,0x1E,0x53,0x29,0xE0,0xD7,0xBC,0xC6,0xE8,0x26,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0xDF,0x1D,0x2B,0x63,0x89,0x8F,0x83,0x03,0xB8,0x23,0x2E,0x06,0x52,0x83,0x12,0xBA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x2A,0x93,0x4D,0x08,0xA2,0x0D,0x64,0xB3,0x30,0xB2,0xA7,0x5B,0x6D,0xA8,0x7B,0x64,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0xD4,0x5C,0x02,0x52,0xB2,0x5D,0xC9,0xA9,0x9C,0x83,0x12,0x48,0xFC,0xEB,0xAE,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xA3,0x44,0x8F,0xAF,0x22,0x3F,0xE1,0x6E,0x80,0xB3,0x71,0x34,0x88,0xB8,0x28,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x0B,0x5B,0x96,0x72,0x15,0xC1,0x75,0x12,0x7C,0x49,0x59,0x69,0xCC,0x62,0xF2,0xF3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0x71,0x1D,0x41,0x0D,0xC3,0xFD,0xB6,0xEC,0x4C,0x8B,0x4F,0x29,0xD2,0x2F,0x17,0x5E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xB9,0xCA,0x86,0x8E,0x65,0x1F,0xCE,0x42,0x26,0xB0,0x51,0x64,0xF1,0x4F,0x20,0xF8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x9A,0xB8,0x10,0x7B,0x62,0xB9,0xC0,0xBC,0x4F,0x2D,0xEA,0x25,0xE1,0x11,0x98,0xBF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x47,0xD8,0x2B,0xA7,0xA0,0x47,0xC5,0xA5,0x35,0xCA,0xD2,0x52,0x14,0x3D,0x03,0x87,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x40,0x96,0xE3,0x72,0x6B,0x29,0x99,0x80,0xB7,0xD7,0xE8,0x94,0xE9,0x57,0x99,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0x8D,0x20,0x16,0x04,0x2C,0x59,0x6F,0x53,0xD0,0x4D,0xF3,0xE2,0x26,0xFE,0xC1,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xD5,0xAB,0xBD,0xE6,0x1E,0x78,0x30,0xED,0xAF,0x1C,0xE1,0x22,0x36,0x53,0x86,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x4E,0xBF,0x54,0x26,0xBC,0x53,0x22,0x9E,0x8B,0x59,0xF3,0x82,0x7E,0x86,0x7B,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x8F,0xF5,0x9E,0x3E,0x15,0x92,0xE5,0x85,0xF3,0x34,0x51,0xA8,0xF5,0xCC,0xC0,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x51,0x34,0xFE,0x6C,0x5E,0xDF,0x68,0x0B,0x88,0x43,0xA7,0xF9,0xE0,0x63,0x5F,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x82,0x39,0x29,0x35,0xFD,0x80,0x69,0x23,0xB3,0xC4,0x02,0xBC,0x17,0x2E,0x9C,0x6E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0xEF,0x00,0xF4,0x7D,0x90,0x54,0x8C,0x13,0xF5,0xB0,0x02,0x8F,0x7A,0xE8,0x89,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xF2,0xCA,0x81,0xF4,0x2B,0x58,0x2D,0x48,0x19,0x93,0x33,0x46,0xA6,0x50,0x64,0x64,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEE,0x92,0xE5,0x93,0xCE,0xF3,0xF5,0xF1,0x44,0xC7,0x67,0x7B,0x7C,0x79,0xC1,0x83,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0xE9,0xCF,0x11,0xCA,0xEE,0xEB,0x9C,0x2C,0xAF,0x9E,0xEE,0xA2,0x04,0xFD,0x6C,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xC3,0x2B,0x8E,0x7A,0x22,0x5A,0x8C,0xB9,0x25,0x4F,0x1B,0x31,0xC0,0x12,0x34,0x7A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0F,0x2B,0x47,0x78,0x6E,0xFF,0x41,0x42,0x18,0x1E,0x56,0x21,0xEF,0x82,0x39,0x09,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xA5,0xBB,0xD0,0xAA,0xA7,0x3D,0xF3,0x39,0xD1,0x9E,0x86,0xF1,0x78,0xC0,0x85,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0xB8,0xEB,0xFE,0x4A,0x3F,0x7E,0x5C,0x3C,0x80,0x77,0x27,0xF7,0x7E,0x92,0x10,0xE7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0xF2,0x4A,0xF6,0x1C,0x80,0x3A,0x22,0x72,0x33,0xE9,0x97,0xEC,0xDC,0x69,0x6F,0xFB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xDD,0x48,0x85,0x7A,0xFA,0xA5,0x24,0x36,0x53,0x10,0xA7,0x38,0xE2,0x5E,0xD1,0xBF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x5F,0xF5,0x19,0x07,0x9C,0x21,0x5C,0x35,0x44,0x87,0xD8,0x29,0x13,0x99,0x5D,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x06,0x39,0xAE,0x4E,0xE7,0x04,0x31,0xEC,0x29,0xF0,0x46,0x12,0xFD,0x2F,0x3E,0xAD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0x34,0x72,0xA8,0xB1,0x0B,0x7F,0x93,0x4B,0xF2,0x7A,0x91,0x02,0x88,0x96,0x63,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xAA,0x02,0x93,0xBC,0xAD,0xBB,0x18,0x34,0x65,0x5F,0x47,0xDA,0x3C,0x0B,0x6A,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x10,0x62,0x2F,0x18,0xB7,0x97,0xD2,0x28,0x0F,0xF9,0xEA,0x1F,0x8A,0xFB,0x00,0x41,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0xB2,0x13,0xDD,0x19,0x43,0xAC,0x37,0x32,0x6C,0xD5,0xB3,0xF3,0xF8,0x67,0xFB,0x83,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0x39,0x48,0x79,0x63,0x28,0x2D,0x9D,0x56,0x29,0x33,0x4C,0x20,0x8B,0x40,0x8C,0xC0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0x4B,0x3E,0xE0,0x18,0xEC,0x10,0xCD,0xD1,0xFF,0x11,0xB7,0x72,0x41,0xEF,0xE5,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x91,0xEF,0x34,0xD0,0x74,0xE6,0x85,0x93,0x32,0xAD,0x16,0x6F,0x9A,0x0C,0x31,0x87,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0xE2,0xF9,0xFB,0x7E,0x47,0x6E,0x3E,0x6C,0x19,0x95,0xC5,0x3E,0xB7,0x02,0xA3,0xE1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0x8D,0x00,0xB8,0x3E,0xA9,0xB5,0x28,0x33,0x7B,0x86,0x42,0xED,0xD8,0x88,0xC1,0x97,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x0F,0x22,0x65,0x69,0x20,0xD7,0x21,0x55,0x88,0x23,0x69,0xBD,0x48,0x55,0xC9,0xB5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x04,0x0C,0xB9,0x5B,0xFA,0x55,0x16,0x98,0xF8,0x88,0x78,0x05,0x49,0x5D,0x7A,0x7E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0xB8,0x8F,0x3E,0x01,0xAD,0x0E,0x0E,0xDE,0xD7,0x7C,0x81,0xAF,0x8A,0x24,0xF5,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0x11,0xF5,0xAF,0x2C,0x11,0x85,0x10,0xBD,0x8D,0xA2,0xA7,0x5A,0x6E,0xE7,0xCE,0x4D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0xC6,0x4F,0x30,0x16,0x58,0x59,0x7A,0x78,0x74,0x26,0x57,0x19,0x09,0x49,0xDB,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x2F,0xA9,0x31,0xCB,0xDF,0xE9,0x8C,0xB6,0xD5,0x61,0x63,0xC0,0x8E,0x8E,0xC5,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0x0B,0x2A,0xA6,0x84,0xBA,0x51,0x35,0xDB,0xE8,0x3E,0x37,0x3D,0xB5,0xB5,0x0B,0xBA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0x17,0xFD,0x4D,0x18,0x77,0x6D,0x7F,0x2B,0x98,0xA2,0xBE,0x5F,0x73,0xB0,0x1F,0x17,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0xF9,0xA7,0xA4,0x99,0x5B,0xD0,0x84,0x00,0x88,0x85,0xF8,0x01,0xBD,0xAB,0x82,0x3D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0xCD,0xBC,0x34,0x80,0x41,0x25,0xDA,0x3F,0x83,0x39,0xDB,0x78,0x92,0x89,0x09,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0xF9,0x17,0xF4,0x78,0x9A,0xA1,0xE1,0x8B,0x21,0xF0,0x7A,0xAB,0xEB,0x78,0x8C,0xD7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0xC5,0x31,0x8D,0xE8,0x88,0x23,0x7B,0x79,0x37,0x67,0x61,0xB2,0xF4,0xE8,0xFF,0xD4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xAD,0x62,0xE1,0xFD,0xC5,0x4A,0xF1,0x4E,0xE8,0x63,0x9C,0xBC,0x91,0xA0,0x08,0x48,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xA5,0xB2,0xD3,0xF0,0x27,0xA3,0x20,0x72,0x42,0x0B,0x68,0x2A,0x9A,0x45,0xEA,0xF1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0x2C,0x16,0x78,0x66,0xE7,0x37,0xFA,0xDD,0xBD,0xE4,0xF4,0x91,0xAB,0xA2,0x57,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x42,0x6F,0xA1,0xF6,0x7A,0xAE,0xEA,0xCB,0x89,0x6B,0x91,0x4C,0x7C,0xB6,0xFB,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0x2B,0x60,0x1B,0x3B,0x3A,0xB7,0x40,0xD2,0xF8,0xF9,0x9C,0xD6,0x1F,0x08,0xE0,0xBB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x9C,0x98,0x20,0x6A,0xA0,0x48,0xF2,0x7E,0x85,0xF5,0x68,0xEE,0x42,0x07,0xDE,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x97,0xA0,0xFD,0x1D,0x07,0x2C,0x2C,0x4D,0xA2,0xCF,0x35,0x84,0x36,0xDE,0x78,0xF7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x91,0x0F,0xDB,0x79,0x88,0x4C,0x25,0x03,0xEA,0x0B,0xA5,0xB9,0x1F,0x2F,0xE3,0x1B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x06,0x3C,0x8B,0xD4,0x79,0xD6,0xF0,0x73,0x0E,0xB2,0x09,0xE8,0x1A,0x02,0x6F,0x75,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0x68,0x92,0x3C,0xBF,0x6F,0xAB,0xE0,0xEE,0xB1,0xA7,0x63,0xAD,0xBA,0x20,0x71,0xB2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xEE,0x75,0xCB,0x8E,0x36,0xD1,0x92,0x74,0x61,0xBD,0x30,0xCF,0x90,0xA8,0xA1,0x9D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0xD5,0x29,0x9A,0xEB,0x65,0x27,0x24,0xD9,0x6C,0x92,0x31,0xB9,0xF9,0xD1,0xB4,0x9B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0xF9,0x84,0x4F,0xA1,0x6A,0x48,0x1F,0x61,0xE5,0xA5,0xEA,0x46,0x25,0x27,0x0C,0x06,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0x48,0xBF,0xBA,0xEF,0x0B,0xAD,0x65,0x6A,0x62,0xD9,0x32,0xFF,0x3B,0xA2,0xAD,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0x9A,0x1E,0x7B,0x47,0x2D,0xE4,0x98,0x5A,0x1E,0x41,0x75,0x38,0x30,0xA3,0x8A,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0x93,0x1A,0xB9,0x1D,0x80,0x2A,0xFB,0x98,0xF0,0xB1,0xE1,0x1E,0xBB,0x4A,0x34,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0xED,0x88,0x7F,0xB8,0xDA,0xA3,0xA3,0xFD,0x01,0x2D,0x6D,0x96,0xB6,0x7D,0xBA,0x54,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xE8,0x95,0x39,0x77,0x20,0x80,0x91,0x79,0x8C,0xD5,0x73,0x29,0xAA,0xB7,0xAE,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0xF1,0x8D,0xAB,0x3B,0x71,0xE2,0x01,0xBF,0xE3,0xB6,0x10,0xF7,0x3C,0xC3,0xF6,0x77,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0xBD,0x7D,0x6D,0x29,0xBB,0x34,0x04,0x3F,0x53,0xC6,0x87,0xBF,0x2C,0x5B,0x5A,0x52,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x23,0xCC,0xC0,0x9C,0x82,0x33,0xDF,0xBF,0x38,0x4F,0xA6,0xA3,0x46,0xE2,0x1E,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0x33,0x1B,0xBE,0x37,0x74,0x2E,0x30,0x16,0xC2,0xC9,0xD0,0xF9,0xDF,0x43,0x1D,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x9F,0x25,0x1D,0xD4,0x39,0xBE,0xEE,0x90,0x59,0x58,0x24,0xC8,0x03,0x04,0xE7,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x31,0xFB,0xC5,0x45,0x5A,0xED,0xA6,0xF2,0xFC,0xB5,0xB1,0xB0,0x53,0xBD,0xC3,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x11,0x74,0x06,0xE5,0x19,0x4A,0xB1,0xE1,0x28,0xA7,0xBF,0xAB,0x05,0x07,0x77,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x1A,0xC4,0x58,0x4A,0x0B,0x16,0xCB,0x56,0x57,0xC4,0x7F,0x7A,0x6A,0x47,0x5B,0x03,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x19,0xC7,0x0C,0x09,0xF9,0x23,0xF6,0xFF,0x80,0x27,0x32,0xF4,0xEF,0x8E,0x9E,0x28,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x4B,0x03,0x09,0xD6,0x3D,0x9F,0x28,0x90,0x2F,0x4B,0x39,0xB6,0x37,0xDF,0x12,0xD0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0x66,0x57,0xD7,0x91,0x3A,0x7A,0xE0,0x44,0x16,0x77,0xB9,0x11,0x4B,0xEF,0x80,0x39,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x6D,0xE1,0xB0,0x5C,0xB3,0x6F,0x95,0x46,0xD5,0xDE,0xDD,0xE3,0xF5,0xEA,0x64,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x61,0xD4,0xE9,0xD5,0x55,0x8F,0x23,0xCE,0x18,0xC0,0xCF,0x15,0x21,0xAD,0xD7,0xE8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0x75,0xA1,0x3D,0xE7,0xD4,0x6B,0x1D,0x43,0x3B,0x4C,0x6C,0xF0,0xBC,0x20,0x24,0x8F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0x7F,0x68,0x0A,0x04,0x80,0x0F,0x46,0x3C,0x68,0xEB,0x08,0xA7,0xEC,0x3F,0xBA,0x8A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x62,0x15,0x7D,0x6E,0x29,0x8F,0x7A,0xFC,0x41,0xE5,0xE0,0x7D,0x59,0x5C,0x92,0x06,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0x34,0xF1,0x7B,0x9E,0x53,0xC0,0xD3,0x1B,0xB0,0x09,0xF1,0x8B,0x61,0x3C,0x41,0x7B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x7B,0x3C,0x76,0xC8,0x94,0x77,0x09,0xF0,0xAF,0xC7,0x1A,0x1A,0xB0,0xED,0x06,0x4C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x76,0x96,0xD4,0xDF,0x2B,0x10,0x66,0x99,0xAA,0xAB,0xA7,0x8E,0xDB,0x92,0x82,0xCC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0x78,0x4B,0x8C,0x10,0x84,0xCC,0xB2,0xCB,0x4C,0x4D,0x7B,0xAD,0x92,0x47,0x3E,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x4F,0x18,0xD9,0x8E,0xA2,0x8F,0x34,0x4C,0xDA,0xA7,0xDC,0x10,0x1F,0xD7,0x55,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0xCB,0xDD,0x53,0xB1,0xA6,0xC4,0xC2,0x81,0x10,0x31,0xE7,0xD9,0xFF,0x43,0x30,0x9E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xA7,0xCF,0x7A,0x0C,0x7E,0x8E,0xD5,0x72,0x96,0xDA,0x92,0xA0,0x5A,0xF7,0x59,0xF3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0x7D,0x2B,0xF8,0x86,0x0B,0x99,0xD3,0x4B,0xE6,0x40,0x82,0x8D,0x3F,0xA6,0x4D,0xB3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0xE8,0x08,0x98,0x82,0x7A,0xBC,0xAF,0xE2,0x70,0x7D,0x61,0x1E,0x94,0x46,0x55,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0xFC,0x9E,0x5B,0x49,0x4F,0xA3,0x04,0x82,0x19,0x48,0x0F,0xF9,0x8E,0x8C,0x61,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x25,0x8C,0x36,0xAE,0xFF,0x25,0xF9,0x90,0xFB,0x6B,0x23,0xCA,0x3C,0xAC,0xA3,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x96,0x2F,0x52,0x66,0xE9,0xB3,0x91,0xE8,0xA3,0x08,0xCE,0x4F,0x21,0x3D,0x2F,0x51,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0xF5,0xF6,0x0F,0x61,0x54,0x24,0xD9,0x27,0xCF,0xB1,0xC0,0x2B,0xD0,0x34,0x21,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x4A,0x2F,0x64,0x1B,0x62,0x8F,0x18,0x30,0xBD,0xE4,0xF3,0xAE,0xF0,0xBB,0x6F,0x84,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0x90,0xEE,0x7B,0xCB,0x3D,0x5E,0x84,0x40,0x2B,0xF9,0x85,0xFB,0xE2,0x17,0x6A,0x15,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x9B,0x00,0x5C,0xE1,0x4A,0xA8,0x06,0xAE,0x7C,0x7A,0x5A,0x9D,0x69,0xC8,0x42,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0xB9,0x80,0x07,0x2E,0xF1,0x04,0x93,0xDB,0xC8,0x40,0x81,0x15,0x60,0x2F,0x56,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x70,0x44,0xAA,0xA0,0xED,0xAF,0x0A,0x2B,0xA1,0xF5,0xFF,0x51,0x46,0x7A,0x72,0xF8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x23,0x0F,0xC3,0x18,0xDB,0x27,0x11,0xF0,0x2E,0x4A,0xE7,0x7E,0x44,0x5A,0x56,0x71,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xD4,0xA9,0x82,0xA1,0x0C,0x32,0x70,0xEC,0x91,0x80,0x5E,0x75,0x94,0x43,0x49,0x17,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0x60,0x92,0x86,0x13,0x0C,0x3C,0x47,0xA5,0xBB,0x2F,0x1F,0xBE,0x0D,0xCB,0x93,0x47,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x9B,0xD5,0x82,0x4B,0xBD,0x6E,0xEC,0x45,0x5D,0x8E,0x38,0xF5,0x82,0x5F,0xA6,0xA1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xBB,0x5C,0x05,0x6B,0xCF,0x13,0x5F,0x28,0x87,0x21,0x72,0xA8,0x7F,0xA0,0x8F,0x41,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0xE6,0x69,0x86,0xCD,0x51,0x77,0xD6,0xDF,0x24,0x73,0xA7,0x49,0xCC,0x1E,0xAE,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0xBB,0xEB,0x64,0x1F,0xE3,0xFF,0x1C,0x8B,0x90,0x3F,0xA8,0x51,0x8F,0x5A,0xC3,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x32,0xDD,0x64,0x41,0x0F,0xD7,0x5D,0x52,0x3A,0x4E,0x9B,0x8E,0xFF,0xE3,0x1F,0x48,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0x89,0x49,0xF5,0x80,0x9E,0xCE,0x41,0x7B,0x7E,0x29,0xF0,0x0B,0x5A,0x52,0x5E,0xB2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0xF2,0x0A,0x56,0xD3,0xDC,0xBE,0xCC,0x87,0x7D,0x7F,0xAC,0xBD,0x92,0x8F,0x43,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xB7,0x50,0x24,0x94,0x52,0x8B,0x51,0x60,0xF5,0xEE,0x0F,0x65,0xC3,0xA7,0xB4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x09,0x68,0xEF,0x7B,0x7A,0x79,0xF7,0x07,0xDC,0x10,0x42,0x88,0x6F,0x7A,0xFD,0x89,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0x8A,0x38,0x32,0xAC,0x71,0x7E,0xBB,0x23,0x06,0x99,0x53,0x0B,0x71,0xA6,0xA1,0x64,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x03,0x27,0x67,0x83,0x30,0xD3,0xCA,0x4D,0xC1,0xCA,0xB3,0x1C,0xBB,0x03,0xA0,0x4B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0xB1,0xDB,0x1C,0xAC,0xDF,0x0B,0xF9,0x9B,0xE5,0xCF,0x76,0x9F,0xCD,0x0E,0x94,0x06,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0xE1,0xF0,0xDB,0x71,0x7C,0x6D,0x2B,0x54,0x16,0x46,0xC4,0x3A,0x09,0x72,0x23,0x1C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xAF,0x2A,0x40,0x69,0x1C,0xFE,0xD4,0x20,0xB3,0xFA,0x85,0x4B,0xC2,0x9B,0x2E,0x28,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0x6E,0xE3,0x2C,0x0B,0xA9,0x03,0x15,0xFC,0x14,0x65,0x4B,0x19,0x89,0x96,0x24,0xBD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xB5,0x90,0x52,0xA3,0x34,0xC0,0x7D,0x08,0x2E,0xA1,0x80,0x21,0x11,0xCF,0x5B,0x98,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x0A,0xD4,0x80,0x1D,0x71,0xCB,0x90,0xEF,0xC3,0x41,0x8B,0x17,0x28,0x89,0x99,0x0E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x75,0x16,0x1D,0x7A,0xD1,0x38,0xDD,0xDD,0x2B,0x0F,0x11,0x51,0x7E,0xA7,0x2B,0x25,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x4D,0xBE,0x27,0x7E,0x4D,0x96,0x33,0x78,0x24,0x2E,0x2C,0x1B,0xE6,0xF4,0x8E,0x1B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x6C,0xA9,0xD4,0x0E,0xB3,0xD3,0x28,0x33,0x98,0x34,0x3C,0xD5,0xDD,0x3D,0x25,0x0D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x47,0x83,0x3A,0x40,0xEE,0x4F,0x87,0x79,0xED,0xF7,0xB6,0x8B,0xF7,0xE8,0x54,0xB9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0xB0,0x57,0xCE,0x7A,0xCC,0x2C,0xC7,0x22,0xD4,0x69,0x15,0x88,0x50,0xC4,0x9E,0x43,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0x14,0x8F,0x48,0x0A,0x99,0x73,0x96,0x6B,0x85,0x28,0x3F,0x42,0x4B,0xD8,0x7D,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0x5A,0xF0,0xC6,0xAF,0x45,0x82,0x41,0x9A,0x33,0x17,0x17,0x6F,0x88,0x7B,0x67,0xB1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0xC1,0xC6,0xA4,0x0C,0x6F,0x49,0xD4,0x56,0xA7,0xDB,0xE5,0x07,0x51,0xBB,0x56,0x3C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xF7,0xA5,0x07,0x0D,0xAB,0xBC,0x60,0x11,0x35,0xFF,0x21,0x83,0x61,0xB2,0x8B,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0x08,0xFC,0x55,0xF8,0x53,0x04,0x03,0x3A,0x93,0xE2,0x87,0xA6,0x70,0xB9,0xBF,0xDD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0x73,0x99,0x1D,0x15,0x89,0x28,0xB1,0x1C,0x80,0x28,0x5D,0x6B,0xAC,0xCE,0x5A,0x28,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB1,0xCC,0x48,0xA7,0xDC,0x27,0x98,0xE9,0x64,0x8A,0x78,0xE8,0x16,0x5C,0xD9,0x74,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0xDE,0x7E,0x9E,0xCC,0x5F,0x35,0xC1,0x01,0xCD,0x96,0xFA,0x17,0x2E,0x5F,0xD4,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x92,0x1C,0xB5,0xEF,0x3B,0xF1,0x7D,0x95,0x39,0x63,0x68,0xC0,0x6F,0x1A,0x10,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xAC,0x57,0x81,0x6F,0x16,0xE9,0x71,0xDE,0x6A,0x49,0xD8,0x70,0xF0,0x25,0x88,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0x0D,0x7B,0x3D,0xB8,0x95,0xB2,0x88,0xF4,0x39,0xBA,0x4B,0x74,0x13,0xD5,0x5B,0x5A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0x60,0x04,0x7C,0x01,0x5E,0xFB,0xC3,0xC3,0xD8,0xFB,0xE2,0x7D,0x53,0x71,0xE4,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x8A,0x9D,0xFD,0x1B,0x2B,0xDA,0x76,0x8B,0x38,0x0B,0xA4,0x12,0x4A,0xD2,0xFB,0xF0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0x98,0xA7,0xD4,0x99,0x68,0x2D,0xC1,0x87,0x98,0x64,0x90,0x05,0x64,0x0A,0x83,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0xEA,0xC1,0x20,0xBE,0xF5,0xA9,0x4A,0x6E,0xDA,0xCB,0xC4,0xF4,0xF1,0x84,0x01,0xF0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x56,0x04,0xA8,0x14,0xAC,0x60,0xD0,0xEF,0x6A,0x85,0x81,0xCD,0x5D,0xBB,0x73,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x46,0x7F,0x3C,0xFA,0x2A,0xDB,0x8C,0xBA,0xE0,0xB2,0x72,0xC0,0x79,0x00,0x62,0x3F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x12,0x57,0x02,0xF4,0xF6,0x8C,0xB3,0x21,0x49,0x2B,0xC1,0x47,0xDC,0x90,0x2F,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0x60,0x05,0x7B,0x93,0x7A,0x5E,0x00,0x2F,0x48,0x8D,0xC1,0x9D,0xB7,0xB0,0x11,0xCF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0x46,0xF4,0x9B,0xFE,0xE2,0x44,0xA6,0x0A,0xD6,0xF7,0x09,0x90,0x2F,0x15,0x07,0x1D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0x72,0xA4,0x7C,0x50,0xC6,0x36,0xB7,0xA0,0x84,0x41,0xA1,0x85,0x10,0x90,0xC1,0xFA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC8,0x1F,0x9A,0x3C,0x40,0xD5,0xED,0xD0,0x24,0x66,0x84,0x10,0xCD,0xE6,0x25,0xAB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xFE,0xEC,0x4E,0x9B,0xBD,0x96,0xAD,0xB5,0x42,0x95,0xE1,0x20,0xEC,0xB2,0x48,0xA7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xF2,0x39,0xF4,0xEB,0x51,0xF8,0xEB,0x08,0x84,0x31,0x11,0x64,0x64,0x57,0x0B,0xCB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x30,0xFA,0x73,0x7E,0xFE,0x73,0x25,0xB2,0x89,0xB9,0xE8,0x1C,0x5A,0xAF,0xAF,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0xBB,0x2A,0xE8,0x78,0x9D,0xF5,0x43,0xA5,0xFB,0xBD,0x62,0x56,0x8E,0x72,0x3C,0x0B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x91,0x57,0x8B,0xDA,0x44,0x5F,0x09,0x1C,0x50,0x5F,0x2D,0x44,0x7D,0x4E,0xA7,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0x27,0x87,0x6E,0xD2,0x0E,0x29,0x9A,0x2C,0x5E,0xD0,0x22,0x00,0x21,0xC2,0xCB,0xAF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x24,0xF3,0x5A,0x06,0x6A,0x45,0xD3,0x62,0x54,0xA4,0x48,0xE4,0x78,0xDD,0x80,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC8,0x1C,0x4B,0x4F,0xF1,0x8C,0x6C,0xFF,0x5E,0xCD,0x4B,0xDA,0xE1,0xA9,0xF2,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0x16,0x9A,0xCA,0xF1,0xC7,0xAF,0xD2,0xE2,0xE5,0x78,0xC8,0x11,0xCF,0x0E,0xF9,0xE2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0x08,0x03,0x26,0x2A,0xD2,0xDD,0x06,0x5A,0xDB,0x02,0xB5,0x28,0x27,0xFA,0x0C,0x0E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0xAF,0xCD,0x5E,0xA3,0x2D,0x14,0x69,0x48,0xBE,0xBB,0x1F,0x87,0x7D,0xBA,0x61,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0xAF,0x47,0x42,0x6D,0x63,0xE6,0x24,0xA3,0x80,0xC5,0x30,0xAE,0xE6,0x86,0x4E,0xB4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xE0,0xF0,0x0B,0x83,0x02,0x96,0x40,0x4A,0x13,0xC2,0x60,0x7D,0x34,0x43,0x6D,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0xCA,0x01,0xCB,0x7E,0x7B,0x38,0x04,0x30,0xBF,0xA3,0x1F,0x59,0xF7,0x2B,0xBD,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x74,0xB7,0x73,0xFD,0xBF,0x6E,0xE8,0x0F,0xC6,0xE3,0x09,0x70,0x5F,0x6E,0x7C,0x72,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0x6C,0x1D,0xEC,0xB6,0x3D,0xAD,0xF2,0x3C,0xEE,0xA1,0x10,0xEA,0x76,0x12,0xC7,0x50,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x8D,0x45,0xAF,0x07,0xA3,0xCA,0x13,0x54,0xDC,0x63,0x68,0x29,0x45,0x2F,0xF3,0x1A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0xC5,0xC0,0x9A,0x8F,0x06,0xDB,0x6D,0xB5,0xB7,0xFD,0x14,0x76,0xF8,0x8E,0xD2,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xE3,0xBA,0xFB,0x7E,0xE5,0x3E,0xC8,0x9C,0x1E,0x49,0x85,0xCA,0x18,0xDB,0xF5,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xD4,0x88,0xE4,0xF8,0xE0,0xCC,0xFC,0x92,0x8F,0x34,0xAA,0x6A,0xBF,0x4D,0x5B,0xCF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0x86,0xCF,0x5D,0x59,0x76,0xBD,0x07,0x57,0x20,0x9A,0x4A,0xC6,0xAB,0x9F,0x8E,0x27,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0xB5,0x68,0xA8,0x80,0xF0,0x86,0xFC,0xDB,0xE7,0x2D,0xB7,0x55,0x62,0x08,0x4C,0xD0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0xC7,0x58,0xE5,0xB7,0xBF,0xA7,0xC4,0xF7,0x27,0x19,0xBA,0x49,0x3C,0x85,0x46,0xB5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x33,0x12,0x7E,0x7E,0xF7,0x3A,0x45,0x98,0xA9,0xB8,0xA3,0xEB,0xE1,0x10,0xCB,0x91,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0xEE,0x6F,0xC9,0x82,0xE4,0xE3,0x4D,0x3D,0x71,0x55,0x8E,0x7C,0x97,0xBE,0xD5,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0xC5,0xB3,0xD1,0xD5,0x30,0x5E,0xD4,0x86,0xC8,0x91,0x9E,0x3F,0xA8,0xB0,0x20,0x62,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0xE7,0x24,0x65,0xC0,0x2C,0x4D,0xA8,0x44,0x85,0x76,0x3F,0xBF,0xD9,0xA0,0xED,0x04,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xF4,0xC5,0x9C,0x94,0xC8,0x53,0xF9,0xE7,0x6C,0xE1,0x19,0x03,0xEE,0xA3,0x1B,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0x45,0xFA,0xEA,0x4A,0x42,0x9E,0x04,0xC4,0xDA,0x63,0x02,0xF7,0xA4,0x7F,0x75,0x77,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xEF,0xCC,0x82,0x48,0x47,0xBE,0xEC,0x15,0x10,0x4B,0xD7,0x5E,0x19,0x47,0xD3,0x6B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0xE9,0xA2,0x2A,0xC4,0x63,0xA4,0x6D,0xA1,0xA9,0xCC,0x2D,0xF0,0xE6,0xAF,0xAA,0xAE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x3D,0x28,0xAF,0xC5,0x3D,0xBF,0x3D,0x7A,0xFA,0x46,0x86,0x28,0x2D,0x92,0x96,0x13,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x78,0x79,0x55,0x77,0x7E,0xBA,0xD1,0x0F,0xC1,0x9C,0x8E,0x10,0x16,0x6A,0x9A,0xC3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x3A,0x83,0xB7,0x9B,0x6F,0xA6,0x36,0x68,0xAD,0xF2,0x5A,0xB3,0xC9,0xF0,0x66,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0xA5,0x6F,0xC1,0x5A,0xBB,0x35,0x39,0x73,0x9D,0x55,0xDC,0xBD,0xEB,0x3C,0x3B,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0xD5,0xD9,0xE6,0x36,0x33,0x5B,0xB7,0x88,0xE3,0x0B,0xBA,0x5D,0x44,0xFD,0xB3,0x1A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0xF9,0x3E,0x90,0xC5,0x2C,0x18,0xB4,0xB5,0x85,0xA8,0x44,0x38,0x0B,0xA8,0x04,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x56,0x4F,0x13,0x27,0x37,0x50,0xD1,0x2F,0xDD,0xAA,0x6E,0xE0,0x2C,0x34,0x23,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x32,0xBC,0xA6,0x5E,0xF9,0x88,0xBE,0xC4,0xF6,0xC4,0x3E,0xD5,0x1C,0x6F,0x78,0xB4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x08,0x5E,0xC0,0x25,0x76,0x48,0x9C,0xAA,0x6C,0xCB,0x11,0x8D,0xC4,0x80,0xC6,0xE0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x60,0xBC,0xDF,0x8B,0xD4,0xAE,0x74,0x13,0x14,0xC5,0x22,0x18,0x04,0xC7,0x88,0x72,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x97,0x24,0x4D,0xC3,0x2B,0x1F,0x6B,0xD4,0xBC,0x9B,0xE7,0xB0,0x98,0x1A,0x4B,0xBC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0xCB,0xF4,0xD8,0x16,0x85,0x41,0xCC,0xCE,0x92,0xFC,0xC5,0x60,0xEC,0x03,0xAB,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xB7,0x1B,0x14,0xFB,0xE3,0x45,0x5C,0x7F,0xDB,0x0B,0xA0,0x38,0xFE,0xC1,0x44,0xAB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x22,0x6C,0x30,0xFC,0xBC,0xC9,0xD7,0x78,0x6A,0xCA,0x92,0xF5,0x9B,0xAB,0x10,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xF8,0x03,0x7B,0x59,0x60,0x34,0xA5,0x53,0x68,0x52,0xC0,0xA9,0xBA,0xC7,0x89,0x6A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x29,0x3F,0xE4,0xBC,0x8C,0x7D,0x35,0x45,0x37,0x99,0x61,0x80,0x47,0xFD,0xB8,0x01,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0x89,0x4A,0x00,0x28,0x28,0x21,0x32,0xCB,0xC9,0x24,0xD5,0xE2,0x08,0x89,0x12,0xA8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x80,0xAC,0xF3,0x6B,0xFC,0x3D,0x16,0xBA,0x9D,0x00,0xAE,0xAB,0x63,0xA2,0x18,0x10,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0x39,0xB3,0x6A,0xE5,0x23,0x51,0x39,0x82,0x9C,0x39,0xEC,0x7E,0x4C,0xC1,0x7D,0xA0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xC4,0x27,0xFD,0x32,0xB1,0x51,0x10,0x3F,0x38,0x64,0x6D,0xC1,0xB8,0x3E,0x11,0xAB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x3A,0x8D,0xB2,0xCA,0xE4,0x49,0x99,0x58,0x68,0xFB,0x14,0x4F,0xC0,0x81,0x35,0x2D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0x31,0xBF,0xB5,0xFC,0x28,0x3A,0xF4,0x9E,0xE1,0x6B,0x65,0xF8,0x1D,0x9C,0x87,0x67,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7C,0xD9,0x85,0xBE,0x29,0xAE,0x5E,0xD9,0x24,0x32,0x01,0x66,0xF5,0xCC,0x68,0x02,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0xEE,0xA1,0xC4,0x7B,0xD1,0xA0,0x65,0x4D,0x9B,0x62,0x8D,0xF3,0x20,0xC4,0x63,0x8C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0xFB,0x65,0xC8,0x89,0x7E,0xAC,0xAA,0x6E,0x35,0x76,0xA7,0x53,0xD9,0xC6,0x76,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x3C,0x57,0x69,0x05,0x1B,0xE4,0xBE,0xCE,0x5B,0x33,0x9C,0x9D,0xC6,0xBD,0x93,0x4A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x90,0xE1,0x89,0x65,0x97,0x08,0x2F,0x37,0x31,0xC7,0xF0,0x55,0xE2,0xDE,0x85,0xED,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xAB,0xC6,0x91,0x64,0x79,0x58,0x21,0x84,0xB4,0xCF,0x4C,0xA8,0x0E,0xAD,0xA1,0x52,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x85,0x72,0x60,0xBB,0xF6,0x44,0x94,0x65,0x8D,0x93,0xB7,0xE4,0x77,0x2D,0xB3,0x01,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0x88,0x32,0xB6,0x78,0x6E,0x01,0x30,0xCC,0xF2,0xDB,0xCD,0x2F,0xE5,0x20,0xFE,0xAC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0xD5,0x5A,0x74,0xEF,0x96,0xE8,0x87,0x59,0x76,0xC7,0xB6,0xB3,0xD2,0xA6,0x4B,0x59,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xF4,0x64,0x3A,0xC5,0xAB,0x8B,0x89,0x03,0x25,0x29,0xCC,0x8D,0x2F,0x17,0xEF,0x0C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0x2D,0xE4,0xFE,0xAE,0x7C,0xBC,0x5D,0xF1,0xB4,0x73,0xA6,0x74,0x60,0x4A,0x2B,0x27,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xC8,0xCE,0x78,0xBB,0xC7,0x19,0xD8,0x5A,0x27,0x89,0xBC,0xE9,0xC2,0x7B,0xD9,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0x38,0xA9,0xED,0xD6,0x14,0x6B,0x2B,0xA4,0x4E,0x90,0xB8,0x1C,0xA0,0xD5,0x79,0x3B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0xCF,0x47,0x7A,0x65,0xCC,0xD3,0x9F,0x62,0xC7,0x54,0x77,0x35,0x68,0xB3,0xEB,0x1D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x86,0xD4,0x2A,0xEC,0xD2,0xEC,0x2D,0x06,0x90,0x88,0x65,0xB3,0x63,0x3C,0xA0,0x2C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0xCF,0xF0,0x9C,0xE1,0x74,0xEA,0x3E,0xF7,0x3F,0x16,0xA6,0xCD,0x67,0xF1,0xDA,0x43,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x7D,0xF2,0x90,0x7E,0xC5,0xCC,0xB1,0xE1,0xC3,0x96,0x33,0x0F,0x49,0x70,0x58,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0xB7,0x03,0x53,0x23,0x0B,0xE0,0x5E,0x6D,0x09,0x97,0x04,0x44,0xDD,0x08,0xD4,0xEF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xFD,0x8B,0x8E,0xAB,0x8E,0x6B,0x99,0x8D,0xF3,0x81,0xF2,0xDF,0x42,0x2F,0x9D,0x0B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0xF7,0x6D,0x51,0x97,0x1C,0xEF,0xDC,0x04,0x51,0xFE,0x9D,0x5B,0xC1,0x7A,0xCF,0x6B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0xC9,0x8D,0x65,0x39,0x7D,0x37,0x8E,0x7C,0x02,0x15,0xF5,0x67,0x81,0xAB,0xC9,0x99,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0xD9,0xDE,0x1F,0x51,0xE2,0x24,0xE4,0xAC,0xEB,0xDD,0x5E,0xA9,0xD3,0x04,0xB6,0xEF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xDE,0xC2,0x7B,0xE9,0x3B,0x35,0x43,0xE8,0x3B,0xF0,0x14,0xF0,0x7F,0xE7,0xAB,0xA7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0x01,0xB1,0x3D,0x22,0xD7,0xD1,0x47,0x14,0xB6,0x0A,0x72,0x0B,0xBE,0xEF,0x55,0xC9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x08,0x37,0x76,0x52,0x86,0x30,0xE9,0xBF,0x77,0x7F,0xE9,0xFA,0x37,0xBE,0x21,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0x63,0x52,0x4D,0x41,0x2A,0x3B,0x31,0xC2,0xCF,0xC5,0x57,0x5B,0x62,0x26,0xB2,0xDF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0x44,0x81,0x8C,0x17,0x23,0x7F,0xEC,0x10,0xF7,0x6F,0x21,0x95,0xE9,0x71,0xF1,0x5D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0xBA,0x8F,0xA8,0x69,0x61,0x9B,0xF7,0x26,0xAF,0x80,0xFE,0xE6,0xAC,0xDB,0xF8,0x6A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0xF6,0x1B,0x3E,0xC0,0xB0,0x5F,0xE7,0x98,0xD4,0x85,0x81,0xF6,0x91,0x88,0xDE,0x97,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0xF9,0xBF,0xA3,0x74,0xF3,0x44,0x8D,0x3D,0xD2,0x8A,0x5D,0xAB,0x81,0x35,0xE7,0xEA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x20,0x49,0x5B,0x27,0x96,0x5E,0x8F,0x7E,0xAB,0xC4,0x00,0x8A,0xE2,0x72,0x1A,0x69,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x0A,0xC0,0x6F,0xB9,0x7F,0x28,0xEB,0x8B,0x0C,0x0A,0x23,0xD4,0xFB,0xCC,0xC6,0x56,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x46,0xD6,0x69,0xB7,0x11,0x0B,0xFF,0x3A,0xF1,0xA6,0x05,0x9B,0xD0,0x21,0x39,0x25,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0xA2,0xC0,0xF3,0x75,0xB8,0xDE,0x4A,0x97,0x5D,0xC8,0x8B,0x2D,0xB3,0x87,0x94,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0xBB,0xE8,0x1B,0xC0,0x39,0x8F,0x88,0xDB,0x5A,0xA7,0xB4,0x4A,0x83,0x6F,0x6A,0xBA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0xDD,0x06,0xF2,0xFC,0xE3,0xCA,0x70,0x72,0xE8,0x11,0xE4,0x5A,0xF5,0xC4,0x91,0x0F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xE9,0xD0,0xBA,0x90,0x07,0x87,0x67,0xEE,0x26,0xE9,0x8A,0x06,0xD4,0x0F,0xE6,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0x13,0x43,0xFF,0x73,0x48,0x98,0x05,0x2D,0x70,0x5B,0x51,0x1F,0x1B,0xE8,0xAD,0x4E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x68,0xC1,0x31,0x95,0xBC,0xD0,0xC5,0x46,0xA5,0xB3,0x8D,0xED,0x90,0x99,0x7C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0xF0,0xA3,0xF5,0x4F,0xAF,0x6A,0x0B,0x1B,0xE8,0x61,0x0B,0x76,0xAF,0xA7,0x78,0xEF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x49,0x55,0x3B,0xD8,0xE2,0xE5,0x2C,0xC7,0x51,0xF6,0x06,0xF5,0xB8,0xEC,0x64,0x6D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x97,0xDD,0x92,0x9C,0x6C,0x43,0x20,0x00,0xC6,0x66,0xE7,0xF3,0xD1,0xD3,0x76,0x2B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xA9,0xED,0x42,0x2B,0x35,0x92,0x92,0x83,0x97,0x80,0x05,0x91,0x74,0x8F,0xDB,0xB2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x02,0x2C,0x3C,0xB8,0xE6,0xD6,0xAE,0x77,0x56,0x16,0xDF,0xE1,0xCB,0xB2,0xD1,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x07,0xB6,0x95,0x99,0x15,0x7C,0xE9,0x90,0x8D,0x48,0x78,0xB8,0x95,0x06,0xEF,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0xFA,0x08,0xFF,0x64,0xB7,0xBC,0xC7,0x51,0x90,0x68,0x54,0xA4,0xFE,0x40,0x5A,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0xF2,0x51,0x6D,0x1D,0x0D,0x0F,0x3A,0xD8,0x14,0x7F,0x88,0x58,0xE8,0xCD,0x59,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0x33,0x70,0x66,0x09,0x95,0xED,0x3A,0x70,0x93,0xF9,0x5F,0x70,0x81,0x11,0xDD,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x53,0x14,0xD5,0xE5,0x6B,0xAE,0xA0,0x3E,0x41,0xE9,0x8D,0xC4,0xE2,0x41,0x14,0xC3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x89,0x30,0xA9,0xBD,0xCB,0x9C,0x11,0x41,0x09,0x71,0x7D,0xFB,0x32,0x8C,0x71,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xBB,0x25,0x3A,0x75,0xDB,0xC4,0x73,0x82,0x9E,0x77,0x1D,0x40,0xCB,0x53,0xC1,0x59,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x44,0x77,0xFB,0xD5,0x87,0xB3,0x98,0x87,0x29,0x7C,0x89,0xF4,0x61,0xF2,0xBB,0xDF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0xA9,0x78,0xF0,0xF2,0x1F,0x9A,0x01,0xCF,0x49,0x8A,0xD9,0x55,0x47,0xCD,0x9B,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xF7,0x94,0x2C,0xAE,0xA4,0xAA,0xC3,0xA5,0x4E,0xCE,0xE3,0x66,0x29,0x48,0x29,0x7E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0x30,0x8F,0xCA,0xE2,0x4D,0xE3,0x99,0x98,0x98,0x2B,0x90,0xCA,0xD9,0xB8,0x46,0xC6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0x69,0x13,0x6A,0xE6,0xB5,0x88,0x5E,0x9A,0x94,0x19,0x4A,0x1D,0xBC,0xC8,0x1D,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0x14,0x8D,0x0C,0x75,0xE9,0xAB,0xED,0x7E,0x95,0xF5,0xF2,0xEE,0x23,0x4B,0x80,0x98,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0x01,0x04,0x20,0xC9,0x48,0x94,0x48,0x14,0x8A,0x70,0xF8,0x31,0xB4,0x97,0xE4,0x0F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x88,0xF0,0xD9,0xA0,0xE9,0x15,0x3A,0x80,0x3A,0x7C,0x41,0xD8,0x1D,0x8D,0x19,0x65,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x7A,0x98,0xE3,0x15,0x15,0x1C,0xA8,0xAE,0xFE,0x6D,0xA1,0xE8,0xC3,0x8D,0xA1,0xD3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0x2F,0x12,0xFA,0x45,0xC2,0x73,0xCC,0x69,0x75,0x95,0x57,0xF9,0xA7,0xC7,0x52,0x50,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0xF5,0x23,0x25,0x0D,0xF8,0xE0,0xF5,0x5E,0xF0,0x26,0x74,0x42,0xF6,0x60,0xCF,0x39,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0x67,0xE1,0xDE,0x06,0xB4,0x9F,0x20,0x86,0x21,0x78,0x83,0xC0,0xA5,0x39,0x6D,0x06,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xCD,0x18,0x53,0x92,0x1A,0x9A,0xF6,0x28,0xD7,0x68,0x4C,0xFA,0x18,0xF8,0x59,0xAD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x62,0xDD,0xD8,0xF7,0x66,0x0E,0x7A,0xBF,0x3B,0x0A,0x11,0x5E,0x79,0x63,0xCD,0x88,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xB2,0x5B,0x0C,0xD6,0x94,0x1D,0x0B,0x6D,0xBD,0xA5,0x7A,0xA2,0xBE,0x93,0x00,0x45,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0x0F,0x9C,0x58,0x14,0x83,0xBE,0xC7,0xCE,0xA3,0x01,0x90,0x83,0x0D,0xE3,0xA4,0xA4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x80,0x7D,0x63,0x76,0x53,0x5A,0x1D,0xA6,0x75,0x3C,0xE5,0x24,0xBE,0x52,0xF0,0x04,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0x4C,0x15,0x8A,0xCE,0xC6,0x10,0x14,0xDE,0x82,0xC7,0xFE,0x79,0x45,0x54,0x2F,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0xB2,0x49,0x43,0x39,0x6C,0x54,0xDB,0x0C,0x2B,0x51,0xC9,0x30,0x5F,0x89,0xB8,0xE0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0x9A,0xA3,0x08,0x80,0xE5,0xDF,0x26,0x89,0x52,0xA4,0xE8,0xD4,0x06,0xFA,0x2E,0xAB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x72,0xF6,0x90,0x9D,0x91,0x8A,0xDF,0x30,0x6A,0x15,0xE8,0x6A,0x7E,0xEE,0x4A,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0xC8,0x94,0xC6,0x74,0x64,0x9F,0x96,0x58,0x50,0x34,0x29,0x90,0x28,0x1D,0x21,0x51,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x21,0x73,0x35,0xF6,0x77,0xFB,0xA2,0xF2,0xF9,0x3C,0xB6,0x3F,0x22,0xBF,0xEE,0x75,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0x83,0x4E,0xFA,0x9E,0xFA,0x96,0x8F,0xF8,0x64,0xB6,0x23,0xFD,0xFE,0x86,0xB2,0xC1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0x72,0x7D,0xF9,0x2A,0xD0,0xA7,0x7D,0xDD,0x2A,0xD9,0xEB,0xDA,0x91,0xEA,0x6F,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x27,0xCA,0x7A,0xCA,0xBD,0xEF,0xBA,0xC9,0xBE,0x60,0x51,0x15,0x85,0x23,0xD3,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0xE1,0xF7,0x04,0xD9,0xE7,0xF1,0x5F,0xEE,0x71,0xE9,0x3C,0xB1,0xEB,0xFF,0x5A,0xD4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5E,0x75,0x87,0x81,0x6F,0xCF,0x23,0x2A,0xDD,0x95,0x07,0x96,0x29,0x2A,0x2E,0x4C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x9F,0x21,0x11,0xE3,0x42,0x59,0x69,0xBD,0x3F,0xB5,0x0B,0xE6,0x7E,0xF9,0x6F,0x71,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0x8F,0x71,0xAF,0x41,0x74,0x3E,0x38,0x95,0x29,0x8D,0x93,0x86,0x79,0x94,0x06,0x3F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0x08,0xD4,0xF8,0x1C,0xC0,0x31,0x91,0xCF,0x06,0x9D,0xEF,0x55,0xA9,0x28,0x7D,0xB5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x76,0xF5,0x3E,0x5F,0x71,0x72,0x69,0x69,0xB4,0x90,0x96,0x58,0x66,0x9E,0x2A,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x65,0xEE,0x25,0x80,0xD2,0x12,0x5D,0xD4,0xA1,0x2C,0xF4,0xEA,0xB7,0x9F,0xBE,0x63,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0xC5,0x36,0x87,0xE1,0xC3,0x11,0xC1,0x62,0xD3,0xFB,0xC0,0x2E,0x9D,0x75,0x09,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0xEB,0x30,0xA0,0x51,0x47,0xD8,0xA0,0x5D,0xEE,0x1E,0x81,0x60,0x3E,0x7F,0xF0,0x37,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0xBB,0x05,0xBC,0x1D,0x47,0x7B,0x04,0xA9,0x41,0x76,0x5B,0xB3,0x7B,0x1E,0x04,0xC7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0xFF,0x57,0xD4,0xAA,0x71,0x3F,0x00,0xF1,0x7A,0xD0,0x26,0x63,0xC3,0x37,0xD4,0xA0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0xEA,0x3E,0x02,0x1F,0x8B,0x8C,0x0E,0x7E,0x12,0x17,0xBE,0xC6,0x8B,0x8B,0xC6,0xB6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x3F,0x79,0x43,0x67,0x04,0x05,0x1D,0xF9,0xB1,0x93,0xD9,0xF8,0x2C,0x11,0xE9,0xB9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xC9,0x46,0xB3,0xBA,0xFE,0x36,0x67,0xCD,0x0F,0xAD,0xC2,0x41,0x4A,0x27,0xD1,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x90,0xAF,0x41,0x1C,0x1B,0xC8,0x6D,0x3F,0x32,0xD6,0x5A,0x3A,0x03,0x9B,0x5F,0xB7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xD4,0xFB,0x9F,0x45,0xDE,0x04,0xA0,0x8F,0x5A,0xB5,0x49,0xA1,0x0E,0x0D,0x42,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xF2,0xEB,0xF7,0xC4,0x53,0xEF,0xD7,0x77,0xBD,0x9A,0xAA,0xDF,0x02,0x4F,0xC8,0x46,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0x19,0x1F,0x23,0x7F,0xE8,0xF9,0xAF,0x1B,0x7A,0x9A,0xC5,0xFB,0x37,0x38,0x40,0xDC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD8,0xFF,0xF1,0x94,0x9F,0x21,0xF5,0x12,0x37,0xF0,0x22,0x96,0x6D,0xEB,0x51,0x03,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0xAD,0xC1,0x37,0x50,0xDD,0xC2,0xD1,0xA0,0x13,0x26,0x8F,0xEB,0xBD,0x55,0xAB,0xFA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x33,0xF9,0x31,0xC3,0x79,0x56,0xA6,0x35,0x2D,0x61,0xD7,0xCD,0x69,0xCC,0xDA,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x09,0x49,0xAB,0x69,0x69,0x3E,0x6F,0x31,0xCA,0x4E,0x5E,0x73,0x86,0x0A,0x59,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x4B,0x34,0x6C,0xAC,0x88,0x85,0x6C,0x7D,0x24,0xA2,0xF5,0xFB,0x80,0xB8,0xB9,0x6E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0xC4,0x44,0x0A,0x24,0xA0,0xF5,0xBC,0x88,0xBA,0x31,0x38,0x01,0xD4,0xB7,0xD8,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x7A,0xB3,0x1F,0x64,0x13,0x8C,0xD3,0x0F,0x32,0xD6,0x48,0xE3,0xDB,0xE9,0x74,0x82,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xEA,0xBF,0x63,0x57,0x34,0x4C,0x59,0x9C,0x5A,0x16,0xF1,0x26,0x13,0xF6,0x82,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xDF,0xE2,0x13,0x14,0x0C,0xDF,0xCA,0x2B,0xD2,0xA5,0x3F,0x8C,0xD5,0x56,0x9D,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x0B,0xB4,0x55,0x63,0xBD,0x0C,0xB7,0x54,0xDA,0x5B,0xBA,0x66,0x69,0xD4,0xD0,0xDF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0xD2,0x0F,0xED,0x59,0x0B,0x7F,0x32,0x0E,0x3F,0xA8,0x7D,0xE6,0xA4,0x01,0xB7,0x7C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xBE,0x85,0x21,0xBA,0xBC,0xBA,0xE4,0x3A,0x93,0xBE,0x9A,0x8D,0x81,0x58,0x3B,0x75,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0x1F,0x73,0xC9,0xDE,0xE6,0x42,0xF2,0x31,0xBD,0x05,0x4B,0x64,0xAC,0x34,0xC4,0xA4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0xEE,0x4E,0x2B,0x15,0xBF,0x1F,0x7C,0xB9,0x5A,0x37,0x44,0xF7,0xE3,0x95,0x3C,0x62,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x8B,0x3E,0x67,0x34,0x9C,0x90,0x43,0x17,0x38,0x5C,0x41,0xAD,0x5C,0x83,0x82,0x7E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0xE7,0x2D,0x92,0x21,0x9E,0xBA,0x5D,0xED,0x3B,0x65,0x9B,0xDE,0x35,0x4E,0x31,0x66,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0x2C,0x8D,0x9B,0x32,0x06,0x78,0xC5,0x18,0x92,0xDE,0xFD,0xAD,0x16,0x1C,0x4C,0x60,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xA7,0x57,0xC2,0x23,0xE6,0x52,0x75,0x8D,0xCE,0x00,0x5A,0xEB,0x1B,0x24,0x43,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0xD5,0xA0,0xD2,0x61,0x74,0xA2,0x2C,0x73,0x1E,0xCB,0x49,0x7A,0x58,0xD6,0x2E,0x07,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0xBE,0x3C,0xBE,0x04,0xD8,0x92,0x0B,0xD3,0x02,0xC4,0x1F,0xBF,0x63,0x1B,0xB3,0xC7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0xE4,0x6C,0xC4,0x17,0xE0,0xC0,0x36,0x50,0x60,0x37,0x08,0xBB,0x3A,0xE9,0x5C,0x4B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x12,0x8F,0xA8,0xBE,0x6D,0xD7,0x81,0xD0,0xED,0x54,0x3F,0x36,0x41,0xC4,0x62,0xBC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0xA7,0xD3,0x0C,0x05,0xB0,0xD6,0x15,0x19,0x7F,0xE0,0x87,0x2A,0x8E,0x4F,0x59,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0xEE,0xC5,0x36,0xCA,0x3D,0xAC,0x9A,0xF3,0x1F,0xE6,0x6A,0x8A,0x4A,0xB9,0x1C,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x27,0x54,0x96,0x17,0x84,0x83,0x2A,0x87,0xD4,0x22,0x95,0x34,0xF8,0x86,0x7B,0x13,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0xD9,0xC0,0x6A,0x4D,0xD6,0x91,0xAB,0x77,0xD6,0xA4,0xEC,0xA7,0xCD,0xD1,0xA2,0xDD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0x3B,0x7E,0xA7,0xFD,0xEC,0x55,0x7B,0xE1,0x41,0xA5,0xB5,0x16,0x6F,0xF0,0x61,0x7F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x04,0x61,0x2C,0x07,0x54,0x31,0xDE,0x9E,0x0B,0xEB,0x44,0x2C,0x92,0xF0,0x62,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0x8F,0xD9,0x9A,0x0D,0xE7,0xFF,0x94,0xF3,0xFA,0x7E,0xF3,0x02,0xEA,0x7E,0x4F,0x97,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x1D,0x14,0x33,0xF5,0xAF,0x98,0x9F,0xBB,0x6E,0x0F,0xBF,0x6E,0x8E,0xE2,0x8C,0xAC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x83,0xDA,0xD2,0x10,0x3C,0x7E,0xE5,0xF7,0x7F,0x44,0x5C,0x4B,0xD9,0x83,0x88,0x49,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0x14,0x6F,0x60,0xA9,0x6E,0xC5,0xBF,0x62,0x3A,0xA4,0xDD,0x2C,0x62,0x0D,0x2C,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0x22,0x80,0xD5,0xA6,0xED,0xFC,0xBC,0xB5,0x6D,0xF3,0x82,0x9E,0xA9,0x1F,0xBE,0xEC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x77,0x84,0xCB,0x56,0x5F,0x15,0x66,0x96,0xC8,0x9E,0xC2,0x2F,0x68,0xF0,0xD3,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0xB8,0x26,0x2A,0x29,0x11,0xCB,0xC8,0xEA,0x81,0x55,0x1B,0x02,0x5D,0x50,0x78,0xB9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x29,0xA4,0x01,0xEB,0xC7,0xEE,0xD4,0xD0,0x5C,0xAC,0xFD,0x2B,0xBF,0xF8,0xF1,0xFC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0x7F,0xC8,0x8C,0x67,0x46,0x9F,0x74,0xEF,0xCF,0xB6,0x39,0x47,0x80,0xFD,0x96,0xCE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x88,0x22,0x9A,0xAC,0x8E,0x35,0x58,0x5F,0x6C,0x8B,0x93,0x69,0x4B,0xFE,0x22,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x8C,0x9D,0xDC,0xC3,0x88,0x1B,0xFD,0x6B,0x8F,0xA2,0x31,0x7A,0xE9,0xD4,0x7D,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0xC1,0xAB,0x73,0x0D,0xAF,0x46,0x50,0xF5,0x40,0x47,0x3F,0xF8,0x28,0x02,0xA3,0x58,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0x4A,0x3E,0x8E,0x24,0x9E,0x5B,0x4E,0x89,0x80,0x28,0x59,0xB8,0x93,0x8C,0xAC,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x7A,0x63,0xD3,0x4A,0x53,0x94,0x85,0x0A,0xBA,0x5F,0xE9,0x6F,0xDC,0x74,0x3E,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0xED,0xC7,0xE6,0x13,0xA4,0xCB,0x72,0xBB,0xDA,0x93,0x44,0x88,0xA0,0x4A,0x38,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0x0C,0x91,0x44,0x23,0x74,0x33,0xEE,0xD0,0x67,0xC6,0xE6,0x97,0xE0,0xEA,0x8C,0xC6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0x92,0xAC,0x94,0xA3,0xCD,0xDC,0x3B,0xC9,0xB8,0x45,0xA9,0x11,0x82,0x8A,0x82,0x90,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0xB4,0x33,0x74,0xFA,0x89,0x62,0x0C,0x8C,0x78,0x59,0x92,0x49,0x4A,0x2E,0xBE,0xAC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0x56,0x22,0x56,0xF8,0x6F,0x85,0x55,0xF0,0x08,0x04,0xAF,0xC2,0xFD,0x75,0x80,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0x77,0x9F,0x5D,0x50,0x05,0x96,0x13,0xF4,0xDE,0x3A,0x16,0x49,0xBB,0xC3,0x75,0xC0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0xAB,0x83,0xD2,0x29,0xCB,0x33,0x01,0x87,0xEE,0xFE,0xFE,0x6C,0x5B,0xAC,0x13,0xDD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0x9A,0x27,0x04,0x64,0x20,0xC9,0x93,0xDD,0x95,0xB5,0xE8,0x83,0x9F,0x27,0x4D,0x7B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x44,0xFD,0x44,0x9A,0x15,0xDC,0x19,0x53,0x77,0xD1,0x1B,0xE6,0xE9,0x70,0x56,0x38,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xA2,0xD1,0x95,0x6B,0x30,0x7D,0x3E,0xC8,0xEB,0xC2,0x35,0xE7,0x86,0x3C,0xDF,0xD1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x0A,0xAC,0x3B,0xC1,0xD8,0xB0,0x7F,0x5D,0x91,0xDB,0xB8,0x59,0xE2,0x54,0x27,0x27,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x1E,0x56,0x79,0xDC,0x7C,0xFA,0x0B,0x5A,0xBC,0x67,0x38,0xC1,0x6D,0xE6,0xE9,0x13,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x93,0x8B,0x05,0x65,0x6B,0x37,0x9A,0xB7,0x10,0xF3,0xA5,0xA5,0x05,0xBD,0x05,0xFE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0x7E,0x8C,0x99,0x4C,0x0D,0x06,0xD5,0x80,0x04,0x42,0x57,0x75,0xE6,0x09,0x6A,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x3C,0x7C,0x54,0x3A,0x8A,0xC3,0x7B,0xEE,0xAD,0xBE,0x50,0xD5,0x5A,0x25,0xC0,0x1B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xCB,0xCD,0x3F,0x70,0x3D,0x84,0x44,0x4E,0x72,0x12,0x61,0x5B,0xE0,0x7A,0x23,0xAE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8E,0x2B,0x90,0x39,0xD8,0xB8,0xC1,0xB9,0xBD,0x09,0x14,0x99,0x72,0xC4,0x83,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0x50,0x18,0x5F,0x5C,0xA1,0x28,0x96,0x18,0xAC,0x07,0xE7,0xC1,0x10,0x92,0x56,0xAD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x14,0x6D,0xFD,0xF7,0x68,0xEC,0x38,0x0E,0xB8,0xC1,0x4E,0xF9,0xA5,0x72,0x7A,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0x8E,0xC0,0x44,0x3F,0x1A,0x62,0xEF,0xB8,0x85,0x5D,0x71,0xA5,0x2A,0xBB,0xA0,0x9D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x39,0x19,0x8F,0x26,0xF6,0xA9,0x69,0xFD,0x6F,0x62,0x5E,0xD7,0x62,0x2F,0xE2,0x81,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x90,0x01,0xBF,0x8F,0x66,0x52,0x29,0x76,0xBF,0x22,0x69,0x7D,0xEA,0x20,0xD1,0x1D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x8A,0x33,0x60,0x02,0xBD,0x72,0xC1,0xCA,0xBD,0x32,0xA9,0x0A,0x9D,0xB5,0xA3,0x56,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0x9C,0x97,0x2B,0x2A,0x82,0x6C,0x7D,0xA3,0xBB,0xEF,0xB3,0xFD,0x40,0x84,0x99,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x3A,0x44,0x0A,0x49,0x2D,0xCC,0x55,0xD1,0xF8,0xFE,0xE2,0x22,0xB8,0x62,0x94,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x18,0x99,0x5A,0x07,0x8F,0x2F,0x2F,0xBE,0xC8,0x6C,0x72,0xDD,0x89,0x32,0x6E,0xF7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x5C,0x96,0xC1,0x0B,0xF7,0x46,0xD9,0x3D,0xF3,0x68,0x05,0x80,0x85,0x02,0xE6,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0xD3,0x5F,0xB5,0xA5,0x5E,0xC2,0x4C,0xD3,0x72,0xB0,0x5E,0x3D,0xC5,0xB1,0xAE,0x34,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0x23,0x27,0x88,0x97,0x04,0x84,0x42,0xA5,0x3B,0x40,0x58,0x0E,0xEB,0x14,0xAD,0xAA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x5A,0x39,0x49,0x07,0x9B,0x87,0x0E,0x73,0x7E,0x59,0x74,0x1F,0x07,0x4B,0xB7,0x79,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x68,0x0D,0x24,0x94,0xC5,0x2F,0xE3,0x73,0x2F,0x5D,0x85,0xD4,0x4E,0x25,0xDF,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0xE1,0xAC,0xB6,0x6C,0xDF,0x51,0xE6,0xB1,0xA2,0xB9,0xBC,0x23,0x79,0x60,0x5E,0xAC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x6F,0x2F,0xF6,0x7A,0x84,0xC4,0x8F,0xC6,0x24,0xC0,0xB0,0x2D,0xE4,0x2B,0x31,0x30,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x8A,0x09,0x28,0x49,0xF2,0x4A,0xEE,0x87,0xD2,0x3E,0x98,0x41,0x1C,0xBA,0x2D,0xDC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0xDF,0xE2,0x3F,0x16,0x42,0x50,0x82,0x90,0xD6,0x82,0x45,0x27,0x95,0x58,0xB2,0xFB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x26,0xD5,0x19,0x36,0xCD,0xC2,0x15,0x51,0xFE,0x53,0x4D,0xDD,0xE3,0x71,0xB3,0xE3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x7B,0x90,0xCC,0xE6,0x5F,0x1A,0x59,0x99,0x25,0x6A,0x81,0x4A,0xD4,0x31,0x57,0x4E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x55,0x89,0x7D,0x7D,0xEC,0xB2,0x77,0xCE,0x9C,0xD3,0xA9,0x18,0xD5,0xB3,0xED,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0x4B,0x48,0x0E,0x62,0xB6,0xD6,0xB3,0x66,0xBA,0xC4,0xBC,0x19,0x4D,0x33,0x49,0xC4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0x74,0x2A,0x9D,0x55,0xC1,0x60,0xA2,0xF6,0xE5,0xB4,0x9E,0xBC,0x78,0x1E,0x47,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x5F,0x42,0x3D,0xEC,0xB6,0x8D,0xCE,0xBB,0x35,0xE4,0xBF,0x01,0x8F,0x90,0x73,0xC3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0x70,0x00,0xD9,0x4D,0x44,0x39,0x65,0x0E,0x90,0x77,0xCD,0x70,0x60,0xA3,0xBC,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0x74,0x08,0xF3,0xEB,0x32,0x1D,0x1D,0x42,0xCC,0xA0,0x8B,0xEC,0xD4,0xD7,0x47,0x66,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xF9,0x44,0x58,0x4B,0xAF,0x58,0x74,0x64,0x8F,0xC1,0x26,0xC4,0x57,0x25,0x76,0x64,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x37,0x81,0x83,0x11,0xCB,0x6F,0x64,0x1A,0x22,0x10,0x44,0xFB,0xF3,0xD3,0xC6,0x06,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x1E,0x11,0x4C,0xD6,0xB5,0x48,0x4C,0x3B,0x41,0x36,0x31,0x43,0x58,0x21,0x5D,0xC8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0xDB,0x16,0xA0,0xD3,0x84,0xC7,0xBC,0xF4,0x97,0xAD,0x20,0xC0,0xF8,0x59,0xB7,0x5A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0xFF,0xB5,0xA0,0x5B,0x57,0x8C,0x80,0x2E,0x22,0x26,0x60,0x0A,0x33,0xA6,0x90,0x3F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x47,0x22,0x4F,0xE6,0x72,0x68,0x0D,0x1B,0xEB,0xE0,0x71,0xA3,0xDA,0x58,0xFB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0x8A,0x16,0xCF,0x75,0x21,0x6F,0x60,0xE4,0xCF,0x96,0x39,0x8D,0x66,0x5C,0x38,0x56,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0xE0,0x2D,0xCA,0xC2,0x2B,0x55,0xD7,0xB4,0xC7,0x3C,0x69,0xB9,0x89,0xDC,0x25,0xDF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xFF,0x6A,0x1B,0xFE,0x5A,0x61,0xD6,0xF7,0xE4,0x0A,0xFC,0xE4,0x4C,0xA5,0xE4,0xCB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0x72,0xE1,0x98,0x6D,0xF8,0x40,0x8B,0x2D,0x63,0x42,0xED,0xCB,0xE8,0x0B,0xC7,0x77,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0xDB,0xC9,0x8E,0x82,0x08,0x84,0x90,0xD6,0x97,0x9B,0x2F,0xD6,0xEA,0xC0,0x62,0xB5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x42,0xA8,0x6A,0xDD,0x55,0x41,0x82,0xC4,0x0F,0xC6,0x79,0x4B,0x54,0x9F,0x5A,0xFF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x2D,0x36,0xC2,0x7C,0x9C,0xEB,0xB1,0x2D,0x54,0xA4,0xB8,0x58,0xEB,0x6A,0xDF,0x18,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0x2D,0x02,0xCA,0xF0,0x0C,0xB9,0x28,0xF2,0xB3,0x85,0xBC,0x00,0x1E,0x07,0xC2,0x46,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0x6F,0x4A,0x50,0xB1,0x66,0x6A,0x8B,0x4B,0x0D,0x67,0x4E,0x4E,0x4B,0x9C,0xAE,0xD5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x0B,0x2C,0x9B,0x70,0x83,0x72,0x39,0xB8,0xB0,0x80,0x6C,0x79,0xBD,0x2F,0xFE,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0xD8,0x06,0x7C,0x44,0xCB,0xB9,0x8C,0x33,0xB6,0x42,0xD7,0xFF,0x0D,0xF9,0x49,0xEC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xCC,0x45,0xFA,0x13,0x6F,0x72,0xC7,0xC7,0x61,0x69,0xCA,0xCE,0x60,0x57,0x61,0x8B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x81,0xE5,0x4A,0x2B,0xF4,0x07,0x50,0x29,0xCA,0xC6,0x4B,0x78,0x7B,0x20,0x8C,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0x4B,0xC0,0x66,0x6E,0x43,0xFF,0x77,0x21,0xB4,0x8E,0x69,0x1A,0x72,0xB0,0xCF,0xB7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x16,0x2D,0x7C,0x02,0x3F,0x97,0xF3,0xF6,0x34,0x74,0x7A,0xCF,0x92,0x17,0x86,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x2D,0x54,0xC7,0x85,0x77,0x2F,0x0B,0x17,0x02,0xBC,0x7B,0x5F,0xB8,0x19,0x99,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x29,0xCD,0xD0,0x15,0x25,0x71,0x00,0xD9,0x00,0xAC,0x5D,0x2F,0xDF,0x96,0x97,0x6F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x07,0xC8,0xA3,0xC9,0xA8,0x51,0xD4,0x20,0x6D,0x3D,0x62,0xC4,0xA5,0xB3,0x2A,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x96,0x5A,0xF8,0xEA,0x7D,0xDE,0xBC,0x25,0xB1,0xB4,0xAD,0xC7,0xFD,0x70,0x7A,0xF2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0x50,0x7F,0xC7,0xDF,0x7C,0xD9,0xD3,0xB0,0xE3,0x28,0x74,0x78,0xC8,0x29,0x95,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0x8F,0x77,0xB5,0x15,0x39,0x01,0x18,0x72,0x8F,0xD5,0xC5,0x01,0x01,0xED,0xEA,0xB3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0xF6,0x6A,0xA7,0xBE,0x71,0x36,0x2F,0x90,0xCD,0xD2,0x95,0xFE,0x55,0x50,0x6E,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0x0A,0x37,0x40,0x02,0x15,0x1C,0x0F,0xFE,0x07,0x53,0x3D,0x41,0x49,0xC5,0x7B,0xBA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0xC2,0x57,0x54,0xA3,0xF4,0x2B,0x81,0x4A,0x6E,0x8C,0x3D,0x2E,0xC5,0x23,0x00,0xDD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0x06,0xCF,0x95,0x75,0x50,0xC6,0xBE,0x9E,0x15,0x6E,0x92,0x33,0x96,0x4E,0xF0,0xDA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0x1D,0x83,0xAC,0xB9,0x59,0x1E,0x69,0x2D,0xFC,0x05,0xF4,0xFD,0x7E,0x0F,0xF8,0x07,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x44,0x53,0x30,0xAC,0x19,0x2B,0x85,0x4D,0x75,0x48,0x73,0x03,0x24,0x24,0xF0,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x20,0xE1,0xA6,0x93,0x7E,0x4A,0xE2,0x4C,0x72,0x78,0x84,0x1B,0xA1,0x66,0x7F,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0x81,0x3B,0xAF,0xB3,0xF5,0x4C,0xD0,0x3F,0xCF,0x17,0x49,0x95,0x6D,0xA4,0x7B,0x98,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x5F,0x7C,0x7A,0xD2,0x48,0x99,0x04,0xCE,0xB4,0x54,0x91,0x0F,0xE3,0xE5,0xFB,0x07,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0x7A,0x8F,0xE5,0xBA,0xF9,0x35,0xB8,0x2D,0x72,0x6B,0x4B,0xF0,0xA6,0x0A,0x49,0xC3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0xF7,0xEC,0x30,0x65,0xE9,0x48,0x98,0x94,0x64,0xDF,0xAB,0x98,0x22,0xE6,0x3B,0xBB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x97,0xF0,0x5C,0xCA,0x5C,0xBE,0xB4,0x1A,0x7D,0xAD,0x59,0x32,0x38,0x3C,0x68,0xC0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0xA3,0xC3,0xD7,0xFB,0xE2,0xDF,0xDF,0x02,0x1E,0xDC,0x29,0x32,0x17,0xD9,0xEA,0xCC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x85,0x45,0x69,0xE0,0x7E,0xD9,0x78,0xE4,0xC4,0x50,0x80,0x38,0xF4,0x94,0x7F,0x58,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xEB,0x09,0x59,0x3B,0x01,0xD7,0x2A,0xA6,0x09,0x11,0x9B,0x9B,0x40,0x0F,0xF3,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xF1,0x04,0x78,0x73,0x79,0x1F,0x7E,0x79,0x63,0x33,0x4D,0xEA,0x1E,0x4F,0xE7,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x3C,0xFB,0x44,0xE9,0xFC,0xBD,0x1B,0x2F,0x62,0xBD,0x10,0x0E,0xCA,0xB6,0xA3,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x49,0xCD,0xE4,0xE3,0x61,0x0F,0xD8,0x39,0x68,0xF8,0xFB,0x11,0x7C,0x1F,0x3D,0x46,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0x82,0xD8,0xE2,0x97,0x86,0x6D,0xEE,0x2D,0x56,0x7F,0xDF,0xE3,0x46,0x2A,0x4B,0xD2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x36,0xF6,0x75,0xC2,0xB0,0x11,0x7F,0x74,0x49,0x76,0x12,0xF5,0x2B,0xC0,0x48,0x55,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x92,0x0D,0xF7,0x58,0x99,0xFE,0x2E,0xB8,0xC8,0x37,0xCA,0xAF,0x9E,0x08,0xF0,0xC0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x19,0x6D,0x47,0x2A,0xD2,0x71,0xCD,0xB6,0xFD,0x2E,0x87,0x96,0x5C,0x34,0x99,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xB0,0x6D,0xC7,0xD1,0x9A,0x24,0xB3,0x3C,0x5D,0x56,0xA0,0xB7,0x36,0x70,0xBF,0xA8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x3F,0xAF,0x24,0xB3,0x33,0x14,0x7C,0xE4,0x74,0x10,0x8A,0x59,0x55,0x3F,0x75,0xBC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0x8F,0xAC,0x58,0x53,0xCC,0xF4,0x0F,0xAA,0x49,0x08,0xA7,0xDA,0xB8,0x21,0xE3,0x34,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xE0,0x6A,0xAA,0xB4,0x37,0x4D,0xC5,0xC0,0xA8,0x95,0x1B,0xFC,0x6C,0x1B,0x78,0x3B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0x1B,0x8B,0x17,0xD1,0xAC,0xF5,0x97,0x52,0xEB,0x99,0x0B,0xA3,0x05,0xD3,0x6B,0xFF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0x82,0x29,0x38,0x58,0x52,0xEA,0xCF,0x41,0x09,0x25,0x51,0xD4,0x24,0x03,0x93,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x8F,0xC7,0x3B,0xBD,0xF0,0x60,0xA2,0x2F,0xDE,0x44,0x2D,0xF9,0xE7,0x2C,0x68,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0xBD,0xA9,0xAB,0x85,0x4B,0xE7,0x62,0x61,0x22,0x2C,0x43,0x62,0xB9,0x2E,0xB2,0x7D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xAC,0xB5,0x7E,0xD8,0x7F,0x6F,0x6D,0x74,0x2B,0xCE,0xD4,0x9B,0xD8,0x73,0xA5,0x2C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0x40,0x6A,0x93,0xA9,0x60,0x97,0xCB,0x9D,0x1A,0xFC,0x9D,0xC6,0xC0,0x54,0x8B,0x50,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x69,0x3F,0x18,0xF6,0xB5,0x22,0x5F,0xBA,0x75,0xB7,0xAB,0x28,0x11,0x04,0x60,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x69,0x07,0x54,0x28,0xCC,0x31,0xD8,0xB1,0xD1,0x8C,0xF1,0xB4,0x92,0x59,0xEC,0x14,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x7B,0x53,0x1F,0x2C,0x1D,0x78,0x9B,0x55,0x14,0xF3,0x4D,0x30,0x16,0xA7,0x19,0x2E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x28,0x47,0x79,0xBE,0xBC,0x2A,0x76,0xF3,0x78,0x14,0x32,0x5E,0xA0,0x0F,0x8C,0x6C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x9C,0x14,0x01,0x24,0xDB,0x7C,0xFC,0xA5,0x93,0x60,0x64,0xA8,0xE7,0x03,0xB1,0x21,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0x1D,0xED,0x5F,0xEB,0xD1,0x1E,0x53,0x70,0x56,0x76,0xD6,0x25,0xF7,0x99,0x15,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0xCE,0x02,0x92,0xC9,0x1F,0x3F,0x8A,0xD3,0xEC,0xE1,0x9F,0xE8,0xB2,0xF5,0xD2,0x85,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xEF,0x1B,0xA8,0x14,0x05,0x8E,0xA3,0xF6,0x6C,0xC6,0x32,0x9D,0x7B,0x7C,0xAF,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0xEF,0x3B,0x59,0x3E,0x31,0x12,0xC1,0x49,0x64,0xBE,0xE3,0xEE,0x8B,0x4C,0xFF,0x44,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x1C,0x6F,0x2C,0x8E,0xD1,0x79,0xD4,0x88,0x3C,0x78,0xAC,0x71,0x4E,0x87,0x0E,0x9B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0x96,0xE5,0xCF,0x25,0xD7,0x4D,0xAF,0x28,0x36,0x87,0x45,0x2C,0x3D,0xEF,0x91,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0xB1,0x9A,0x8A,0x50,0x91,0x2C,0xC6,0x59,0xF8,0xE0,0x21,0x45,0x78,0x62,0x1F,0x7F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xC3,0x3B,0x46,0x33,0x67,0xB4,0xD9,0x0E,0x2D,0x69,0x40,0x83,0xF4,0x94,0x17,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x52,0x71,0x40,0x1B,0x74,0xEA,0xA8,0xA1,0xD4,0x69,0x1E,0xC0,0xAB,0x10,0x71,0xC9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xD1,0xCD,0xA5,0x80,0x61,0x04,0x91,0x6C,0xFE,0x86,0x8F,0xF2,0x9A,0x27,0xB6,0xEF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x65,0x8F,0xCB,0x1F,0xC0,0xEF,0x7B,0x33,0x33,0x14,0x6F,0xB1,0xD2,0x10,0x32,0x02,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0x7B,0x1F,0x59,0x8A,0xE2,0xF9,0xC3,0xF9,0x80,0x07,0x09,0xE8,0xA2,0xD4,0x70,0xE6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xF1,0xF7,0x87,0x7F,0x1F,0x84,0x83,0x32,0x27,0xE9,0xC6,0x88,0xE8,0xA6,0x7E,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xFE,0x61,0x43,0x28,0xE2,0xFC,0x5B,0x62,0xFF,0x33,0x37,0x39,0x54,0x7D,0xD6,0x45,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xE3,0x8C,0xCE,0x38,0x6E,0xE9,0xDB,0x7C,0x3B,0xEA,0xB8,0x1E,0x86,0xDB,0x39,0xA3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0xBB,0xB7,0xF4,0xE9,0x02,0x9A,0x6B,0x2C,0x60,0x20,0x45,0xAA,0x5D,0xB9,0x23,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xDD,0xE1,0x32,0x42,0x89,0x67,0xE3,0xA9,0x06,0xFA,0x24,0x74,0x14,0x33,0x6E,0x70,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0x98,0x19,0xAA,0xEC,0x41,0xE1,0xCB,0x7A,0xAF,0xA0,0x62,0x3E,0xCF,0x34,0xC1,0xDD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0x8D,0x9F,0x94,0xE5,0xB0,0x67,0xD3,0xF4,0x76,0xE3,0x08,0x28,0x49,0xC2,0x57,0xD4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xB0,0xA1,0x5E,0x10,0x01,0x16,0x93,0x58,0x05,0x03,0x15,0x68,0x00,0xC7,0x75,0x02,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xDA,0xA5,0xCA,0x9A,0x42,0x17,0x1B,0x81,0x97,0xE0,0xCA,0x0F,0x31,0xEA,0x2B,0x49,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0x00,0x22,0x08,0xB3,0xB5,0x54,0x83,0x53,0x14,0x83,0x01,0xB2,0x4D,0x66,0xEB,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x23,0x38,0xCE,0xFE,0x9D,0x83,0x59,0x82,0xAE,0x6A,0x9E,0x01,0xCC,0x95,0x7A,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0x0D,0xDA,0x7C,0xBB,0xE1,0x95,0xA5,0x61,0x2F,0x0D,0x57,0x12,0x74,0x46,0x7E,0xF4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0xB1,0x2E,0xA7,0x2D,0xC2,0x00,0x4C,0xC2,0x70,0x8A,0x0D,0x03,0xD7,0xF1,0x99,0xFD,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0xEE,0x25,0x94,0x5C,0xDD,0x21,0xCA,0xB1,0x5B,0x40,0x98,0x02,0xF5,0xD5,0x25,0x0A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x88,0xCD,0x8B,0x8F,0xB3,0xB9,0x29,0xD0,0x4F,0x36,0x21,0xAE,0x6D,0x98,0xF3,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x67,0x7F,0x63,0x96,0x3A,0x08,0x8B,0x82,0x3F,0xF5,0x48,0x29,0x43,0xB7,0xF1,0x7E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0xA5,0x22,0x89,0x91,0xCB,0xEF,0x2D,0x8D,0xE9,0x31,0x41,0x36,0x84,0x56,0x0D,0x26,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD5,0x05,0xA3,0x31,0xCB,0xC6,0xA6,0xF8,0x62,0x2C,0x25,0x31,0xC9,0xB3,0x79,0x59,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF0,0x68,0x0F,0xB8,0x7C,0x43,0xC3,0x70,0xEC,0x16,0xA7,0x4D,0x2C,0xC7,0x66,0x03,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x24,0x40,0x4B,0x23,0x17,0x8E,0xC3,0x5F,0x9F,0x22,0xED,0x8E,0x2D,0xFD,0x69,0x26,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x6B,0xAA,0x60,0x96,0xAC,0xF8,0xE3,0x43,0x0B,0x04,0xD9,0xD2,0xD4,0x1E,0x98,0x59,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0x98,0xFE,0xF4,0x32,0xB1,0xAB,0xEA,0xB1,0xC1,0x64,0xD5,0x39,0x95,0x9C,0xD7,0xF1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x7F,0xB4,0x94,0x15,0x2A,0x70,0xDA,0x6F,0x25,0x0D,0xA1,0x32,0x84,0x9F,0x56,0xB7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xDD,0x8F,0x60,0x15,0xC2,0xD9,0x10,0xDD,0x62,0x73,0x2B,0x07,0xF0,0x82,0x90,0x26,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0xB3,0xD9,0xA0,0xAB,0x95,0xD8,0xE4,0x08,0x5C,0x36,0x15,0xA1,0xEF,0xB7,0xEA,0x95,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0x93,0xB3,0x58,0xC7,0x09,0x24,0xF3,0x50,0x7A,0x7D,0x33,0xF1,0x57,0x3C,0x10,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0x09,0x27,0x40,0xCF,0xA0,0x57,0x9A,0x30,0x61,0xFF,0xB0,0x29,0x63,0x6C,0x83,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x98,0xA9,0x45,0x26,0x58,0x2A,0x95,0xFE,0x93,0x3E,0xB9,0xDE,0xBA,0x51,0x65,0x43,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x08,0xB9,0x9D,0x15,0x1E,0xFE,0x14,0x08,0x04,0x2A,0xE1,0x1D,0xCE,0xCD,0x8B,0xDA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0x5D,0x02,0xE2,0x14,0xB0,0x20,0xB2,0xD1,0x79,0x3F,0x40,0x66,0xA9,0xB2,0x20,0x22,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x76,0x4B,0x09,0xA2,0xC0,0x7F,0x65,0x13,0x40,0x03,0x1B,0x12,0x8C,0x82,0x17,0x5E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0x50,0x0E,0x88,0xF3,0xF6,0xEF,0x86,0x7D,0xE9,0xEB,0x4E,0x14,0xC1,0x1B,0x46,0xD7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0xEC,0xBA,0x72,0xAE,0x87,0x21,0xEB,0x52,0xD4,0xA9,0x98,0xF8,0x5C,0x12,0x48,0x8D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0xF8,0xDD,0x90,0xFF,0xC2,0x46,0xA3,0x20,0x22,0x9F,0x81,0x4C,0x5C,0x50,0x04,0x8F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0xA1,0xA8,0xC6,0x5B,0x9D,0x16,0xE1,0xE0,0xE3,0x5F,0xA2,0xB4,0xE1,0x05,0xA9,0x18,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x1B,0x53,0xA0,0x37,0x63,0xEA,0x15,0x2F,0xAE,0x1F,0xF0,0xF9,0xFF,0xB4,0xEB,0x2A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0x28,0x68,0x21,0x75,0x6E,0x71,0xD0,0xB9,0x4F,0x41,0xDB,0xEF,0xA4,0x86,0x8C,0xFA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x0D,0x05,0xA1,0xA3,0xCC,0x7E,0xCF,0xE9,0xBE,0xF6,0xE1,0xE4,0x03,0x78,0x1C,0xD6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0x65,0xD2,0x82,0x3F,0xFB,0xED,0xA1,0x04,0x20,0x7D,0x29,0xA0,0x12,0x52,0xF2,0xB6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0x82,0x7C,0x29,0x9D,0xE8,0xEF,0x01,0xF1,0xB9,0x41,0x5C,0x0E,0x7C,0xBB,0x28,0xF5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0xE5,0x9E,0x95,0x46,0x6B,0x69,0x35,0x6D,0xA1,0x53,0x9B,0xCA,0xF6,0xC4,0x8E,0x74,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0xEE,0x88,0x59,0x53,0x58,0x13,0xD2,0x77,0xC5,0x5D,0xB0,0x40,0x5D,0xF8,0x38,0x8C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x56,0x9F,0xA1,0xBE,0x3B,0x5E,0x69,0x6F,0x09,0x38,0x2A,0xD1,0x4C,0xEB,0x3A,0x72,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xD6,0xB2,0x2A,0x70,0xD1,0xFB,0x13,0x14,0xCC,0x4B,0xE0,0x7E,0x78,0x00,0x0D,0xBB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x53,0xD0,0xDD,0x65,0xEA,0x0F,0x91,0x8C,0x31,0xD9,0x0E,0x06,0x57,0x4B,0x7E,0xA2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0x3E,0x92,0x55,0xAC,0x2E,0xD6,0xEE,0x3F,0x80,0xD3,0x13,0x4F,0x17,0xFC,0xE9,0x7F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0xC0,0x3D,0x1C,0x1A,0x1E,0x3D,0x1F,0xBC,0x34,0xC1,0x03,0xDF,0x48,0x0C,0x17,0xE2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x83,0x8B,0x1C,0x0B,0xC7,0x97,0xC9,0x2E,0x1C,0x91,0x07,0x87,0x56,0xBF,0x35,0xE6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x27,0x4F,0x2C,0x5B,0x15,0x12,0xEC,0x28,0x31,0xEA,0xA6,0xDF,0x2B,0x09,0x19,0xC7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0x4C,0x70,0x16,0x8F,0x45,0xCE,0x7D,0x8F,0x33,0xB7,0xEE,0xB7,0xBC,0x62,0xCD,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x8C,0x80,0x9B,0xD4,0x9F,0x81,0x22,0x6F,0xB6,0x7F,0xE5,0xEB,0x8D,0xB0,0x50,0x82,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0xC3,0xAC,0xD7,0x15,0xC4,0x41,0x41,0x70,0x0A,0xD3,0xB3,0x47,0x6B,0x2A,0x14,0x00,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x18,0x1E,0xDE,0xF2,0x44,0xAC,0x07,0x6B,0x29,0x43,0x73,0x30,0x32,0x18,0xE6,0x41,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x5E,0x62,0xAA,0xA7,0x4C,0xF8,0x39,0x44,0x7E,0xEA,0x92,0x0D,0xD1,0x81,0x1D,0x79,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x75,0x60,0x73,0x67,0xC9,0xD2,0xEC,0xF5,0xD8,0xF7,0xA3,0x8B,0xFC,0x4D,0x72,0x58,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0xC0,0xE9,0xA9,0x21,0x85,0x84,0x69,0x26,0xAF,0x59,0x79,0x07,0x13,0x1A,0x65,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x21,0x17,0x0C,0x0D,0x6A,0x0D,0x1F,0xAA,0x17,0x68,0x2C,0x26,0x00,0xA3,0x1A,0x98,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0xA0,0xC0,0x24,0x93,0xBD,0x94,0x2C,0x0A,0xFC,0x6E,0x28,0xA6,0x66,0x47,0xDE,0x92,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x25,0xD1,0x89,0xA5,0x3C,0x7A,0x7A,0x56,0x3F,0xE8,0x79,0xC4,0x14,0x5D,0x86,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0xD2,0x3E,0x04,0xF3,0x82,0x99,0x53,0x29,0x19,0x11,0x5A,0x04,0xE4,0x44,0xED,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0xC3,0x63,0x43,0xBF,0x89,0x55,0x14,0xDA,0xAC,0x87,0xBC,0xC5,0xEC,0x00,0x80,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x71,0x25,0x50,0x9B,0x8C,0xD8,0xEB,0x1C,0xF3,0x34,0xFD,0xE0,0xF0,0x5F,0x6C,0x47,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xAF,0xE2,0xA1,0x05,0x05,0x2A,0xAD,0xE8,0xE7,0x92,0xC2,0xCB,0xFE,0x94,0x77,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0x89,0x6E,0x57,0xF9,0xD8,0x42,0x61,0xA0,0x8A,0xA1,0x5B,0x41,0x2A,0x62,0x66,0x5B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0xB6,0x09,0x9B,0xC0,0x76,0xEF,0xFF,0x0F,0x43,0x1B,0xE8,0x14,0x9A,0x21,0x8B,0x2E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x3F,0x83,0xE1,0x85,0x8E,0xC9,0x25,0x6F,0xAD,0x44,0x5C,0x5E,0xAB,0x83,0x18,0x38,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0x8C,0x0C,0x1A,0xDC,0x5A,0xB5,0x86,0xF7,0xEB,0xE2,0x21,0xF2,0x87,0xFF,0xA0,0xBE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0x84,0x0F,0x72,0x74,0x50,0xB6,0x0C,0xD8,0x9B,0x86,0x77,0x18,0x28,0x9B,0x94,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0xD9,0xE2,0x71,0xBD,0x1C,0x5C,0x9B,0x7B,0x97,0x09,0x45,0xE0,0xB0,0x98,0xA7,0x0B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0x7B,0xE4,0x9C,0xFA,0x2D,0x6B,0xCC,0x7B,0x0E,0x90,0x67,0x54,0x5C,0x3D,0x96,0xF6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0xCB,0x7B,0x05,0x5B,0x59,0xF3,0x5D,0xFD,0x9F,0xFD,0x9B,0x21,0xEE,0xDF,0x3B,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x60,0x68,0x8D,0xC7,0xC4,0x86,0x35,0x58,0x4F,0x10,0x25,0x11,0x34,0x51,0x1C,0xC1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0x5D,0x85,0xA4,0xF3,0xBC,0x74,0xA8,0xFC,0x41,0x2A,0xA0,0x52,0x7F,0x46,0xC6,0x79,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xC5,0xFB,0x20,0x74,0x12,0x39,0xFA,0x0E,0xB1,0xC7,0xF7,0x3D,0xC5,0x24,0x90,0x39,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x63,0xDE,0x2E,0x20,0x03,0x6D,0x60,0xF5,0xB3,0x5C,0xEA,0xB1,0x19,0x4D,0x9B,0x89,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xF9,0xE4,0xC3,0xAD,0x5A,0xCF,0x5B,0x35,0x97,0x75,0x17,0x53,0xF9,0xAF,0x7F,0x7A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xDF,0x52,0x6F,0xEF,0x46,0x60,0xBE,0x4A,0xCC,0xE2,0x10,0xC5,0x8F,0xC7,0xE5,0x6C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x84,0x3A,0x08,0xB5,0x55,0x7A,0xDE,0x17,0x50,0xCB,0x89,0xC7,0xE9,0x9A,0x40,0x64,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7A,0x5E,0x45,0x48,0xB8,0x4F,0xF8,0x43,0xA8,0xCE,0x94,0xF3,0x5E,0x6E,0x54,0x54,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0xDF,0xD0,0x9D,0x5A,0xE8,0x3C,0xBA,0xB1,0x4B,0x3D,0xF4,0xE8,0x27,0x2F,0xAD,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0xE9,0x41,0x73,0x88,0xBF,0x86,0xBF,0x74,0xC8,0xE8,0x8F,0x20,0xE2,0x1B,0xDD,0x08,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0x07,0xFD,0x43,0x48,0x3D,0x6E,0xBF,0x93,0xC0,0x91,0x68,0x04,0x40,0x49,0x9A,0xBC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x7E,0xE0,0xBB,0x85,0x06,0x85,0x1C,0xF4,0xD5,0x2E,0x21,0x29,0x2D,0x72,0xD3,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1C,0x11,0x70,0x29,0x83,0xA6,0x24,0x4C,0x4A,0xD3,0xBC,0xB1,0x80,0xDD,0xB1,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x28,0x97,0x3A,0x3A,0x5C,0x10,0xA1,0x38,0xCD,0xF6,0x5D,0x44,0x43,0xFC,0x2A,0x42,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0xF9,0x5B,0x52,0xCC,0xE4,0x1A,0x37,0x58,0xA5,0x76,0x00,0x26,0x22,0x78,0x7B,0x69,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0x08,0x3D,0x1F,0xB3,0x8F,0xE2,0x26,0x29,0x33,0x1E,0xA6,0xB9,0x88,0xE1,0xF9,0xD0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0x40,0x29,0x48,0xF6,0x0D,0x6A,0x20,0x3C,0xF7,0x78,0x48,0xFC,0x0A,0x4F,0x70,0xF8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0xFB,0x61,0xD0,0x56,0xFA,0x44,0xD1,0xB5,0xCF,0xD3,0xBF,0x75,0x58,0xE4,0xC5,0xB0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0xDB,0xF2,0x50,0x1B,0xFC,0x7E,0x88,0xEA,0x82,0x7C,0x8E,0x57,0x26,0xBF,0x85,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0xE4,0x7C,0x0D,0xE3,0x7F,0x16,0x30,0x9C,0xE4,0x18,0xA7,0xA8,0xE5,0xD4,0x1A,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0xF0,0x4C,0x52,0xB8,0x20,0xE7,0x9F,0xEB,0x28,0x7B,0x34,0xF0,0x8B,0x5C,0x2A,0x82,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xE8,0x2D,0xB5,0x60,0x57,0x32,0xBE,0xF8,0x17,0x19,0xA9,0xDD,0x74,0xAA,0x34,0x21,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0xBA,0x2C,0x9A,0x23,0xB1,0x55,0xF2,0x1D,0x8A,0x2C,0xC7,0xA8,0x54,0xA5,0x2A,0x27,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x2F,0xC0,0xEF,0x80,0x9C,0x37,0xB8,0x59,0x58,0xA4,0xD1,0x3C,0x3F,0x0A,0xBC,0x89,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0xF4,0x79,0x58,0x92,0xC0,0x3A,0x0C,0x27,0x04,0x6F,0x91,0xD9,0x77,0xD6,0x77,0xB6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0xD1,0xE5,0x46,0xF1,0x63,0x7B,0x3B,0x40,0xA2,0x10,0xB3,0x46,0x0C,0xDE,0xE0,0xA3,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0xD5,0xAE,0xD0,0x1B,0xF3,0x80,0x29,0xB8,0xD8,0x54,0x3C,0xB5,0x8B,0x51,0xBF,0x08,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0x70,0x5B,0x4E,0xA1,0x64,0x40,0x55,0x7A,0x8F,0x5A,0x8E,0x93,0x0F,0x51,0xCE,0x6D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0xF7,0x31,0x2F,0x42,0x25,0x9C,0x83,0x80,0x32,0x82,0xFE,0xBE,0x16,0x8F,0xF7,0x69,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0xDD,0xC6,0x03,0x86,0x91,0xA4,0x81,0xB8,0xBA,0xA9,0x1D,0x0C,0xE1,0x04,0xD3,0x53,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0x79,0xCB,0x83,0xD8,0x37,0xA8,0x25,0x3A,0x2D,0x92,0x37,0xA9,0x18,0xA4,0xB0,0x21,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0xC5,0x87,0x3F,0xD7,0x7C,0x29,0xD3,0x0E,0xC3,0x36,0x3A,0x17,0xC7,0xD1,0x05,0x15,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x17,0xA1,0x84,0x71,0x1B,0x81,0x0A,0x82,0x1D,0xBD,0x71,0x04,0x55,0x67,0x8E,0x80,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0x97,0x8D,0xDF,0x95,0x64,0xB2,0xED,0x2F,0x36,0xA1,0xC9,0xD4,0x28,0xB5,0x59,0xE8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0xEB,0xBB,0x7D,0xC9,0x00,0x90,0xE1,0x36,0x2C,0xE2,0xAF,0x55,0xFF,0x83,0x50,0x33,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0x9A,0xCB,0x93,0x4D,0x5C,0xCB,0x08,0x5D,0xC4,0xCA,0x69,0x21,0x11,0xFF,0x3B,0x19,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x53,0xFA,0x9C,0xC8,0xE5,0x44,0xB5,0x22,0xD0,0x86,0xF2,0x94,0x2E,0x19,0x22,0xDB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0xD8,0x95,0x71,0x2E,0xDC,0x08,0x9D,0x09,0xD2,0xDC,0x4F,0xE8,0x2C,0x41,0xB3,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x50,0x90,0x05,0xA4,0xAC,0xF8,0x0A,0xA3,0x0D,0x48,0xCE,0xCD,0x3E,0xC6,0xC6,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0x42,0x48,0xD7,0xE7,0x4C,0x4A,0x70,0x63,0x29,0x18,0x68,0xA6,0xA6,0x8E,0x17,0x5C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0xE1,0x31,0x74,0x2A,0x91,0xD5,0x80,0x80,0xAF,0xC7,0x35,0xB9,0x4A,0x36,0x08,0x25,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0x6F,0x48,0x2D,0xFD,0x5B,0x19,0xA2,0x68,0x54,0xC8,0xD0,0xB1,0x85,0x71,0x27,0xCE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0xBF,0x22,0x6B,0x8F,0x0B,0xD7,0xCC,0x1F,0xE1,0x68,0xB1,0x08,0x1F,0x77,0x92,0xEA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x75,0x91,0xDD,0x94,0xF2,0xD0,0x97,0xC7,0x2E,0x70,0x11,0x7D,0x2E,0xCC,0x30,0xF1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0x3A,0x7A,0x30,0x33,0xF4,0x25,0x7A,0xB0,0x74,0x86,0xE1,0xC3,0x99,0xE4,0x1A,0xE7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0xEB,0x86,0x5A,0x6E,0xFF,0x03,0xF4,0x1C,0x89,0x68,0x9F,0x53,0x2A,0x2B,0xE3,0x7D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0xB3,0xCC,0x29,0xD5,0xC2,0x15,0x5F,0xBF,0x90,0x27,0x17,0x51,0xFE,0xCB,0x96,0x25,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0xF1,0x1D,0x12,0x2A,0x6F,0x6E,0x46,0x66,0x06,0xEB,0xB3,0x39,0xE0,0x91,0x53,0x7C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x5A,0x72,0xDD,0x53,0xB1,0xF4,0xE8,0xF3,0x1F,0x81,0x05,0x85,0x26,0x78,0x53,0xF4,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xC3,0x11,0xA5,0xA2,0x8A,0x55,0x2F,0x2C,0x9F,0xD4,0x1F,0xA1,0xED,0x1F,0x4A,0x35,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0x0F,0x4E,0x91,0x38,0x18,0x27,0xDF,0xB1,0x1F,0x5C,0x1B,0x15,0x6F,0xF9,0xA0,0xEB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x55,0xC0,0xD1,0x11,0x67,0xFB,0x8D,0xD4,0x5B,0x19,0x2D,0x0A,0x65,0x7D,0x90,0x52,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0xE4,0x3F,0x2F,0x4C,0xE7,0xE4,0x2F,0xB9,0x9E,0x19,0xA9,0x65,0x1E,0x86,0x32,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x0D,0xC2,0x6C,0x4D,0x0C,0xFE,0x7F,0xC3,0xD7,0xD5,0xEB,0xD4,0x82,0x9A,0x05,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x4F,0x42,0x0F,0xBD,0xF1,0xD2,0xC3,0x9F,0x1D,0xED,0xDC,0x29,0x3F,0x95,0x55,0x20,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x10,0xA3,0xE6,0x6D,0x54,0x49,0x25,0x93,0x3F,0x53,0xBA,0x78,0x95,0x86,0x9B,0xDE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x44,0x09,0x54,0xA1,0xC0,0x81,0x1D,0xEA,0x23,0x4B,0x82,0x53,0x45,0xBB,0x4A,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x24,0x48,0x1A,0x7D,0x65,0x02,0xA1,0xDF,0x80,0x6F,0xBB,0x63,0xB7,0x1D,0x70,0xB1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xA1,0xC7,0x42,0x4C,0x9C,0xEF,0x01,0x1F,0xB2,0xF5,0xC3,0x67,0xCD,0xB8,0x7E,0x9D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xDE,0x00,0xEE,0x08,0x66,0x26,0x8E,0xFF,0xB6,0xF5,0xA3,0x98,0x43,0xC8,0xC2,0x8F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0xB5,0x2A,0xC6,0xA2,0x1D,0x4A,0x2A,0x82,0x8B,0x73,0x1C,0x18,0x47,0xA5,0x3D,0x15,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xAF,0xF8,0x9C,0xD0,0x56,0x9B,0x12,0xD6,0xEE,0xB8,0x40,0x97,0x99,0xF2,0xAA,0x5B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0xC7,0x34,0xCF,0x5A,0xE9,0x62,0x4D,0xAB,0xBA,0x20,0x14,0xD2,0x2E,0xB3,0x96,0xE5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x65,0x96,0x24,0x5F,0x7A,0x88,0xC6,0x6D,0x1A,0x4A,0xE3,0x8D,0x13,0x1C,0xC6,0x8A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0x82,0xCD,0xB2,0xF6,0xE2,0xDC,0xAC,0xA8,0x78,0x7A,0x9F,0xF5,0x73,0xB2,0x85,0x5E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x5D,0xCF,0x7B,0x89,0xEF,0xA3,0x39,0x3F,0x9D,0x95,0x04,0x8C,0xB9,0x43,0x4D,0xD2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x78,0x2F,0x33,0x30,0xBB,0x30,0x7D,0xAB,0x4A,0x13,0x30,0xD2,0x81,0x62,0xCF,0xE0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x65,0x6B,0x91,0x41,0xFE,0x0B,0x59,0x14,0x11,0x83,0x1A,0xA5,0x95,0x51,0x80,0x40,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0xD2,0x5C,0x58,0xE0,0x25,0x3B,0x3B,0x7A,0x80,0x2D,0xD6,0x4B,0x09,0x99,0xD2,0x32,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x34,0xBC,0xEB,0x08,0x48,0x4D,0x23,0x2E,0x6C,0x30,0xFF,0xFE,0x23,0x32,0x8C,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0xA8,0x94,0x7A,0xC1,0x3C,0x2C,0x2E,0x08,0x4E,0xBF,0x94,0x00,0x93,0x71,0x1B,0x5B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0xB1,0x58,0x4F,0x12,0x8E,0xE1,0xAD,0x0B,0xD3,0xA8,0x30,0xF4,0xEC,0x51,0x6E,0x5E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x12,0x58,0x74,0x90,0xE3,0xD7,0x4F,0x98,0xC7,0x9B,0xA8,0x65,0x5F,0xC8,0x46,0x00,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xB3,0x86,0x3F,0xD1,0x0D,0x13,0x73,0xDC,0x67,0x78,0x6F,0x57,0x51,0x57,0xE3,0xC6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xEE,0x8F,0xB7,0xB6,0xCE,0x74,0xE0,0x5B,0x0B,0x5F,0xCA,0x0E,0x4C,0x50,0x60,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0xDF,0x3D,0x1A,0xCA,0x24,0x40,0xCE,0xCD,0x1D,0x4F,0xAD,0x4A,0xB5,0x91,0xA2,0x86,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x67,0x68,0x50,0xBA,0xE4,0x1E,0xB5,0x18,0x0B,0x5B,0x87,0xA5,0xA4,0x32,0x0A,0xF7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x68,0x5D,0x93,0x93,0x80,0x76,0xA5,0x59,0xEB,0xE4,0x6D,0x66,0x5F,0xC6,0x97,0x89,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0x65,0x56,0x1D,0xC1,0x83,0x3C,0x8E,0x83,0x04,0x56,0x95,0x09,0x9A,0xDB,0x79,0x91,0xD9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0x56,0xE2,0x67,0xF8,0x8D,0x48,0xAC,0x0E,0xB2,0x64,0x24,0xDE,0x35,0x86,0x9C,0xFC,0xF0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0x5D,0x81,0xEB,0x40,0x2B,0x7D,0xBB,0x01,0xED,0x1C,0xF8,0x70,0x59,0x18,0x05,0x1D,0x50,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x17,0xB4,0x61,0xF5,0x14,0x91,0xEF,0x87,0xA0,0x14,0xE3,0x13,0xEC,0xAB,0x7D,0x49,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xE5,0x2A,0xAB,0x9B,0x5C,0xFE,0x9E,0x12,0x1F,0x5E,0x09,0xAD,0xB8,0xBB,0x2C,0xAA,0xDC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0xFC,0x52,0x16,0x8C,0x33,0x9E,0x35,0x89,0x2D,0x17,0x1F,0xE5,0x04,0xFA,0x55,0x3B,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x02,0x71,0xE1,0x4D,0x68,0x1B,0x25,0x14,0x38,0xEB,0x10,0x9F,0x83,0xA8,0x98,0xEE,0xAE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0x09,0xFE,0x37,0x91,0xF9,0xA5,0xDD,0xB9,0xCD,0xEC,0x48,0xAE,0x17,0x0F,0x5D,0x3C,0x31,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x9C,0xAA,0xEC,0x40,0xEF,0x88,0x7F,0xA7,0xA3,0x55,0x03,0x09,0x8B,0x91,0xAD,0x8B,0x26,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x9A,0xD7,0x1F,0x86,0x21,0xB2,0x8A,0x92,0x3E,0x0E,0xEF,0xB3,0x1C,0x3A,0x23,0xAB,0xFF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x79,0xF9,0x2F,0x31,0xD7,0x1A,0xCA,0xF2,0xCC,0xD5,0xD2,0xEC,0x15,0x19,0x70,0x89,0x1F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x93,0xF9,0x52,0x98,0x8B,0x94,0x68,0xA5,0x6E,0xE2,0x90,0x5F,0x83,0x7B,0x72,0xBE,0xFB,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x54,0x63,0x58,0x0E,0x52,0x2E,0xF0,0x4C,0xC5,0x4A,0xA5,0xBE,0xCA,0x7B,0x4B,0x01,0xE6,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xD3,0x5D,0x7C,0xD3,0xFE,0x0E,0x78,0x7C,0x36,0xD9,0x82,0x92,0x74,0x8B,0x21,0xDB,0x71,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xA5,0xD3,0x06,0x95,0xB6,0xEB,0xC0,0x76,0x2A,0xA3,0x3A,0xDB,0x6F,0x75,0xCC,0xA7,0x8E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0x0A,0x92,0xF9,0x3C,0x61,0xC1,0xEA,0x08,0x70,0x02,0x4D,0x4D,0x66,0x4C,0x67,0x6A,0x3F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xC4,0x0C,0xBE,0x9F,0x9F,0x43,0xA8,0x4E,0x59,0xF3,0x4D,0x4B,0x32,0x36,0xFA,0x8F,0x44,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0x0A,0x16,0xEE,0x1B,0xE1,0x21,0xB9,0x2D,0x53,0x45,0x43,0x3D,0xAA,0xCD,0x46,0xEB,0xF7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x31,0x05,0x9C,0x90,0xD4,0x5F,0x22,0x26,0x5E,0x79,0x8D,0x0A,0xE9,0x23,0x2C,0x02,0xA7,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0xC6,0x3A,0x68,0xB2,0x97,0x79,0x82,0xAE,0x9A,0xF2,0x67,0x03,0x90,0xE9,0x19,0xD0,0xC5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x3F,0xD4,0xDF,0x9F,0xD2,0xBC,0x4F,0xEC,0xCD,0x03,0x24,0x64,0x0A,0x1F,0xBC,0xF4,0x27,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x75,0xA5,0x30,0x33,0x19,0xD0,0x1A,0xC1,0xD9,0x07,0x9A,0x81,0x55,0xBC,0x5F,0x35,0xA0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x15,0x60,0x89,0x2F,0x66,0x2D,0xD7,0x99,0xF1,0x01,0x8F,0xBD,0x99,0x20,0x3E,0x39,0xA5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0x69,0xB2,0x82,0xCE,0x17,0x6D,0xAB,0x5E,0x65,0x12,0x11,0x6E,0x32,0x61,0x31,0x75,0xE1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x1F,0x91,0xF0,0x6D,0x92,0xAF,0xDD,0x60,0x7E,0x93,0x8A,0x27,0x4A,0x90,0x52,0x97,0xD5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0x0E,0x3D,0xDB,0x48,0xFD,0x27,0x96,0x3A,0xAE,0x96,0x73,0xF2,0x35,0x3F,0xB5,0x77,0x0F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0x58,0x88,0xA4,0xF5,0x8D,0xA7,0x28,0x95,0x4A,0x1F,0x4B,0xD4,0x65,0xF6,0x38,0xE5,0xCA,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x19,0x93,0xF8,0x55,0x78,0x1E,0xCC,0xA9,0x74,0x72,0x34,0x45,0x69,0x39,0x9E,0xA9,0xB8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xD4,0xDC,0xDE,0xA6,0x73,0xEF,0x6E,0x22,0xE6,0x3B,0x15,0x3D,0x58,0xD0,0x3B,0xF6,0x2F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xB4,0x9F,0x62,0x3D,0xC9,0xDA,0xB9,0x54,0xF6,0x98,0xAD,0x25,0x76,0x95,0x61,0x8B,0x36,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0x88,0x78,0x06,0xC7,0x8B,0xE5,0xA7,0x33,0xF4,0x38,0x73,0xBC,0x02,0x51,0x27,0xED,0xE8,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x0E,0xC3,0x58,0x73,0xE1,0x7F,0xCB,0x61,0x78,0x4D,0x3C,0xED,0x77,0xCA,0xF2,0x90,0xCF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x13,0x64,0x11,0x78,0x93,0x7D,0xCC,0xC5,0x30,0xFF,0x10,0xCB,0xAF,0x7A,0x88,0x19,0x98,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0x65,0x2F,0x72,0x56,0x45,0x6F,0xA9,0x35,0xAC,0x34,0xBB,0xF5,0x5A,0xE1,0x35,0xF3,0x32,0x57,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0x56,0x4D,0x10,0x1E,0xD9,0xBB,0x4A,0xB9,0x2D,0x77,0xF4,0xD6,0x24,0x4A,0x54,0x8B,0xFA,0xC2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0x5D,0x42,0x0E,0xB6,0x7D,0xAF,0xF8,0x96,0x91,0x1B,0x9D,0xAB,0x71,0xA7,0xFA,0xE9,0x30,0xA0,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x17,0x67,0x42,0xF9,0x45,0x57,0xB0,0x75,0x2C,0x1B,0xEB,0xA3,0x4C,0x7E,0xCE,0xD0,0xB4,0x7D,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xE5,0xC7,0xB4,0x3A,0x96,0x79,0x25,0x87,0xBC,0x00,0x77,0x0D,0x86,0x7A,0xC7,0x36,0x35,0xEC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0xFC,0x96,0x80,0x2A,0x83,0x06,0xF3,0xE8,0xAD,0xA9,0x57,0x93,0x55,0x5E,0xAD,0x0D,0xFC,0xE9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x02,0x9E,0x1D,0x1F,0xF8,0xAB,0xC2,0x22,0xAD,0x01,0x4E,0xB3,0xC5,0xA4,0x7B,0x10,0x05,0x76,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0x09,0x14,0xFB,0x0A,0x41,0x70,0xEF,0xB0,0xA1,0x63,0xAB,0x71,0x96,0xEE,0x01,0xE6,0xAD,0xF9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x9C,0x44,0x01,0x9D,0x8C,0xD3,0xB1,0x4B,0xB0,0xDB,0x6B,0x91,0x89,0x30,0x5E,0xF0,0xB4,0xFC,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x9A,0xE6,0x20,0x71,0xB7,0xF4,0x9A,0x02,0xA3,0x06,0xF6,0x4C,0xA8,0xBA,0x12,0xF7,0x30,0x8B,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x79,0x25,0xEC,0xF6,0xA2,0xC0,0xF8,0xE3,0xF4,0xFE,0xE3,0x2D,0xEF,0x19,0xDF,0x78,0x97,0x10,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x93,0xDC,0xC0,0xA6,0x26,0x7B,0x57,0xD4,0x00,0x5D,0xC1,0x5F,0x42,0xE6,0xA5,0x51,0xE9,0xB5,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x54,0x47,0x9A,0x0D,0x6C,0xDB,0x44,0xCB,0xB2,0x25,0xDF,0xA5,0x93,0xD0,0x65,0x70,0x97,0x9F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xD3,0x2C,0x53,0xE2,0x81,0xBE,0xEA,0x0E,0xFA,0x05,0x2C,0xFC,0x9C,0xF2,0xE9,0xAE,0xBB,0xB2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xA5,0x30,0x16,0xB9,0xB2,0xA8,0x49,0xF8,0x34,0x62,0xF3,0xA8,0x3F,0x46,0x64,0x71,0x3E,0xA2,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0x0A,0x2C,0x27,0x68,0x06,0x46,0x7E,0x20,0x2F,0xC0,0xE1,0xB7,0x49,0x13,0x97,0x51,0xB9,0x3C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xC4,0xC0,0x38,0x3B,0x23,0x23,0xED,0x91,0x4C,0x59,0xCE,0x9B,0xDC,0x4E,0x02,0x12,0xF6,0xEE,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0x0A,0x61,0xAF,0x02,0xB4,0xD3,0xE6,0xB9,0xB6,0xD8,0xD3,0x39,0xF7,0x62,0xA5,0xCF,0xDC,0x18,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x31,0xBD,0xD4,0x3F,0xCB,0xC7,0xC4,0x1E,0xA4,0xAA,0x93,0xF7,0x1C,0x9D,0xC2,0x3D,0x64,0x3E,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0xC6,0x5E,0xE3,0x13,0xF3,0xB2,0x32,0x47,0x6C,0x1B,0xEF,0x5D,0x4B,0xB4,0x2D,0x04,0x34,0xBF,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x3F,0x6B,0x22,0xCA,0x6D,0xF8,0x24,0x99,0x68,0x01,0x3C,0x2D,0x94,0xBF,0xC4,0xD3,0xFE,0xC9,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x75,0xC9,0x55,0xA2,0x72,0xA9,0x9A,0xAD,0x4D,0xFE,0xCE,0xA2,0xA5,0x51,0xBC,0xEB,0x1D,0x42,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x15,0x4E,0x10,0xF4,0xC2,0xF2,0xF3,0xDA,0x9C,0x3C,0x8B,0x28,0x19,0x51,0xC6,0x30,0x8E,0x05,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0x69,0xFD,0x94,0x8D,0xF1,0x3E,0x5A,0x1C,0xE5,0x46,0xC8,0x24,0xCB,0xC7,0x8B,0x5E,0x89,0x4F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x1F,0x12,0x1E,0x91,0xE7,0x74,0x65,0xE0,0x5A,0x85,0x85,0xE5,0xBB,0xC9,0x2C,0xBA,0x4C,0x1A,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0x0E,0x61,0x44,0x51,0x02,0x85,0x42,0x69,0x57,0xA1,0xB0,0x3B,0x72,0x9F,0xA7,0x77,0x4C,0x7F,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0x58,0x33,0x9B,0xCA,0x69,0x1A,0x1D,0x04,0x62,0xF2,0xE2,0x16,0xAE,0x0F,0xEF,0x39,0xB0,0x79,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x19,0x62,0xF6,0xBB,0x35,0xCB,0xF8,0x14,0xF7,0x8C,0xAF,0x28,0xBA,0x40,0xE9,0x0C,0xF6,0xB1,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xD4,0x6B,0xF7,0x6B,0xAE,0x3E,0xFC,0x0E,0x63,0xDF,0x0E,0x9C,0xDE,0xF5,0xA8,0xDF,0xCF,0x41,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xB4,0xB4,0xF8,0xCB,0x2F,0xAE,0xFA,0xAF,0xD6,0xB8,0x8F,0x91,0x8E,0x18,0x85,0xD1,0xC0,0x16,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0x88,0xD8,0xC7,0xC8,0x92,0x3D,0xBB,0xB7,0xC7,0x17,0xF8,0x12,0x71,0x8B,0xF6,0xE9,0x4E,0x4C,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x0E,0x14,0xC7,0x09,0x26,0x95,0x40,0xE6,0x9A,0x7A,0x24,0xE4,0xE9,0x68,0x59,0x5A,0xCD,0x63,]),
-        (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x13,0x8F,0x1A,0x96,0x06,0xDB,0x25,0x59,0x37,0xB6,0x8E,0x02,0xFE,0xC8,0x3E,0x2C,0x54,0xB9,]),];
-    }
-}
\ No newline at end of file
+pub fn get_cipher_vectors() -> Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    vec![
+    (vec![], vec![], vec![0x14,0xDA,0x9B,0xB7,0x12,0x0B,0xF5,0x8B,0x98,0x5A,0x8E,0x00,0xFD,0xEB,0xA1,0x5B]),
+    (vec![], vec![0x00], vec![0xE8,0xD5,0x04,0x53,0xF8,0x4B,0x57,0x54,0x12,0x32,0x7D,0x7C,0x03,0x02,0xD8,0xD3]),
+    (vec![],vec![0x00,0x01,],vec![0x77,0x6F,0x82,0x9E,0xB5,0xDE,0x73,0xD4,0x00,0xEF,0x4D,0xED,0xB2,0xE2,0x77,0x2D,]),
+    (vec![],vec![0x00,0x01,0x02,],vec![0xAF,0xAD,0x61,0x1B,0xA2,0xDC,0xF5,0x1F,0x05,0xB1,0x31,0xA0,0xDA,0x08,0x11,0x2E,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,],vec![0xC4,0xEB,0x15,0x0E,0x92,0x18,0x01,0x58,0xFB,0xE8,0xB4,0x9E,0x15,0x16,0x90,0xF3,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x9D,0xC0,0x74,0xA7,0x7B,0x3F,0x8A,0x5F,0xD5,0xDB,0x9B,0xD0,0x26,0x60,0x73,0x70,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x39,0x90,0x47,0xF7,0xF8,0xA7,0x1E,0xDD,0x08,0x54,0x53,0x52,0x51,0x2B,0xAA,0xAC,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xDE,0x61,0x09,0x3D,0x85,0x70,0xD6,0x4F,0xCD,0xB8,0xC8,0x9B,0x76,0xB2,0x9E,0x24,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x65,0x8C,0xCC,0x60,0xEF,0xDD,0x2E,0xFB,0xE8,0xF9,0xCA,0x5E,0xC8,0x0D,0xF1,0x6F,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x0E,0xA7,0xA0,0x85,0xFF,0x31,0x6B,0x01,0x86,0x78,0x1C,0x82,0x0C,0x09,0xA8,0x26,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC7,0x36,0xEB,0xBF,0x1E,0x75,0x68,0x4F,0x2E,0x76,0x61,0xAF,0x02,0x81,0x67,0x14,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x47,0xD8,0xC0,0xF4,0x1B,0x1B,0xAF,0x04,0xD9,0x8D,0x9C,0xCB,0xFC,0x43,0xA3,0xDA,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0xD0,0xD3,0xB0,0x7B,0x12,0x4F,0x47,0x45,0x24,0x81,0xFF,0x18,0x0C,0xB7,0xFD,0x34,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x18,0xD0,0x64,0x07,0x2B,0x1A,0x59,0x9D,0x40,0xD4,0x70,0x1B,0xCD,0xAF,0x21,0x1B,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x09,0x60,0xE4,0x8E,0xEA,0xAA,0x2D,0x0F,0xC2,0x08,0xC1,0x4D,0x91,0x54,0xD4,0x92,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0xED,0xCB,0xDB,0x3A,0x2B,0x7C,0x74,0x0E,0xF0,0x1A,0xE1,0xBF,0x88,0xBD,0xD4,0x8C,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x47,0x17,0x6D,0x99,0x16,0x9B,0x45,0x55,0xB6,0x7B,0xD1,0x82,0x82,0xE4,0xA4,0x91,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x19,0xB6,0x5B,0xD1,0x5F,0x1C,0x2A,0x67,0x22,0x04,0x6E,0x5B,0x93,0xD8,0x2E,0x7A,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xB0,0xA0,0xD0,0xF3,0x1A,0x3A,0x74,0xF6,0xB1,0x9C,0xAA,0xA4,0xA0,0x95,0x7B,0x4A,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0xE8,0xA3,0x53,0xF0,0xE0,0x23,0x4A,0x5A,0xF6,0x8C,0xF8,0x5C,0x1A,0x01,0x30,0xAA,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x34,0xB3,0x6D,0x5B,0x79,0x07,0xCE,0x54,0xDC,0x6D,0xC3,0x93,0x55,0x29,0xF4,0xBE,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x2A,0xA6,0xE3,0xF4,0xDF,0x2D,0x9A,0xFE,0xCB,0x16,0xA0,0x71,0xA0,0xDB,0x5E,0x17,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x67,0xB5,0x76,0x34,0xA2,0x27,0xFC,0xD0,0x9D,0x2A,0xD2,0x9B,0xB1,0x36,0x27,0xA8,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x28,0xA8,0xA5,0x43,0xAD,0x0E,0x91,0x03,0x84,0x08,0xEF,0x99,0x28,0xE3,0x5B,0x37,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0xCA,0x7A,0xFB,0xD8,0x9D,0xAC,0x26,0x9D,0x16,0xC6,0x66,0xC4,0xCA,0x06,0xC4,0x68,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x8A,0x6A,0x82,0xAD,0xCC,0x0E,0x29,0x86,0x5E,0x2C,0x6E,0x21,0x05,0x12,0x11,0x4D,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x64,0x6C,0xE5,0x2D,0x7A,0xD9,0x3D,0xEA,0x40,0x4F,0x85,0xDC,0x95,0xBB,0xF1,0x18,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCD,0xAE,0xE2,0x45,0x47,0xD0,0xC7,0xDE,0xEA,0xF2,0xB1,0x29,0xC8,0x1D,0xCB,0xCE,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x90,0x61,0xF7,0x4D,0xE7,0x14,0xF2,0x32,0x0C,0x31,0x30,0x2E,0xCF,0xF5,0x75,0xC8,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x8F,0xE2,0xBA,0x44,0xC2,0x56,0x6A,0x86,0x1D,0x6C,0xF6,0x86,0xCC,0xC3,0x24,0x34,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0xFA,0xA3,0xE0,0x3E,0x50,0x36,0x96,0x85,0x6C,0x3F,0x53,0x1E,0xC5,0xAE,0xFC,0x7E,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x14,0x30,0xDD,0x76,0xDA,0xC1,0x19,0x7F,0xFF,0x90,0xBE,0x09,0x0E,0x7D,0x5A,0x0F,]),
+    (vec![],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x26,0x5B,0xCB,0x41,0x09,0x69,0x66,0x83,0x11,0x61,0x19,0xC0,0x68,0xA1,0xAC,0x4D,]),
+    (vec![0x00,],vec![],vec![0x7F,0x80,0x49,0x2C,0x31,0x7B,0x1C,0xD5,0x8A,0x1E,0xDC,0x3A,0x0D,0x3E,0x98,0x76,0xFC,]),
+    (vec![0x00,],vec![0x00,],vec![0xD4,0x4B,0xFE,0xBD,0xB1,0x38,0x2A,0x0F,0xAC,0x73,0xBC,0x9C,0xDD,0x06,0x57,0xC7,0x62,]),
+    (vec![0x00,],vec![0x00,0x01,],vec![0x8D,0x30,0xD6,0x08,0xE1,0x20,0x18,0xF1,0xE3,0xE0,0xC9,0xA6,0x2C,0x2D,0xAD,0xD1,0x66,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,],vec![0xBC,0xFC,0xDC,0x5B,0x20,0x12,0x15,0xCB,0xCD,0x33,0xBF,0xB3,0xF7,0x39,0x79,0x02,0x6D,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x2B,0x97,0xA0,0x46,0x34,0x24,0x63,0xC1,0x40,0xF1,0x47,0x31,0xF8,0xBF,0x4F,0x95,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x03,0xEF,0x53,0xFA,0x29,0x82,0x58,0x25,0x90,0x07,0x0C,0xA0,0xED,0x60,0x78,0x02,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x27,0x77,0x54,0x38,0xF5,0x63,0xA8,0x98,0x66,0xF2,0x8E,0x9E,0xF8,0x31,0x01,0x4A,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0xD4,0xB0,0x25,0x1A,0xB1,0x35,0x28,0xBD,0x4C,0x01,0xEB,0xEB,0x3A,0xFC,0x8A,0x9B,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xB1,0xF1,0x45,0x0C,0x4C,0x6E,0x0D,0xA5,0xF8,0x53,0x6B,0x08,0x22,0x9E,0xA1,0xB7,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0x16,0xA3,0x68,0x5A,0x41,0xF6,0x86,0xED,0x65,0x30,0xAE,0xD9,0x0E,0xBB,0x3B,0x45,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x65,0x07,0xB3,0xAD,0xCF,0xCD,0x49,0xD8,0x03,0x7F,0xE0,0x01,0xBE,0x58,0x27,0xAF,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0xA5,0x84,0x42,0xB7,0xF1,0x64,0x64,0x93,0x38,0xFF,0x3D,0xEE,0x88,0xCD,0xBB,0x0C,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x8D,0x51,0x8D,0xC0,0x3F,0xAB,0x91,0x98,0x03,0x36,0xB0,0x35,0x9D,0x74,0x19,0x93,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0x5D,0x1C,0x41,0x11,0xC8,0x5A,0x2C,0xE6,0xB2,0x7A,0xA4,0x69,0xD8,0xAE,0x32,0xB5,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x49,0xA2,0x8F,0x06,0x9E,0xD0,0xF1,0x2A,0xAD,0xB0,0x83,0x85,0x66,0x17,0x1E,0xC0,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0xD7,0x3C,0xDB,0xC6,0x59,0xAF,0xBC,0x7D,0xCA,0x72,0xC3,0x6A,0x5B,0x3E,0x5F,0x45,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x41,0xEF,0x53,0x08,0x50,0xD2,0x43,0x1F,0x4D,0xA1,0xB7,0x1E,0x43,0x0C,0x4C,0x49,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x5E,0x8A,0x35,0x3F,0xE9,0x46,0xD6,0x26,0x75,0x4B,0x80,0x97,0xF5,0x5F,0x06,0x16,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x1D,0x86,0x95,0x3B,0x05,0xD5,0x5E,0x17,0xB1,0x05,0x39,0x6C,0xA9,0x2B,0x7F,0x30,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x15,0x34,0x5A,0x7F,0x40,0x41,0x4F,0xCD,0xB5,0x3C,0x68,0xBE,0x1A,0x2C,0x1C,0xD5,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x97,0xFB,0x61,0x65,0x5D,0x28,0x4A,0xE8,0x78,0x29,0xD0,0xEB,0x88,0x2D,0x4D,0x90,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x2D,0x15,0xF6,0xA1,0x25,0x2D,0xFF,0x98,0xEE,0x2F,0x6B,0xBA,0x1F,0x11,0x83,0xDD,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0x27,0xFD,0x00,0xAD,0x02,0x01,0x45,0x78,0x9A,0xD6,0x2B,0x56,0x6D,0xFC,0xD8,0x7C,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x22,0xA1,0x41,0xBA,0x51,0xCA,0x65,0x28,0xA2,0xCA,0x54,0x4A,0x2D,0x5C,0x2C,0x9E,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x08,0x84,0x72,0xDE,0x89,0x76,0x0F,0x6A,0xEC,0x58,0xF0,0xA1,0x05,0x32,0x58,0xAB,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0xD6,0xF1,0x48,0x63,0x13,0x8A,0xD9,0x34,0x54,0xAC,0xE7,0x57,0xAD,0xB0,0xD4,0x56,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0xC4,0x4B,0x5C,0x99,0x51,0x78,0x97,0xEC,0xA3,0xE2,0xF8,0x71,0x3D,0x6A,0xF5,0xEB,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0xB3,0x02,0x00,0x78,0x32,0x75,0xFF,0x0E,0x13,0x60,0x48,0x13,0xF3,0x06,0x04,0xC1,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xCF,0x14,0xD4,0x7C,0x8B,0xCC,0xCC,0xCF,0x57,0x8B,0xDF,0x3C,0xB4,0xFD,0xC6,0xEB,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x74,0x64,0x4E,0xA6,0x3E,0x17,0xD2,0x52,0x59,0x61,0x7A,0xBF,0xC8,0x69,0x03,0x01,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0xD7,0x7F,0x94,0xF0,0x3C,0x56,0x32,0x58,0x09,0xC2,0x3A,0x83,0x39,0x51,0xFE,0x49,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x3F,0x87,0xC3,0xA0,0x5C,0xFE,0x15,0x1A,0xB6,0x07,0x26,0x21,0x53,0x3B,0xD0,0x24,]),
+    (vec![0x00,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x74,0xAC,0xE7,0xFA,0x42,0xF1,0xE0,0xB6,0xC6,0xE6,0x9C,0x48,0x43,0xC8,0x81,0x67,]),
+    (vec![0x00,0x01,],vec![],vec![0x7F,0x8A,0x3A,0xEB,0x5A,0x86,0xF3,0x21,0x6A,0x8B,0x70,0xF8,0xE4,0x3D,0x02,0xEC,0x8D,0xC4,]),
+    (vec![0x00,0x01,],vec![0x00,],vec![0xD4,0x4A,0x67,0x48,0x1A,0xA7,0x9C,0x21,0x51,0x00,0xC3,0x1D,0x50,0xE9,0x56,0x43,0x73,0xE9,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,],vec![0x8D,0x72,0xCA,0x21,0x25,0x95,0x58,0xEA,0x09,0x0A,0x65,0x8E,0x15,0xE8,0xAF,0x10,0xB4,0xBB,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xD0,0x50,0x56,0x1F,0xE0,0x52,0xF3,0x26,0x8A,0x49,0xF7,0xE9,0x8E,0xDB,0x97,0x2B,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x95,0xAD,0x5F,0xA2,0xA6,0x63,0x0E,0x1D,0xBE,0x39,0x19,0x79,0x19,0xAD,0x00,0x48,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x80,0x9D,0xEF,0xB1,0x60,0x1B,0x6F,0xB8,0x83,0x55,0xF8,0x1E,0xF8,0x98,0xB0,0x05,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0xA1,0x55,0x84,0x72,0x9A,0x73,0x9D,0x67,0xCC,0xBC,0xC6,0x9B,0xA2,0xFF,0x8F,0xBE,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xC6,0x6D,0x53,0x13,0xDA,0x99,0x93,0x7E,0x37,0xF0,0xD1,0x13,0x4A,0x8C,0xD5,0x65,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0x98,0xA1,0x93,0x39,0xC8,0xD2,0xC0,0x7B,0x9D,0x4D,0x2E,0x29,0xF7,0x45,0x8D,0x15,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0xDB,0xF1,0x6F,0x33,0x2E,0xBA,0x08,0x7E,0x72,0x36,0x52,0x85,0xA9,0x91,0x3D,0x45,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xB5,0xD7,0xD9,0x6D,0x5A,0xCB,0x71,0xBF,0xF4,0xB8,0xE6,0xCB,0x99,0x57,0x5D,0x27,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x58,0x21,0xD0,0x6C,0xA3,0x05,0xFA,0x4F,0x91,0xEF,0x70,0x3B,0x7C,0x9B,0xEF,0xCB,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x0E,0xC7,0xDA,0xF6,0x5F,0x4F,0xCF,0x5E,0x0E,0xB5,0x77,0x09,0x3B,0xDA,0x3A,0x0D,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xF6,0x42,0x28,0xD3,0x98,0xC4,0x27,0x32,0x76,0xF8,0xAE,0x3F,0xDE,0x45,0x44,0xFC,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xB8,0xFD,0xD4,0x40,0xFB,0x25,0x7F,0x4F,0x91,0xCD,0xAA,0x44,0xA7,0xD8,0x53,0x9F,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0xBC,0xBC,0x5E,0xBD,0x2D,0xED,0x5B,0x38,0x98,0x73,0x5C,0x29,0x72,0x5B,0xF5,0x2B,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xD2,0x69,0xE0,0xDD,0x4A,0xEE,0xA0,0xB1,0xA6,0xD4,0x5B,0xE0,0x58,0xB0,0x6D,0x12,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0x72,0xBA,0x1B,0x93,0x1B,0x90,0xA0,0xCD,0x54,0x75,0x7C,0xFC,0xDE,0x0C,0xDC,0x5C,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x47,0xE6,0xC7,0x8C,0xC2,0x6F,0x5E,0xD7,0x93,0xE8,0x7A,0x44,0xE2,0xE7,0x75,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x64,0x14,0xEC,0x61,0xCF,0xBA,0x83,0x62,0x79,0xE4,0x80,0xB5,0x81,0x28,0xD9,0xF6,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x1B,0x65,0xC0,0xBE,0x39,0x03,0xFB,0xDC,0xB8,0x30,0x69,0x9C,0xB5,0x23,0xAE,0x5C,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0x25,0xD9,0x11,0xA7,0x71,0x50,0xF9,0x57,0xDB,0x77,0xA8,0xEE,0x3D,0x01,0x32,0xB4,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0x3B,0xC6,0x1E,0xDA,0x85,0xD7,0xF3,0x04,0x6B,0x29,0xA1,0x7D,0xF1,0xD5,0xE0,0xCA,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x50,0xD7,0x9C,0x3C,0x61,0x97,0x3D,0x8E,0xA8,0x34,0x72,0xC4,0x0E,0xAA,0xA2,0xFF,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0xAE,0x82,0x71,0xBC,0xA0,0xC6,0xA6,0xB1,0x66,0x84,0x9C,0x1B,0x98,0x81,0xB1,0xEF,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xB0,0x40,0x58,0x3F,0xE6,0x2B,0xCA,0x84,0xE6,0x24,0xFC,0x1E,0xBD,0x79,0xF8,0x48,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xDD,0xA8,0x41,0x6B,0x45,0xA8,0x4C,0x63,0x1B,0x0B,0x9F,0x7E,0x55,0x62,0xBD,0x54,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB5,0xBB,0x54,0x3E,0x4D,0xA5,0xF3,0xC0,0xEF,0x6A,0x9C,0x9D,0x46,0xB4,0x6E,0xAF,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x49,0x0E,0x61,0xBB,0x6A,0xE5,0xC6,0x7F,0xC2,0x98,0x49,0xBA,0x0C,0x0A,0x7F,0xD2,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0xF6,0x6B,0xE3,0x09,0x18,0x96,0x9E,0x0E,0xFC,0xE3,0x8A,0xDE,0xDC,0xFC,0xCF,0x35,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xC5,0xDA,0xCE,0x74,0xA0,0x7D,0x3E,0x21,0x2E,0x8C,0xED,0x18,0x4C,0xC1,0x86,0xF8,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0xCD,0x3D,0x8F,0xD1,0x85,0x1F,0x46,0xFC,0x23,0xE4,0x8B,0xCF,0x61,0xEC,0xBC,0x14,]),
+    (vec![0x00,0x01,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x17,0xE7,0xF1,0xA2,0x10,0xB8,0x3A,0xDC,0x80,0x55,0x08,0x9D,0xD5,0x95,0x87,0xB4,]),
+    (vec![0x00,0x01,0x02,],vec![],vec![0x7F,0x8A,0x2C,0x65,0xCA,0xBB,0xEE,0x8A,0x9A,0x9A,0x95,0x9C,0xEC,0x12,0x24,0x83,0xE4,0xE4,0x96,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x8E,0xBC,0x47,0x3D,0x39,0xF3,0x94,0xB6,0x22,0xEE,0x54,0xFD,0xD2,0x24,0xBE,0x18,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x02,0x12,0x5E,0x69,0x2F,0x97,0x80,0x09,0x89,0xF3,0x5C,0x63,0x21,0xEF,0xE1,0x25,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xF2,0x80,0x5E,0xDB,0x5E,0xFE,0x5C,0xE3,0x44,0x35,0xE3,0xCA,0xAD,0x98,0x8B,0xED,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x86,0x37,0xA1,0x9B,0x87,0xB3,0xDE,0x09,0xB7,0x9A,0x0D,0xB0,0xF8,0xE8,0xB2,0x11,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xD7,0x43,0x0D,0xD3,0xBA,0xA2,0x57,0x5E,0xE4,0x84,0x28,0xF9,0x95,0xFF,0x1E,0x4C,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x03,0x0B,0xBE,0xA2,0x3B,0x61,0xC0,0x0C,0xED,0x60,0xA9,0x23,0xBD,0xCF,0x91,0x47,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x45,0x2D,0x8D,0x18,0x30,0x03,0x98,0xB2,0x67,0xA3,0x8A,0x70,0xD2,0x2F,0x3C,0x03,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x51,0xAA,0xD2,0xAF,0x4F,0x6A,0x05,0x38,0xD4,0xB7,0x77,0x28,0x61,0xF7,0x11,0xC5,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x3B,0x3B,0xC1,0xA1,0x42,0xDA,0xC7,0x5D,0xBD,0x72,0x4B,0x99,0x24,0xA8,0xA8,0xE9,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x4D,0xD5,0xD6,0xE1,0x0E,0x88,0x29,0x85,0xA4,0x52,0xE7,0xE4,0x9A,0x05,0x33,0xA1,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x6C,0x9C,0x12,0x5E,0x7C,0xE9,0x87,0xCA,0xD1,0xC3,0x2E,0x5A,0x7C,0xF3,0x05,0xAD,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0x12,0xCB,0xFC,0x34,0xEC,0x87,0xBF,0x46,0xC9,0x8A,0xAD,0x14,0xAA,0x08,0x68,0xA0,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0xE7,0x8E,0xD4,0xF9,0x9D,0xF9,0xD9,0xE6,0x54,0x8D,0x58,0x27,0x80,0x6F,0xBD,0x26,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0xB3,0xE5,0x3A,0x9F,0xC0,0x79,0x4D,0xA9,0x76,0x74,0x4B,0x07,0x2F,0xDB,0xDF,0xF0,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x2E,0xB6,0xEB,0xF3,0xFE,0x27,0x83,0x13,0x5B,0xC4,0x1A,0x85,0x84,0x63,0x7D,0x93,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0x2F,0x55,0x99,0x40,0xA0,0x69,0x57,0xD5,0xE3,0xB0,0x27,0xBA,0xCE,0x5B,0x04,0x08,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0xC2,0xA0,0xFE,0xF5,0x2C,0x98,0x3C,0x9A,0x07,0xF1,0xC6,0x7B,0x7F,0x40,0x32,0x31,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x49,0x7D,0xF3,0x65,0xDF,0x64,0x27,0x4C,0x58,0x6A,0x71,0x68,0x58,0x46,0xD0,0x61,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x6C,0xF0,0xFE,0xF6,0x41,0xBD,0xF5,0xB7,0xAA,0xBE,0xC4,0x4B,0x9C,0xBA,0x67,0xE5,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x62,0xC5,0x8A,0xC2,0xB6,0xC0,0x07,0xDB,0xD0,0x1B,0xDC,0xFA,0x49,0x7F,0xD1,0x06,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x8F,0xDD,0x07,0x0F,0xE8,0xE9,0x2A,0x61,0x2E,0x0A,0x90,0xD1,0x8D,0x8C,0x58,0x03,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0xBE,0x23,0x6C,0x5B,0x83,0xED,0x01,0x27,0x8B,0x26,0x40,0xD3,0x56,0xE5,0xF2,0x89,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x89,0x40,0x9F,0x6C,0x8C,0x10,0x1C,0x46,0xE2,0xC1,0x20,0x4E,0xFD,0x68,0x1A,0x69,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x3E,0x45,0xEA,0x44,0xE3,0x2C,0x60,0x1C,0xE5,0x40,0x6C,0xEF,0x15,0xE3,0x03,0xA9,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0x42,0x94,0x1C,0x1B,0xA0,0x12,0x75,0xE7,0x43,0x85,0x92,0xA0,0x0E,0xD4,0xE3,0x56,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0x56,0x06,0x6B,0x16,0xBB,0x40,0x2D,0x21,0x72,0x5D,0xAC,0x29,0xDA,0xBD,0xDC,0x83,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x9D,0xFA,0x99,0x41,0x45,0xF8,0x99,0x67,0x7F,0x05,0xA7,0x93,0x0C,0x65,0xC0,0x1D,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x55,0x79,0x2F,0x45,0xD4,0xC7,0xD5,0x27,0x45,0x9D,0x72,0x78,0x9D,0x51,0xA1,0xDA,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x5E,0x96,0x1B,0x5F,0x2C,0xE5,0x44,0x62,0x05,0x30,0x88,0x79,0xC2,0x6D,0x8D,0xE5,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xBD,0x62,0x04,0xF8,0x6A,0x84,0xE2,0x0D,0xA7,0xFC,0x62,0x69,0x38,0x8B,0x6E,0xC9,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0xC8,0x0F,0x25,0x20,0xD9,0xE9,0x35,0x73,0x92,0x2D,0x26,0x90,0x71,0x47,0x66,0x45,]),
+    (vec![0x00,0x01,0x02,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x33,0xCA,0x01,0xBD,0x4E,0x54,0x9F,0xF3,0x47,0x24,0xD3,0x32,0x57,0x77,0x8F,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0x78,0x33,0xEE,0xA3,0x0E,0x11,0xFB,0x4B,0x90,0x2B,0x63,0xEB,0x0D,0x3F,0xA6,0x71,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xCA,0xBC,0xDD,0x54,0x73,0x04,0x14,0xD7,0x50,0xBD,0x60,0xC5,0x19,0x98,0x07,0x85,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0xE4,0xA7,0x24,0xE8,0x35,0x45,0xA2,0x94,0xB2,0xE8,0x33,0x62,0x03,0xB8,0xE6,0x1B,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0xD7,0x63,0x1C,0x77,0xDE,0x54,0x53,0x71,0x52,0x0A,0x3E,0x23,0x9E,0x61,0x9C,0xF8,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xC0,0x6E,0x10,0x23,0x96,0xB8,0x1B,0xC1,0x68,0xB4,0xA0,0x29,0x0D,0xD3,0x57,0x6F,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0xA8,0xC5,0xFE,0x55,0x0F,0x44,0x7E,0x9B,0xAA,0x45,0x23,0x8D,0xA3,0xB7,0x30,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0x89,0x63,0x3A,0xAC,0x35,0x3D,0x56,0x53,0x90,0xAF,0xA6,0xC2,0x41,0x3A,0xCA,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x81,0x6D,0x4C,0xF3,0xBA,0xF6,0xB3,0x25,0xEC,0x93,0x7A,0xA8,0x67,0xA3,0xA8,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0x7B,0xE7,0x14,0x98,0x69,0x62,0xA7,0xB4,0x6D,0x50,0x6B,0xD8,0x03,0xC0,0xAF,0x48,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0x26,0x04,0xF5,0x7E,0x8B,0xD3,0x99,0x89,0x9F,0xB0,0x1A,0xA2,0x46,0x8D,0x0D,0xD8,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x6E,0xDD,0xAD,0xD9,0x42,0x83,0xEF,0xCB,0x46,0x6A,0xA3,0xB3,0xF0,0xE4,0x1E,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0x7D,0xDE,0xBF,0x36,0x9C,0x62,0x25,0x94,0x2A,0xE6,0x18,0x1B,0xEC,0x32,0x50,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x04,0x46,0x01,0x40,0x58,0x39,0x71,0xA2,0xE3,0x4C,0x9D,0x93,0xD8,0xC4,0x8F,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x66,0x5D,0x4A,0x4A,0x94,0x3D,0x41,0xFA,0x8C,0x56,0xF4,0x91,0xCA,0x0C,0x53,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0xFA,0x4A,0x8E,0x43,0x5E,0x3F,0xE6,0xC4,0x0C,0xE8,0x75,0xBF,0x87,0xFF,0xF1,0xC7,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0xBA,0x42,0xCB,0x27,0x63,0x99,0x79,0xF7,0x7A,0xC8,0x53,0x3F,0xEE,0xB2,0x49,0xB4,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0xA8,0xA3,0x2F,0x1D,0x6C,0x96,0xF6,0x00,0x7D,0x45,0xE0,0xDE,0x83,0xC6,0xE5,0xA7,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xDF,0x4A,0x17,0xBF,0xA0,0xC6,0xA7,0x4E,0x6C,0x52,0xEA,0x6B,0xA3,0x5A,0x45,0xD8,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x91,0xC5,0x29,0x2F,0xBC,0xC8,0x77,0xF4,0x62,0x2C,0x22,0x2B,0xB7,0x02,0xDE,0xC9,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0xCB,0x26,0xDA,0x17,0x82,0xCB,0x0B,0xD6,0x72,0xA9,0x9B,0xFE,0xBD,0x2E,0x52,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x78,0x38,0x53,0x7C,0xDB,0x45,0x86,0x21,0x26,0xA9,0x4E,0x2C,0xF3,0xD3,0xEA,0x5F,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x60,0xEA,0xD7,0x32,0xAC,0xFD,0x8A,0x06,0x73,0x1E,0x34,0x91,0xFB,0xC7,0xDD,0x79,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0x7A,0x52,0x7F,0x28,0x7A,0x98,0xD2,0xB2,0x57,0xCD,0x6E,0x50,0x00,0x54,0xBE,0xD4,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xBD,0x2B,0xBD,0x12,0xE3,0x3D,0x11,0x3C,0xC1,0xFA,0xDB,0x6E,0x86,0x92,0xF7,0x85,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x69,0x05,0x16,0x7E,0x56,0xF0,0x2C,0x2B,0x33,0x6D,0xA3,0x9B,0xD1,0x8F,0xEF,0x37,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0xFB,0x84,0x77,0xB6,0x95,0x58,0xF8,0xD9,0x4C,0x38,0xFE,0xF5,0xC9,0xC0,0xB1,0x3C,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0xCD,0x72,0x1B,0x0B,0xB6,0x31,0x16,0x91,0x7E,0xE5,0x93,0x5C,0x4C,0xB7,0x88,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0xB5,0x29,0x8F,0x91,0xB7,0x8D,0x7E,0x03,0xD5,0xD7,0x16,0xBC,0x0A,0x38,0x11,0x11,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0xD9,0x5C,0xBE,0xBE,0xB9,0xC1,0xF0,0xB9,0xF3,0xA1,0xC7,0x96,0x6A,0x66,0x78,0xBC,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x82,0xAA,0x06,0x3E,0x91,0x0F,0xEA,0xEA,0x15,0x23,0x29,0x9A,0x59,0x1D,0x97,0x9A,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0x29,0x84,0x0E,0x32,0x5F,0xCB,0x31,0xAB,0xD7,0x8D,0x5E,0xA4,0x45,0x4B,0x80,0xF1,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x58,0xDF,0x15,0x99,0x78,0x64,0x7D,0x2D,0xD9,0xB1,0x21,0xB7,0x07,0x0A,0x85,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x88,0x8B,0xA9,0x1D,0x38,0xE9,0x66,0x35,0x02,0xA9,0x47,0xEF,0x49,0xBA,0x7F,0xA0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x4A,0xAA,0xB2,0x78,0x35,0x10,0xB9,0xD1,0x98,0x23,0x65,0x3F,0x4B,0x24,0xDF,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xBF,0xAB,0x08,0x3B,0x9F,0xF8,0x70,0x2F,0x53,0x31,0xBC,0x31,0x7F,0xC5,0x6E,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xAD,0x52,0x7A,0xC4,0x92,0x36,0x72,0xCC,0xD7,0x93,0xA1,0x18,0x7F,0xED,0x47,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0xAC,0xF0,0xA8,0x1B,0xC9,0x0B,0x56,0x74,0x5B,0xA5,0xB4,0x86,0x2F,0x6A,0xFE,0xA6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0xE9,0xDB,0x4A,0x88,0x75,0xB6,0x99,0x8F,0xFA,0xE2,0xD2,0x75,0x53,0x0F,0xF1,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0x22,0xB9,0xCA,0xBA,0x2B,0xFE,0xBA,0x8C,0x42,0x7D,0x7A,0x24,0x3D,0xA4,0xEC,0x7A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xFC,0xFA,0x43,0x71,0x3D,0xD0,0x23,0x4D,0x06,0x19,0x0D,0xCB,0x63,0xEE,0x8F,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x9D,0x2C,0x12,0x3B,0x21,0x26,0xFE,0x6C,0x64,0x52,0xFF,0x5C,0xCA,0xFA,0x16,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0x5D,0x3B,0xDD,0x20,0x68,0x32,0x37,0x15,0xC5,0x4D,0x8D,0xEF,0xE8,0x01,0x09,0x46,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0xE5,0xD6,0x27,0x3B,0x40,0x79,0xF8,0x6E,0x13,0x0F,0xFD,0xA6,0x6D,0x69,0xDF,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x37,0xA5,0x10,0x97,0xA7,0x98,0xC1,0x12,0xDB,0x23,0x6A,0xC4,0x8A,0x8C,0xA0,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0xA9,0xAB,0xF5,0x22,0x90,0xBE,0x57,0x57,0x3D,0x0A,0x76,0xC6,0xE0,0xFA,0x01,0x62,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0xEC,0xAB,0xE6,0xF4,0xE0,0x88,0x91,0x4C,0xFD,0x2A,0x97,0x62,0x84,0x17,0x22,0xB9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x3F,0xAD,0xD6,0x3D,0x25,0x0F,0xC7,0x11,0x16,0x0B,0xEE,0x00,0x53,0x8E,0x27,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0xC5,0xB4,0x22,0xA3,0xBD,0x0F,0xA2,0x34,0xE1,0x00,0xF1,0x91,0x4E,0xF3,0xF0,0x65,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xCB,0xEA,0x8C,0x73,0xFC,0xC1,0xBD,0x9C,0x06,0xA2,0xA0,0x80,0x2D,0x3B,0x5E,0xB4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCE,0x10,0x0C,0xD0,0x23,0x50,0x2F,0x3A,0xA5,0x5C,0x58,0xE3,0xDD,0x46,0xC4,0xF0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0x95,0xBF,0x4E,0xA8,0x93,0x15,0xC4,0x57,0xBD,0x20,0xBF,0xEC,0x2D,0xDC,0x1A,0x52,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0x92,0x66,0xBC,0x8F,0x20,0x35,0x12,0xAC,0x89,0xB2,0x8B,0x01,0x20,0x6E,0x1B,0x9E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x21,0x3F,0xE8,0xD7,0x00,0xCF,0x76,0x54,0x03,0xF1,0xE9,0x49,0xF1,0xE9,0x98,0xD0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0xF7,0xAF,0x3A,0x90,0xD5,0xB5,0xE3,0x7A,0xC3,0x14,0xE9,0x0D,0x84,0xA1,0x50,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0xEC,0xFD,0xDB,0x91,0x9C,0x1B,0xCA,0xCA,0xBD,0x68,0x9E,0x17,0xD6,0x85,0x08,0x01,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x78,0x0F,0x3F,0xB9,0x66,0x75,0xCD,0xF3,0x60,0xAA,0xAD,0x5E,0x4E,0x7B,0xD0,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0x02,0xE2,0xFA,0xEA,0x29,0x55,0x98,0xD6,0xB1,0xD3,0xC5,0x80,0x7D,0x7B,0xDB,0x5F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xEC,0x8E,0x15,0xC7,0x50,0xEC,0xD3,0xAE,0x6E,0xA1,0xFB,0x63,0x48,0x91,0xD1,0xF9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0xBE,0x89,0x66,0xAE,0x90,0x5D,0x18,0xD6,0x4C,0xD1,0x00,0xFE,0x4F,0x61,0xB0,0x7A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC7,0x78,0xFF,0x9D,0xC2,0x81,0xE4,0xD6,0x02,0xC4,0x75,0x83,0x6E,0x1E,0x9E,0xD8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x82,0xF0,0xB0,0x4B,0xCD,0x8F,0x27,0xC4,0x1F,0x84,0xBC,0x69,0x33,0xD8,0x82,0xDC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xCC,0x30,0x1C,0xC1,0x2B,0x45,0x3E,0xF3,0xE7,0x8C,0xE6,0x92,0xDD,0x18,0x7D,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x2D,0x1C,0x91,0xBC,0x47,0x2E,0xD9,0x2B,0x40,0x10,0x2B,0x81,0xFC,0xFC,0x5F,0x94,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0x68,0xA6,0x23,0xA1,0xDB,0x3C,0x23,0x63,0xD2,0xD3,0x7F,0xE3,0xD1,0x3C,0xD7,0xE4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0x12,0x1C,0x56,0x03,0xC5,0x1C,0x79,0x99,0x93,0x3A,0x4E,0x0F,0x7A,0xE7,0x9C,0x0E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0xE8,0xD5,0x21,0x85,0x42,0xB8,0xBA,0x8D,0x27,0xFD,0xF7,0xB8,0xF5,0x86,0x96,0x65,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xEA,0xAF,0x81,0x6B,0x70,0x6A,0xEA,0x2A,0x74,0x1A,0xED,0xF7,0x40,0x28,0x88,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0xD5,0x8B,0x25,0x65,0x03,0x35,0x32,0xA2,0x6A,0x97,0x52,0xC4,0xAA,0xD2,0xEB,0xA1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x3A,0x4F,0x0C,0x36,0x8C,0x08,0xC1,0xB4,0x64,0x2E,0x37,0xA2,0xA2,0x0A,0x11,0x97,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xFA,0x4A,0xE9,0x4D,0x95,0x24,0x3E,0xAF,0x64,0xDE,0xAB,0xE4,0x71,0x70,0x64,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x4D,0x6A,0xE8,0xE0,0xFE,0xBB,0xFD,0x11,0xD7,0xA1,0x2C,0x5F,0xE8,0xF6,0xEE,0x29,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0x78,0x2E,0x7A,0x9D,0x23,0xB0,0xD2,0x1B,0xBD,0x87,0x18,0x15,0x55,0x07,0x02,0x2E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x9C,0x76,0xD2,0x22,0x22,0xDD,0xC0,0x23,0x40,0x4B,0xA0,0xD9,0xB5,0xDE,0x57,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x0F,0x3A,0x06,0x6E,0xDD,0x6D,0x57,0xD5,0xA8,0x1D,0xFA,0x06,0x3E,0x36,0xD3,0xF7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x82,0x67,0xDA,0xC0,0xC6,0xCA,0x5F,0xC9,0xC7,0x03,0x6B,0xFF,0xA4,0xA7,0xCD,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0xC6,0x12,0xDF,0x6E,0xEE,0x2C,0xEE,0xF7,0xFB,0xD5,0xF0,0x65,0xAB,0x88,0xF3,0x5C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x5C,0xDD,0x18,0x43,0x70,0x25,0xA1,0x80,0x27,0x72,0xAC,0x56,0xFE,0x22,0x19,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xF2,0x40,0x52,0xD3,0x60,0x33,0x7C,0x84,0x7A,0x19,0x95,0x88,0xBE,0xD9,0x6A,0x56,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0x14,0x33,0x1F,0xBD,0xB3,0xD1,0x20,0x11,0xEB,0x39,0x7B,0xDF,0x70,0x9E,0x21,0x8B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0xE1,0x75,0x48,0x0E,0x2B,0xAC,0xE8,0x1D,0xAC,0xA2,0xA2,0xB8,0xE7,0xFC,0x86,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x47,0xF2,0xA8,0x9D,0x75,0x73,0xE0,0x98,0x9B,0xEA,0x11,0xF0,0x74,0xCD,0x63,0x12,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x57,0x3F,0x5D,0x42,0x1D,0xFC,0x06,0x38,0xC2,0x51,0xB7,0xAE,0x5C,0xD5,0x23,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x0D,0xB1,0x2E,0x9E,0x11,0x1E,0x3D,0x6C,0x11,0xBD,0x34,0x2E,0x57,0x71,0x60,0x53,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0xAC,0xF8,0x0D,0xCB,0x44,0x68,0xB2,0x54,0xFE,0x6F,0x5B,0x75,0xB2,0x46,0xF8,0x82,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB3,0x3E,0x7F,0xC3,0xFF,0xDC,0xB4,0x2E,0x5A,0x9C,0x98,0x7A,0xD9,0xBD,0xFA,0xB2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0xD6,0xBE,0x01,0xAB,0x13,0x24,0xA5,0x75,0x33,0x20,0x17,0xE8,0x16,0x68,0xBF,0x85,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x1F,0x53,0x19,0x3A,0x05,0x18,0x55,0x09,0x13,0x12,0xE8,0x51,0xEE,0xA1,0x46,0x00,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0x8E,0x25,0x17,0xDD,0x1A,0x2C,0xA9,0x3D,0x45,0x3A,0x13,0xC7,0x07,0xB1,0xE5,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xAE,0xBF,0x28,0xF8,0x2C,0xD3,0x1E,0xF5,0x81,0x33,0x83,0x48,0xA7,0x3A,0x49,0xA9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x60,0xF2,0xE2,0x88,0x68,0x8F,0x8C,0xF7,0x29,0x24,0xF2,0xEB,0x59,0x35,0xE0,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x50,0xF6,0xED,0x64,0xFC,0x8B,0x74,0xA5,0xA3,0x3D,0xBC,0xA4,0xE6,0xEE,0x67,0xFE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xEF,0x03,0xEE,0x0A,0xC0,0x73,0x47,0x12,0xA6,0x8D,0xCD,0x23,0x77,0xEB,0x4E,0x48,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0x96,0x7D,0x1D,0x52,0x59,0x59,0x3E,0x6B,0x85,0x21,0x04,0x2B,0xFE,0x48,0xB4,0xA1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0x76,0x60,0x55,0x71,0xA9,0x80,0xFF,0x13,0x91,0xA0,0x90,0xB6,0x7B,0x2E,0x43,0xAC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0xF2,0x1F,0x9D,0xC4,0xE3,0xAB,0x0F,0xE5,0x8D,0x9F,0x68,0x3F,0x00,0x77,0x61,0xD7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0xF9,0xE1,0xB7,0xC0,0x4D,0x7C,0x89,0xC7,0xB2,0xF1,0xF3,0x43,0xF4,0xD6,0x5F,0xED,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0x37,0x39,0x72,0x29,0x0C,0x2B,0xFE,0x47,0xFC,0x50,0x4D,0x01,0xEC,0x2F,0x8B,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x72,0x82,0xD0,0x81,0xD1,0x7C,0xD1,0xA0,0x23,0xAF,0x58,0xF9,0x14,0x96,0x59,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x23,0x06,0xE3,0x9A,0x90,0xEB,0x9D,0x46,0x3E,0x78,0xEA,0x45,0x18,0x9C,0x3C,0x24,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xF7,0x21,0x63,0x96,0x0F,0x9C,0x1E,0xF7,0x70,0x85,0x4F,0x93,0x53,0x2C,0x6B,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0x6C,0x27,0x95,0xD0,0xD0,0x38,0x78,0x22,0xC5,0xF9,0xEB,0x4B,0xE5,0x59,0x15,0x6B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x08,0xBC,0x6E,0x59,0xA7,0x76,0x16,0x9B,0xD1,0x2C,0xD3,0x16,0x3B,0x41,0x5E,0xE2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0xEC,0xF9,0x4C,0x3D,0x74,0x30,0x25,0xA6,0xF8,0x99,0xCF,0x14,0x84,0x3A,0x5A,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x03,0x0F,0x7E,0xFA,0xE5,0x1F,0xD4,0x03,0x08,0x77,0x98,0x15,0x61,0xA7,0x73,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0xC0,0x8F,0x9F,0x89,0xF9,0xD8,0x1E,0x7D,0xB2,0x72,0x4F,0x48,0x7B,0x00,0x16,0x54,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x12,0xA4,0x7A,0xF7,0x7A,0x19,0x76,0xEB,0x25,0xEF,0xE4,0x40,0xE0,0x5B,0x50,0xF1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x9E,0xAB,0xDF,0xE6,0x9A,0xD1,0x43,0xDA,0xBE,0x0B,0xA2,0xEB,0x91,0xBC,0x7D,0xEB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x72,0x70,0x95,0xD3,0xFC,0x12,0x20,0xB4,0x06,0x6B,0x3C,0x67,0x7D,0x71,0xFE,0xD2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0x94,0xFF,0x3A,0x30,0x4F,0xCC,0x69,0x71,0x3B,0xC4,0x2D,0x15,0x65,0x2E,0x39,0x84,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0xEC,0xED,0xC0,0x6D,0x64,0x1A,0xE2,0x98,0x8A,0x9F,0xEB,0xD0,0x90,0x12,0x04,0xAE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0xA6,0x44,0x66,0x85,0xCE,0x2C,0x59,0xD5,0xFE,0x16,0xC5,0x9F,0xE1,0xA4,0x62,0x63,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x57,0x55,0xA9,0xB7,0xB0,0x8A,0xB1,0x17,0x89,0x67,0x2B,0x3E,0xD6,0x4C,0x9B,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0xE6,0xC5,0xE7,0x22,0xF1,0xC3,0x5B,0x83,0x44,0x9A,0x37,0x33,0x31,0x5A,0xBF,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x53,0x71,0x0B,0x4B,0x49,0x43,0x22,0xDF,0x32,0xD7,0x48,0x25,0x9C,0xBA,0x71,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x6E,0x79,0xF3,0xEF,0x66,0xA4,0x5F,0x23,0x40,0x62,0x05,0x20,0x5E,0x16,0x9F,0xCB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x35,0x9B,0x11,0xE0,0xF7,0x1B,0xF0,0xC1,0x40,0xF0,0xDB,0x26,0x8A,0x79,0x6E,0xC0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0xF0,0xA1,0xCA,0x0B,0x7D,0xF5,0xBE,0x7F,0x3C,0x84,0x6D,0x38,0x17,0xD1,0x6E,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0xB6,0x1C,0x69,0xD4,0x92,0x87,0x9A,0xFE,0x0F,0x78,0x02,0x25,0x17,0x3F,0x23,0xBD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0x6C,0xC9,0x2C,0x63,0x14,0x10,0xA6,0xB1,0x30,0xC8,0x22,0x87,0x5F,0x39,0x79,0x5C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0xFC,0x33,0x8A,0x6A,0x3D,0x17,0xA4,0x9A,0xA2,0x6D,0x61,0x89,0x85,0xAF,0x16,0x6A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0xB2,0x52,0x44,0xFC,0x88,0x50,0x3D,0xEC,0xC1,0x74,0x37,0x0B,0x8B,0x68,0xC5,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0x9F,0xCB,0xEB,0xCD,0x2B,0x7F,0x80,0x95,0x6E,0x60,0x2C,0xE7,0xCB,0xFB,0x61,0x90,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x81,0x39,0x02,0xEA,0x5F,0xB8,0xDC,0x21,0x41,0xA4,0x8C,0xFB,0x70,0x29,0xC6,0x63,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x7E,0x46,0x16,0x79,0x95,0x53,0xCB,0xE0,0xEF,0x75,0x37,0x47,0x54,0x57,0x98,0xC4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0xF1,0x83,0xD3,0x99,0x40,0xEF,0xB4,0xDA,0x63,0xDC,0xF4,0x32,0x9E,0x00,0x48,0xBF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x4F,0xB8,0x84,0xA1,0x77,0x6E,0xA3,0xA5,0x15,0x9F,0x00,0xBC,0x22,0x0C,0x9D,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x84,0xC9,0xAE,0x69,0x77,0x94,0x6D,0x94,0x20,0x85,0x29,0x9F,0xA9,0x89,0x3D,0x5C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x40,0x53,0x9B,0x49,0xCE,0x65,0x9A,0xF1,0x67,0xB2,0xD4,0x1E,0x82,0xB7,0x26,0x59,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xD2,0x80,0x6F,0x6E,0xAA,0xAF,0x1E,0xD3,0x68,0x22,0x53,0xA1,0x90,0x8D,0x25,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x07,0xB3,0x53,0xE0,0x2E,0xB6,0x7D,0x77,0x5D,0x80,0x55,0x86,0x6F,0x17,0x63,0x9A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x33,0x38,0x6B,0xC4,0x71,0x02,0xD2,0x15,0x35,0x8F,0x0B,0xDF,0x21,0x9D,0x2F,0x68,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0xBB,0x8E,0x93,0x87,0xB7,0xBF,0x70,0xE6,0xE3,0xBF,0x44,0x3C,0xDF,0xF8,0x89,0xDC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0x7D,0xD4,0x06,0x61,0x23,0x5E,0x8C,0xEB,0x2B,0xEB,0x40,0xB6,0xE6,0xC5,0xEE,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0x8F,0xAC,0x22,0xB2,0xD5,0x71,0xEF,0xF5,0x67,0x49,0x40,0x44,0xC7,0xBC,0xC2,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0x51,0x35,0x9F,0x70,0x5A,0x58,0xCF,0x31,0xAC,0xDF,0x5A,0x12,0x56,0x09,0x4E,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0x13,0x10,0x3E,0xF8,0x61,0x1E,0x35,0x5A,0xF3,0xBB,0xEB,0x3B,0x20,0xB0,0x65,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA0,0x20,0x0A,0x04,0x1C,0x0B,0x1E,0x39,0x93,0xEE,0xD4,0xDE,0x1A,0x61,0x69,0x7F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0xA3,0x80,0xAE,0xEB,0xA6,0x4B,0xCD,0xFF,0x78,0x13,0xD5,0x97,0x13,0xFD,0x5C,0x28,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x39,0x1C,0x1E,0x47,0xCD,0x05,0x7E,0xB6,0xB0,0xB2,0xEB,0x9C,0xB4,0xF2,0x48,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0xAB,0x27,0xED,0x49,0x6C,0x29,0xC7,0x79,0x3C,0x8D,0xC0,0x0B,0xF9,0x43,0xCC,0x89,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0xCF,0x46,0x8C,0xA9,0x05,0x7E,0x12,0x62,0xF1,0x89,0xD8,0xF8,0x96,0x4D,0x54,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xD6,0x1D,0xEF,0xFE,0xA2,0x88,0x56,0xD3,0x7B,0x8E,0x5D,0x69,0x62,0x5E,0x1C,0xA7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x16,0x7A,0x43,0x3F,0x76,0xE4,0x7D,0x0D,0x77,0xAF,0xC4,0xA9,0x63,0xAA,0x26,0x0B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0xE5,0xF5,0x83,0xBD,0x57,0xB7,0x41,0x93,0xF3,0xCE,0x60,0x3E,0xDB,0x0E,0x14,0x09,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0xE2,0x5E,0xA6,0xE3,0x65,0x7E,0x76,0x01,0xE6,0x9E,0xBD,0xC9,0x94,0x09,0xF2,0x32,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0x22,0x9E,0xB1,0x30,0x1D,0xE6,0xCA,0xF8,0xAE,0xDE,0x12,0xD9,0x0B,0x97,0x5A,0xC1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xCE,0x3B,0xC6,0xFF,0x98,0xE8,0x58,0x47,0x5E,0xD5,0x72,0x90,0x91,0xF9,0xC9,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x73,0x27,0x03,0x5D,0x7F,0x70,0xFC,0x13,0x5F,0x10,0x6F,0x55,0x79,0x7D,0x93,0xE0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x97,0x12,0xEE,0xF0,0x62,0x4B,0x1C,0x91,0xAD,0x0C,0xB6,0x40,0x74,0xFA,0x3E,0xA3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x5A,0x4D,0x88,0xCF,0x18,0x00,0x5C,0xA2,0x00,0xCB,0xD5,0xF1,0x3C,0x96,0xB1,0xC7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0xDF,0x50,0xCB,0x01,0x7A,0xC4,0x8D,0x04,0x47,0x48,0xEB,0x4F,0x50,0xB8,0x22,0x4A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x4C,0xA4,0x45,0x13,0x7E,0x08,0xB0,0x2D,0x97,0x5B,0xB2,0x7F,0x94,0x95,0x88,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0x18,0x95,0x32,0x80,0xC8,0xFA,0x52,0x50,0x92,0xBB,0x0F,0x65,0x4E,0x41,0xD0,0xAD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xDE,0x15,0xA7,0x9B,0xDC,0xEE,0xDA,0x63,0xE3,0xAE,0x3E,0xE7,0x1C,0x94,0x49,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xA8,0x9C,0xB8,0x60,0xEE,0x2B,0xD1,0x1B,0xB3,0xC9,0xBC,0x89,0x9A,0x87,0x89,0xFB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xF8,0xD3,0x97,0xBA,0x21,0xFC,0x41,0x7B,0x5B,0x2F,0xEE,0x9C,0x8D,0xDE,0x73,0xE8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0x75,0x40,0x9C,0xF9,0x56,0x30,0x91,0x60,0x6A,0x23,0x69,0x46,0x3B,0x13,0x3A,0xF8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x0F,0x06,0x44,0x30,0x4A,0x78,0x78,0xD2,0xCF,0xA7,0xC7,0xFE,0x72,0x37,0x70,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xF6,0xE5,0x0A,0x2D,0x4A,0x47,0x1E,0x2E,0x2B,0xEE,0x4A,0xA8,0x81,0xCD,0xCD,0x42,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0xFB,0xBB,0x63,0xEE,0x40,0x5B,0x7B,0x20,0x0B,0x8E,0xF3,0x1B,0x37,0x76,0x4D,0x31,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x3F,0x9E,0xEC,0x85,0x5B,0xA7,0xE4,0xCF,0x4C,0xCB,0x3E,0xCB,0xE4,0xFC,0xEF,0x08,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0xF6,0x1E,0xD2,0xB7,0x26,0xE3,0x68,0x20,0xBD,0xBF,0x85,0xE3,0xD0,0xDE,0x72,0xA1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0xC5,0x10,0xB6,0x47,0x40,0x27,0x17,0xE1,0xF6,0x27,0xC6,0xDC,0x3A,0x45,0xF3,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xA5,0xBD,0x56,0xF4,0x90,0x81,0xAD,0x32,0xA6,0x51,0x1F,0xD6,0x9E,0xF8,0xA5,0x67,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0xA1,0x5E,0xB9,0x69,0x12,0x67,0x53,0x06,0x3E,0x47,0x0A,0xB0,0xD0,0xDC,0x86,0x97,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xB1,0x8F,0x0C,0x2D,0xCE,0x18,0xF5,0xD1,0x43,0x89,0x62,0xF5,0xDF,0x3B,0x1B,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0x35,0xB7,0x8F,0x7C,0xAD,0x9C,0x4B,0x0A,0x9F,0x72,0x3D,0x10,0x8B,0x14,0x48,0x50,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0xFA,0x06,0x70,0xF3,0x54,0x58,0x18,0xE8,0xF0,0x0C,0x0C,0x9D,0xC8,0x9E,0xC4,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0xCE,0xCE,0xCB,0x20,0x56,0x21,0x91,0x96,0x03,0x1E,0xCD,0x8C,0x78,0x32,0x9C,0x71,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0xAA,0x6A,0xD0,0x08,0xF7,0xFE,0xFF,0x70,0xB9,0x39,0x78,0xBE,0x15,0x5A,0xA8,0x3F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0xE1,0x16,0xA1,0x68,0x44,0x64,0xF6,0xE8,0xE8,0x6A,0x14,0x7A,0x24,0xE4,0x8D,0x44,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xA5,0x1C,0x82,0x4E,0x68,0x9E,0xF3,0xB5,0x95,0xE4,0x76,0x84,0xF9,0x48,0x2B,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x69,0xEA,0xD9,0x6A,0x57,0xDD,0x29,0xE3,0x82,0x29,0xB6,0x7C,0xF4,0xF3,0x8D,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0xF1,0x94,0xB7,0x4D,0xE6,0xA3,0x8E,0x18,0x81,0x9D,0x8B,0x2C,0x7B,0xF4,0xA8,0x0F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0x5E,0xDE,0xC7,0xC9,0x9D,0xF6,0xFC,0x57,0x59,0x8C,0x9D,0x85,0x5B,0xF0,0x86,0xBF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0xAF,0x6A,0xE0,0x9C,0xA4,0x85,0x04,0x10,0x8D,0x54,0x8B,0x5B,0xC3,0x24,0x80,0x69,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0xAF,0x86,0xC3,0xA9,0x25,0x96,0xF1,0x7A,0xF9,0xAB,0x67,0xD6,0xC8,0xFC,0xCD,0xFC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x06,0xEA,0xC9,0xE4,0xA0,0x2B,0x53,0xDE,0x71,0x1E,0xAA,0x80,0x96,0x00,0xDA,0xDF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0x4F,0x14,0x5A,0xA2,0x34,0x20,0x05,0x17,0x3C,0xAF,0x8B,0x23,0x19,0x17,0x56,0x9B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0xB4,0xFD,0x5A,0x9B,0x95,0x6E,0x2B,0x9D,0x14,0x69,0xD9,0x24,0x2E,0x03,0x71,0xBD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x6B,0xE7,0xFC,0x04,0x69,0xE4,0x52,0x6F,0xAA,0xFF,0x80,0xBC,0xAA,0xB6,0x6E,0x48,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xFD,0x46,0x84,0xAD,0xFF,0x9F,0x04,0x4E,0xF9,0x3F,0x4D,0xB3,0xA8,0x67,0xBC,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0xBC,0x35,0xB8,0x29,0x4E,0x75,0x1F,0xDE,0xCA,0xAE,0x24,0x1A,0x09,0x3A,0x52,0x56,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x32,0x5B,0xA2,0x3C,0xCF,0x3D,0xEE,0xC7,0x11,0x46,0x10,0x28,0xEC,0x58,0x9C,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x8B,0x41,0x1A,0x94,0x3E,0x18,0xC2,0xBD,0xD3,0xFA,0x42,0x15,0xC7,0x05,0x3F,0xE4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0x2A,0x0F,0xC0,0xCD,0xD3,0x47,0x79,0x96,0xB1,0x4E,0xB9,0x4F,0x9C,0x6E,0x1B,0x2D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0x94,0x91,0x64,0x19,0x28,0xB6,0x06,0x8C,0x9E,0x9F,0xA9,0xEA,0x15,0x6E,0x87,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x3F,0xC9,0xA8,0xF6,0xFC,0x90,0x3D,0x95,0x1C,0x55,0xEA,0x1F,0xD6,0xE8,0xEB,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0x57,0x6B,0x70,0x91,0xBB,0x9D,0xDD,0x43,0x3C,0x2B,0x6E,0xE2,0x61,0x81,0xB2,0x6B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x8B,0x81,0x8C,0x7D,0xE3,0x17,0x82,0xD5,0x5B,0x19,0xF2,0xD9,0xD1,0xA9,0xCF,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x7E,0x2B,0x96,0x27,0xCC,0x13,0xBC,0x56,0x66,0x4E,0x88,0x53,0x29,0xD6,0x23,0x24,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x07,0x4F,0xAD,0x34,0xB2,0x86,0xAD,0xDC,0xB5,0x4C,0xC9,0x01,0x17,0x47,0x58,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xB7,0xCD,0x8E,0x33,0x7F,0xF3,0x84,0xA6,0xE7,0x68,0x29,0xA3,0xD1,0x1C,0x42,0xC6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x15,0x97,0x1D,0xEB,0x58,0xC9,0xF4,0x50,0x4F,0xED,0x1D,0xE9,0xF2,0x02,0xE7,0x77,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xF8,0xB2,0xD1,0x8C,0xEB,0x40,0x3C,0xD7,0xEC,0xD4,0x19,0xF6,0xF8,0xD4,0x81,0xE4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0x7E,0xA8,0xD1,0x1E,0xEC,0x25,0x6C,0xDD,0x39,0x4E,0x19,0xD8,0xB4,0x42,0xDD,0x19,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xB2,0xBD,0x8A,0x43,0xAB,0xF0,0x43,0xAF,0x70,0xAF,0xDA,0xA5,0xC1,0xE8,0x62,0x4C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0x93,0x0F,0xAA,0xA9,0x8F,0xCF,0x74,0xB9,0x82,0x99,0x77,0x23,0x4C,0x90,0xE5,0x8A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x1C,0x74,0x21,0x40,0xA6,0x67,0x40,0xAB,0x42,0x3A,0x0D,0x96,0x10,0x5C,0xF0,0xCD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x6E,0xB1,0x1E,0x87,0xF3,0x7B,0xA1,0x11,0x1F,0xC7,0x13,0xD2,0xB9,0x6C,0x9C,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x5D,0xC2,0xF5,0xBE,0xF5,0x84,0xFB,0x5B,0x8D,0x73,0xE2,0x85,0x06,0x46,0xF6,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x07,0xBC,0xCE,0x9F,0x64,0xDB,0x75,0x51,0xA7,0xBD,0xCC,0x45,0x04,0x61,0x0A,0x37,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x3E,0x27,0x22,0xD9,0xDB,0x6C,0x23,0xB9,0xE9,0x12,0x67,0x56,0x58,0x15,0x8D,0x4C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x33,0x89,0xDA,0x6D,0x9A,0x19,0x1F,0xC9,0x36,0xD6,0x90,0x2A,0xF7,0x4C,0xF3,0x1C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x7B,0x08,0x9C,0xAB,0xB4,0x25,0xDB,0x25,0x87,0x3B,0x0D,0x92,0x3B,0x68,0xFB,0x42,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0x52,0x69,0xF8,0x2F,0x5C,0xA2,0xD2,0xE7,0x89,0xC3,0x0D,0x93,0xD8,0x1C,0x98,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0xB6,0xF1,0xFB,0x5B,0x72,0x5E,0x72,0xA1,0xB7,0x2F,0x3D,0x0F,0x85,0x4A,0xC9,0xE1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x0D,0xD8,0x99,0x6E,0x19,0xC5,0x51,0x1B,0x10,0xF7,0xA5,0xE0,0xDD,0x91,0xFD,0xF0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x04,0x10,0x7B,0x2B,0xD9,0x9E,0xBD,0xE7,0xCC,0x95,0x48,0x0F,0xA3,0xC4,0x3B,0x68,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xC9,0xBC,0x10,0x0B,0x84,0x61,0xE6,0x1A,0xE8,0x38,0xF2,0x07,0x0D,0xEE,0x36,0xC2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0x1D,0x69,0x42,0x8D,0xE5,0x94,0x31,0x43,0x81,0x2F,0x32,0x79,0xC0,0x9C,0x1C,0xAF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x67,0xFA,0x87,0x65,0x87,0xDA,0x66,0xE8,0x0C,0xF7,0x93,0xAB,0xBF,0xBD,0xBA,0xB3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0xE9,0x10,0x2B,0x51,0x36,0xCD,0x84,0x2D,0x94,0xBA,0x05,0xC9,0x32,0xEF,0xEA,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0x4C,0x3B,0x79,0x0A,0xCB,0xF9,0x31,0x71,0x18,0x32,0x86,0x3F,0x7F,0x4D,0x98,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xBC,0x98,0xC4,0x94,0x5C,0xED,0xF2,0xCF,0x7D,0x7B,0xAC,0x99,0x1E,0x95,0x09,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0xDE,0xCD,0x0D,0x13,0x13,0x34,0x75,0xF5,0x2C,0x2B,0xFD,0x79,0x0D,0x16,0x6D,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0xD6,0xB4,0x57,0x26,0xAC,0x79,0x1C,0xF0,0x46,0x31,0x57,0xBA,0x18,0xA9,0xBC,0xC1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x09,0x10,0xF3,0x7F,0xCD,0x15,0x87,0x81,0x48,0x32,0x88,0xEF,0x5C,0xB4,0x64,0xB7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xF0,0x0A,0x71,0x63,0x53,0x47,0xF2,0x30,0xD6,0xA4,0x6D,0x40,0x95,0xFD,0xCF,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x28,0xDE,0x6A,0x46,0xE8,0x8D,0x7F,0xC8,0xE6,0x43,0x75,0x0A,0x6A,0xAE,0x02,0x19,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xF5,0x20,0xEA,0xBE,0xF6,0xD2,0x01,0x36,0x77,0x63,0xCF,0x3E,0x04,0x92,0x93,0xCD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x7F,0x3B,0x95,0xB7,0xE6,0x01,0x7C,0x2C,0x58,0x23,0x0E,0xCA,0xD5,0x03,0x90,0x91,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0xA6,0x84,0x43,0x27,0xB0,0x75,0x7A,0x7A,0x98,0x71,0x3A,0x83,0xA0,0x80,0x82,0xA3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x4A,0x9B,0xCF,0x30,0x5D,0xFE,0xEA,0x35,0x7C,0xA4,0xB3,0x43,0x5A,0xAF,0xBB,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0x97,0x95,0xB4,0x48,0x21,0x07,0xB7,0x06,0xAE,0x52,0x35,0x25,0x52,0x78,0xD7,0x4D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x57,0xA6,0xB7,0x54,0xE2,0x28,0x26,0x5E,0xEE,0x1A,0xD4,0xF9,0x50,0x00,0xC9,0xF9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x83,0x97,0xFB,0xFF,0x50,0xE2,0x7E,0xA0,0x5B,0x7D,0xD2,0x35,0x20,0xB5,0x30,0xBA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x97,0xE6,0xBF,0x12,0xAD,0x5F,0x0F,0x6D,0x12,0xCA,0xD0,0x2C,0x65,0xC7,0x00,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0x63,0xEC,0x59,0x00,0x8F,0x34,0x60,0x6C,0x02,0x98,0x29,0x38,0xC0,0x31,0x97,0xA3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x52,0x0A,0x7D,0x90,0xFA,0x3B,0x5F,0xF1,0x05,0x41,0x6D,0xF2,0x19,0xFC,0x57,0xD2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0x11,0x17,0x13,0x42,0xFF,0x3D,0xDF,0xDB,0x3C,0xFA,0xCD,0x52,0xCC,0x79,0x9B,0x45,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x0A,0xE3,0x83,0x2C,0xA1,0x1A,0x9F,0x5E,0x5E,0xAC,0x51,0x4D,0x7D,0xD6,0x85,0xCC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xD6,0x0D,0x6F,0x78,0xF1,0xF5,0x29,0xED,0x83,0xBF,0x17,0x36,0xC2,0x7E,0x13,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x91,0x31,0x98,0x91,0xC8,0x1D,0x5F,0x84,0xFA,0xF2,0x90,0x17,0xBA,0x5E,0xD4,0xCE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x51,0x3D,0x80,0x62,0x64,0x4E,0x5C,0x3D,0x01,0xC0,0x0B,0x28,0x1F,0x35,0xBA,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x85,0x81,0xC7,0xF5,0x3D,0xD8,0x74,0x64,0x83,0x14,0x6C,0x78,0xB9,0x90,0xBA,0x4D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x35,0x8B,0x1D,0xFC,0x42,0x81,0xAE,0x80,0x58,0x03,0xAA,0x1A,0x88,0x61,0xCA,0xC1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x56,0x73,0x6A,0x97,0x05,0x47,0x8B,0x5D,0xF7,0xA0,0x3D,0xD5,0xFC,0xDD,0xE8,0x2F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xCE,0x2C,0x0F,0xF9,0x0F,0xB9,0xF2,0x8F,0x59,0x0F,0x64,0xD5,0xD6,0xD5,0x80,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0x34,0x09,0x1F,0x27,0x13,0x63,0x16,0xC5,0x1C,0x2F,0xA8,0x60,0xDA,0xD5,0x65,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xB2,0x98,0x57,0x29,0x88,0xD7,0x38,0xC9,0xC4,0xFD,0x77,0xBF,0xEF,0x86,0x51,0x00,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x96,0xBC,0x2D,0xC3,0x6F,0x2A,0xB2,0x2A,0x6B,0xD9,0x25,0x5E,0x54,0xED,0x0E,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0x43,0x03,0x3B,0x73,0x74,0x01,0x9C,0x54,0x64,0x12,0x81,0xD9,0xAF,0x04,0x7D,0x07,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0xDD,0x19,0x5D,0x6A,0x4A,0xBC,0xA3,0xA3,0x83,0xC6,0xCC,0x51,0x97,0xB0,0x73,0x19,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xF3,0x79,0xFD,0x4E,0xBB,0xAC,0x2F,0xAD,0xBA,0x42,0xE0,0x56,0xA5,0x38,0xD4,0xCE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x56,0x83,0xCE,0x88,0x73,0x1C,0x52,0x6A,0x9A,0xB6,0xF0,0x73,0xF5,0x50,0x67,0xB3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0x34,0x1D,0x39,0xFE,0x50,0xB3,0x07,0x1F,0x32,0x1B,0x80,0x5E,0x66,0x14,0xE4,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x19,0x7D,0x79,0x6C,0x31,0xEA,0x18,0x99,0x06,0xEB,0x8E,0x57,0xCE,0x01,0x28,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0x09,0x15,0x25,0xFB,0x50,0x4F,0xBF,0x00,0xF4,0xF9,0x90,0xB1,0x6D,0xDF,0x15,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x3F,0xE3,0x18,0xFF,0xFD,0xFA,0x5A,0xC5,0xD6,0x45,0xEA,0x1D,0xF4,0x26,0x96,0x9E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xD7,0xA1,0x12,0x72,0x04,0x80,0x12,0xFC,0xF2,0x93,0x89,0x3A,0x56,0xBF,0x87,0x53,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0x07,0x7E,0x87,0xDA,0x57,0x36,0xB8,0x87,0x20,0x9C,0xAD,0xEC,0x5D,0x93,0x9C,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0xDF,0x0B,0x34,0xF8,0xB7,0x98,0xD9,0x30,0x68,0x8F,0x7B,0xEA,0xA9,0x7B,0xBE,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0xB9,0x31,0x95,0xCE,0x2A,0x47,0x6E,0xB8,0x00,0x2A,0x98,0x77,0xD6,0xD9,0xF6,0x3C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0x02,0x88,0x8A,0xCC,0x58,0x79,0x53,0x43,0x50,0x2A,0xEF,0x52,0x7B,0x45,0xA2,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0xD6,0xC5,0x89,0xD6,0x8D,0x78,0xCE,0xC2,0x51,0x82,0xC4,0xA7,0x70,0x9B,0xC6,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0x47,0xB0,0xD9,0xA2,0x9F,0xCF,0x81,0x09,0xFE,0x6B,0x87,0x73,0x81,0x61,0x22,0x38,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0x54,0x2F,0xAB,0x71,0x53,0x13,0xE5,0x98,0x21,0x64,0x1E,0x3E,0x97,0xD0,0x31,0x54,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0x92,0x79,0x8B,0x75,0xC5,0x52,0x50,0xAC,0xB3,0x2A,0x69,0xA1,0x51,0x64,0x34,0x49,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x8B,0x44,0xB4,0xAC,0x79,0xC3,0xF9,0x6D,0xFE,0xED,0x6F,0x1F,0xA4,0x2A,0x4F,0xEB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xA2,0x8B,0xD0,0x99,0x16,0xFA,0xCA,0x07,0x0D,0x66,0x62,0x6D,0x10,0x92,0x4B,0xCB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x08,0x21,0xBC,0xEE,0x59,0xCA,0x7A,0xB1,0x27,0x3D,0x26,0x3B,0x7D,0xFC,0x31,0xE6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x3D,0xF9,0x72,0x0E,0xAB,0x82,0x06,0x31,0x28,0x24,0x6B,0x0C,0xE4,0x06,0x3B,0x82,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x65,0xF7,0xDE,0xE8,0x4A,0xFE,0xD4,0x70,0x0A,0x58,0x07,0x27,0x58,0x16,0x54,0x2D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0x49,0x1F,0x7F,0x08,0x47,0x80,0xA0,0x82,0xCE,0xF3,0xFA,0x38,0x67,0xAE,0x65,0xCF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0x20,0xFA,0x32,0x3E,0x1B,0x21,0x8A,0x31,0x95,0x7A,0x9C,0x33,0x0B,0x47,0x97,0xBB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1B,0xC3,0xDB,0x95,0xCC,0x94,0x1F,0x37,0xA6,0x5D,0x03,0xAF,0x50,0xAD,0xE3,0xE1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x28,0x12,0xF9,0x24,0xB1,0xC6,0xE9,0x1F,0x13,0xE4,0x79,0x24,0xC9,0x01,0xCF,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0x01,0x88,0x62,0xCB,0x80,0x17,0xF0,0xA1,0x06,0x98,0x0C,0x72,0xEA,0x93,0xBE,0xCF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0xB6,0x12,0x6B,0xF3,0x05,0x50,0x0E,0xBA,0xCD,0x2C,0xA6,0xBF,0x25,0x37,0x5F,0x22,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x90,0x5A,0xAA,0x86,0x27,0x8A,0xDC,0x72,0x58,0x9D,0xF0,0x78,0x96,0xF7,0xBC,0xCC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x55,0x45,0xD3,0x35,0xEA,0xC6,0x17,0x56,0x88,0xEF,0xC9,0xCD,0x01,0xF7,0x81,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x36,0xE6,0x9D,0xCA,0xDC,0xE5,0x1F,0x6E,0x4E,0x29,0x44,0x06,0x38,0x09,0x00,0x18,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x8D,0x9E,0x27,0x12,0x15,0xB1,0x91,0x9D,0xA5,0x64,0xBC,0x80,0x7A,0x7F,0x35,0x51,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0x7D,0xF3,0xFC,0x19,0x7E,0x36,0x53,0x9F,0xA9,0xE0,0x85,0x32,0x1E,0xD5,0xB8,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xAD,0xE7,0xA7,0xBC,0xB3,0x1D,0xF4,0x68,0x0B,0x55,0xEA,0xF3,0xB6,0x97,0x55,0x0B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xC2,0x6C,0xF7,0x7E,0x51,0x12,0x2D,0xCC,0x3A,0xA4,0xF3,0x84,0x94,0xD8,0x55,0x90,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0xB4,0xD2,0xC4,0x83,0x26,0x21,0x03,0x50,0x03,0xC4,0x45,0x33,0x2E,0xC1,0xB5,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0x0B,0x62,0x55,0xC2,0x8F,0x23,0x97,0xF7,0x6D,0xD5,0xA2,0x3E,0xB6,0xD4,0xD5,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0x2E,0xEB,0x3F,0x91,0x56,0xDB,0x16,0x0C,0x46,0x18,0x1C,0x4D,0xF7,0xA1,0xFA,0xE2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0x43,0xB2,0xC8,0x90,0xF5,0x5A,0x45,0xA0,0x80,0x41,0xFD,0x82,0x09,0x08,0x3F,0x0C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x46,0xC6,0xCB,0x92,0x2E,0xB8,0x0D,0x48,0x03,0x2B,0xB5,0xA2,0xEB,0xC6,0x14,0xA6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0xB7,0x42,0x96,0xAB,0xC3,0xBE,0x3F,0xC2,0xB1,0xCF,0x9C,0xCC,0x12,0x09,0x70,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xF6,0x1F,0x86,0x93,0x22,0xB8,0x94,0x1C,0xFE,0xD5,0x1C,0xE0,0x30,0xEA,0xA3,0x42,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0x4B,0xA7,0x2D,0xC1,0x77,0x77,0x5D,0x1E,0x53,0x29,0xE0,0xD7,0xBC,0xC6,0xE8,0x26,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0xDF,0x1D,0x2B,0x63,0x89,0x8F,0x83,0x03,0xB8,0x23,0x2E,0x06,0x52,0x83,0x12,0xBA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x2A,0x93,0x4D,0x08,0xA2,0x0D,0x64,0xB3,0x30,0xB2,0xA7,0x5B,0x6D,0xA8,0x7B,0x64,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0xD4,0x5C,0x02,0x52,0xB2,0x5D,0xC9,0xA9,0x9C,0x83,0x12,0x48,0xFC,0xEB,0xAE,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xA3,0x44,0x8F,0xAF,0x22,0x3F,0xE1,0x6E,0x80,0xB3,0x71,0x34,0x88,0xB8,0x28,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x0B,0x5B,0x96,0x72,0x15,0xC1,0x75,0x12,0x7C,0x49,0x59,0x69,0xCC,0x62,0xF2,0xF3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0x71,0x1D,0x41,0x0D,0xC3,0xFD,0xB6,0xEC,0x4C,0x8B,0x4F,0x29,0xD2,0x2F,0x17,0x5E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xB9,0xCA,0x86,0x8E,0x65,0x1F,0xCE,0x42,0x26,0xB0,0x51,0x64,0xF1,0x4F,0x20,0xF8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x9A,0xB8,0x10,0x7B,0x62,0xB9,0xC0,0xBC,0x4F,0x2D,0xEA,0x25,0xE1,0x11,0x98,0xBF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x47,0xD8,0x2B,0xA7,0xA0,0x47,0xC5,0xA5,0x35,0xCA,0xD2,0x52,0x14,0x3D,0x03,0x87,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x40,0x96,0xE3,0x72,0x6B,0x29,0x99,0x80,0xB7,0xD7,0xE8,0x94,0xE9,0x57,0x99,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0x8D,0x20,0x16,0x04,0x2C,0x59,0x6F,0x53,0xD0,0x4D,0xF3,0xE2,0x26,0xFE,0xC1,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xD5,0xAB,0xBD,0xE6,0x1E,0x78,0x30,0xED,0xAF,0x1C,0xE1,0x22,0x36,0x53,0x86,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x4E,0xBF,0x54,0x26,0xBC,0x53,0x22,0x9E,0x8B,0x59,0xF3,0x82,0x7E,0x86,0x7B,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x8F,0xF5,0x9E,0x3E,0x15,0x92,0xE5,0x85,0xF3,0x34,0x51,0xA8,0xF5,0xCC,0xC0,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x51,0x34,0xFE,0x6C,0x5E,0xDF,0x68,0x0B,0x88,0x43,0xA7,0xF9,0xE0,0x63,0x5F,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x82,0x39,0x29,0x35,0xFD,0x80,0x69,0x23,0xB3,0xC4,0x02,0xBC,0x17,0x2E,0x9C,0x6E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0xEF,0x00,0xF4,0x7D,0x90,0x54,0x8C,0x13,0xF5,0xB0,0x02,0x8F,0x7A,0xE8,0x89,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xF2,0xCA,0x81,0xF4,0x2B,0x58,0x2D,0x48,0x19,0x93,0x33,0x46,0xA6,0x50,0x64,0x64,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEE,0x92,0xE5,0x93,0xCE,0xF3,0xF5,0xF1,0x44,0xC7,0x67,0x7B,0x7C,0x79,0xC1,0x83,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0xE9,0xCF,0x11,0xCA,0xEE,0xEB,0x9C,0x2C,0xAF,0x9E,0xEE,0xA2,0x04,0xFD,0x6C,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xC3,0x2B,0x8E,0x7A,0x22,0x5A,0x8C,0xB9,0x25,0x4F,0x1B,0x31,0xC0,0x12,0x34,0x7A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0F,0x2B,0x47,0x78,0x6E,0xFF,0x41,0x42,0x18,0x1E,0x56,0x21,0xEF,0x82,0x39,0x09,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xA5,0xBB,0xD0,0xAA,0xA7,0x3D,0xF3,0x39,0xD1,0x9E,0x86,0xF1,0x78,0xC0,0x85,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0xB8,0xEB,0xFE,0x4A,0x3F,0x7E,0x5C,0x3C,0x80,0x77,0x27,0xF7,0x7E,0x92,0x10,0xE7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0xF2,0x4A,0xF6,0x1C,0x80,0x3A,0x22,0x72,0x33,0xE9,0x97,0xEC,0xDC,0x69,0x6F,0xFB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xDD,0x48,0x85,0x7A,0xFA,0xA5,0x24,0x36,0x53,0x10,0xA7,0x38,0xE2,0x5E,0xD1,0xBF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x5F,0xF5,0x19,0x07,0x9C,0x21,0x5C,0x35,0x44,0x87,0xD8,0x29,0x13,0x99,0x5D,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x06,0x39,0xAE,0x4E,0xE7,0x04,0x31,0xEC,0x29,0xF0,0x46,0x12,0xFD,0x2F,0x3E,0xAD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0x34,0x72,0xA8,0xB1,0x0B,0x7F,0x93,0x4B,0xF2,0x7A,0x91,0x02,0x88,0x96,0x63,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xAA,0x02,0x93,0xBC,0xAD,0xBB,0x18,0x34,0x65,0x5F,0x47,0xDA,0x3C,0x0B,0x6A,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x10,0x62,0x2F,0x18,0xB7,0x97,0xD2,0x28,0x0F,0xF9,0xEA,0x1F,0x8A,0xFB,0x00,0x41,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0xB2,0x13,0xDD,0x19,0x43,0xAC,0x37,0x32,0x6C,0xD5,0xB3,0xF3,0xF8,0x67,0xFB,0x83,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0x39,0x48,0x79,0x63,0x28,0x2D,0x9D,0x56,0x29,0x33,0x4C,0x20,0x8B,0x40,0x8C,0xC0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0x4B,0x3E,0xE0,0x18,0xEC,0x10,0xCD,0xD1,0xFF,0x11,0xB7,0x72,0x41,0xEF,0xE5,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x91,0xEF,0x34,0xD0,0x74,0xE6,0x85,0x93,0x32,0xAD,0x16,0x6F,0x9A,0x0C,0x31,0x87,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0xE2,0xF9,0xFB,0x7E,0x47,0x6E,0x3E,0x6C,0x19,0x95,0xC5,0x3E,0xB7,0x02,0xA3,0xE1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0x8D,0x00,0xB8,0x3E,0xA9,0xB5,0x28,0x33,0x7B,0x86,0x42,0xED,0xD8,0x88,0xC1,0x97,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x0F,0x22,0x65,0x69,0x20,0xD7,0x21,0x55,0x88,0x23,0x69,0xBD,0x48,0x55,0xC9,0xB5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x04,0x0C,0xB9,0x5B,0xFA,0x55,0x16,0x98,0xF8,0x88,0x78,0x05,0x49,0x5D,0x7A,0x7E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0xB8,0x8F,0x3E,0x01,0xAD,0x0E,0x0E,0xDE,0xD7,0x7C,0x81,0xAF,0x8A,0x24,0xF5,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0x11,0xF5,0xAF,0x2C,0x11,0x85,0x10,0xBD,0x8D,0xA2,0xA7,0x5A,0x6E,0xE7,0xCE,0x4D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0xC6,0x4F,0x30,0x16,0x58,0x59,0x7A,0x78,0x74,0x26,0x57,0x19,0x09,0x49,0xDB,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x2F,0xA9,0x31,0xCB,0xDF,0xE9,0x8C,0xB6,0xD5,0x61,0x63,0xC0,0x8E,0x8E,0xC5,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0x0B,0x2A,0xA6,0x84,0xBA,0x51,0x35,0xDB,0xE8,0x3E,0x37,0x3D,0xB5,0xB5,0x0B,0xBA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0x17,0xFD,0x4D,0x18,0x77,0x6D,0x7F,0x2B,0x98,0xA2,0xBE,0x5F,0x73,0xB0,0x1F,0x17,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0xF9,0xA7,0xA4,0x99,0x5B,0xD0,0x84,0x00,0x88,0x85,0xF8,0x01,0xBD,0xAB,0x82,0x3D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0xCD,0xBC,0x34,0x80,0x41,0x25,0xDA,0x3F,0x83,0x39,0xDB,0x78,0x92,0x89,0x09,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0xF9,0x17,0xF4,0x78,0x9A,0xA1,0xE1,0x8B,0x21,0xF0,0x7A,0xAB,0xEB,0x78,0x8C,0xD7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0xC5,0x31,0x8D,0xE8,0x88,0x23,0x7B,0x79,0x37,0x67,0x61,0xB2,0xF4,0xE8,0xFF,0xD4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xAD,0x62,0xE1,0xFD,0xC5,0x4A,0xF1,0x4E,0xE8,0x63,0x9C,0xBC,0x91,0xA0,0x08,0x48,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xA5,0xB2,0xD3,0xF0,0x27,0xA3,0x20,0x72,0x42,0x0B,0x68,0x2A,0x9A,0x45,0xEA,0xF1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0x2C,0x16,0x78,0x66,0xE7,0x37,0xFA,0xDD,0xBD,0xE4,0xF4,0x91,0xAB,0xA2,0x57,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x42,0x6F,0xA1,0xF6,0x7A,0xAE,0xEA,0xCB,0x89,0x6B,0x91,0x4C,0x7C,0xB6,0xFB,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0x2B,0x60,0x1B,0x3B,0x3A,0xB7,0x40,0xD2,0xF8,0xF9,0x9C,0xD6,0x1F,0x08,0xE0,0xBB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x9C,0x98,0x20,0x6A,0xA0,0x48,0xF2,0x7E,0x85,0xF5,0x68,0xEE,0x42,0x07,0xDE,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x97,0xA0,0xFD,0x1D,0x07,0x2C,0x2C,0x4D,0xA2,0xCF,0x35,0x84,0x36,0xDE,0x78,0xF7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x91,0x0F,0xDB,0x79,0x88,0x4C,0x25,0x03,0xEA,0x0B,0xA5,0xB9,0x1F,0x2F,0xE3,0x1B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x06,0x3C,0x8B,0xD4,0x79,0xD6,0xF0,0x73,0x0E,0xB2,0x09,0xE8,0x1A,0x02,0x6F,0x75,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0x68,0x92,0x3C,0xBF,0x6F,0xAB,0xE0,0xEE,0xB1,0xA7,0x63,0xAD,0xBA,0x20,0x71,0xB2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xEE,0x75,0xCB,0x8E,0x36,0xD1,0x92,0x74,0x61,0xBD,0x30,0xCF,0x90,0xA8,0xA1,0x9D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0xD5,0x29,0x9A,0xEB,0x65,0x27,0x24,0xD9,0x6C,0x92,0x31,0xB9,0xF9,0xD1,0xB4,0x9B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0xF9,0x84,0x4F,0xA1,0x6A,0x48,0x1F,0x61,0xE5,0xA5,0xEA,0x46,0x25,0x27,0x0C,0x06,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0x48,0xBF,0xBA,0xEF,0x0B,0xAD,0x65,0x6A,0x62,0xD9,0x32,0xFF,0x3B,0xA2,0xAD,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0x9A,0x1E,0x7B,0x47,0x2D,0xE4,0x98,0x5A,0x1E,0x41,0x75,0x38,0x30,0xA3,0x8A,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0x93,0x1A,0xB9,0x1D,0x80,0x2A,0xFB,0x98,0xF0,0xB1,0xE1,0x1E,0xBB,0x4A,0x34,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0xED,0x88,0x7F,0xB8,0xDA,0xA3,0xA3,0xFD,0x01,0x2D,0x6D,0x96,0xB6,0x7D,0xBA,0x54,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xE8,0x95,0x39,0x77,0x20,0x80,0x91,0x79,0x8C,0xD5,0x73,0x29,0xAA,0xB7,0xAE,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0xF1,0x8D,0xAB,0x3B,0x71,0xE2,0x01,0xBF,0xE3,0xB6,0x10,0xF7,0x3C,0xC3,0xF6,0x77,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0xBD,0x7D,0x6D,0x29,0xBB,0x34,0x04,0x3F,0x53,0xC6,0x87,0xBF,0x2C,0x5B,0x5A,0x52,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x23,0xCC,0xC0,0x9C,0x82,0x33,0xDF,0xBF,0x38,0x4F,0xA6,0xA3,0x46,0xE2,0x1E,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0x33,0x1B,0xBE,0x37,0x74,0x2E,0x30,0x16,0xC2,0xC9,0xD0,0xF9,0xDF,0x43,0x1D,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x9F,0x25,0x1D,0xD4,0x39,0xBE,0xEE,0x90,0x59,0x58,0x24,0xC8,0x03,0x04,0xE7,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x31,0xFB,0xC5,0x45,0x5A,0xED,0xA6,0xF2,0xFC,0xB5,0xB1,0xB0,0x53,0xBD,0xC3,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x11,0x74,0x06,0xE5,0x19,0x4A,0xB1,0xE1,0x28,0xA7,0xBF,0xAB,0x05,0x07,0x77,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x1A,0xC4,0x58,0x4A,0x0B,0x16,0xCB,0x56,0x57,0xC4,0x7F,0x7A,0x6A,0x47,0x5B,0x03,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x19,0xC7,0x0C,0x09,0xF9,0x23,0xF6,0xFF,0x80,0x27,0x32,0xF4,0xEF,0x8E,0x9E,0x28,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x4B,0x03,0x09,0xD6,0x3D,0x9F,0x28,0x90,0x2F,0x4B,0x39,0xB6,0x37,0xDF,0x12,0xD0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0x66,0x57,0xD7,0x91,0x3A,0x7A,0xE0,0x44,0x16,0x77,0xB9,0x11,0x4B,0xEF,0x80,0x39,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x6D,0xE1,0xB0,0x5C,0xB3,0x6F,0x95,0x46,0xD5,0xDE,0xDD,0xE3,0xF5,0xEA,0x64,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x61,0xD4,0xE9,0xD5,0x55,0x8F,0x23,0xCE,0x18,0xC0,0xCF,0x15,0x21,0xAD,0xD7,0xE8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0x75,0xA1,0x3D,0xE7,0xD4,0x6B,0x1D,0x43,0x3B,0x4C,0x6C,0xF0,0xBC,0x20,0x24,0x8F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0x7F,0x68,0x0A,0x04,0x80,0x0F,0x46,0x3C,0x68,0xEB,0x08,0xA7,0xEC,0x3F,0xBA,0x8A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x62,0x15,0x7D,0x6E,0x29,0x8F,0x7A,0xFC,0x41,0xE5,0xE0,0x7D,0x59,0x5C,0x92,0x06,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0x34,0xF1,0x7B,0x9E,0x53,0xC0,0xD3,0x1B,0xB0,0x09,0xF1,0x8B,0x61,0x3C,0x41,0x7B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x7B,0x3C,0x76,0xC8,0x94,0x77,0x09,0xF0,0xAF,0xC7,0x1A,0x1A,0xB0,0xED,0x06,0x4C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x76,0x96,0xD4,0xDF,0x2B,0x10,0x66,0x99,0xAA,0xAB,0xA7,0x8E,0xDB,0x92,0x82,0xCC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0x78,0x4B,0x8C,0x10,0x84,0xCC,0xB2,0xCB,0x4C,0x4D,0x7B,0xAD,0x92,0x47,0x3E,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x4F,0x18,0xD9,0x8E,0xA2,0x8F,0x34,0x4C,0xDA,0xA7,0xDC,0x10,0x1F,0xD7,0x55,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0xCB,0xDD,0x53,0xB1,0xA6,0xC4,0xC2,0x81,0x10,0x31,0xE7,0xD9,0xFF,0x43,0x30,0x9E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xA7,0xCF,0x7A,0x0C,0x7E,0x8E,0xD5,0x72,0x96,0xDA,0x92,0xA0,0x5A,0xF7,0x59,0xF3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0x7D,0x2B,0xF8,0x86,0x0B,0x99,0xD3,0x4B,0xE6,0x40,0x82,0x8D,0x3F,0xA6,0x4D,0xB3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0xE8,0x08,0x98,0x82,0x7A,0xBC,0xAF,0xE2,0x70,0x7D,0x61,0x1E,0x94,0x46,0x55,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0xFC,0x9E,0x5B,0x49,0x4F,0xA3,0x04,0x82,0x19,0x48,0x0F,0xF9,0x8E,0x8C,0x61,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x25,0x8C,0x36,0xAE,0xFF,0x25,0xF9,0x90,0xFB,0x6B,0x23,0xCA,0x3C,0xAC,0xA3,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x96,0x2F,0x52,0x66,0xE9,0xB3,0x91,0xE8,0xA3,0x08,0xCE,0x4F,0x21,0x3D,0x2F,0x51,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0xF5,0xF6,0x0F,0x61,0x54,0x24,0xD9,0x27,0xCF,0xB1,0xC0,0x2B,0xD0,0x34,0x21,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x4A,0x2F,0x64,0x1B,0x62,0x8F,0x18,0x30,0xBD,0xE4,0xF3,0xAE,0xF0,0xBB,0x6F,0x84,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0x90,0xEE,0x7B,0xCB,0x3D,0x5E,0x84,0x40,0x2B,0xF9,0x85,0xFB,0xE2,0x17,0x6A,0x15,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x9B,0x00,0x5C,0xE1,0x4A,0xA8,0x06,0xAE,0x7C,0x7A,0x5A,0x9D,0x69,0xC8,0x42,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0xB9,0x80,0x07,0x2E,0xF1,0x04,0x93,0xDB,0xC8,0x40,0x81,0x15,0x60,0x2F,0x56,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x70,0x44,0xAA,0xA0,0xED,0xAF,0x0A,0x2B,0xA1,0xF5,0xFF,0x51,0x46,0x7A,0x72,0xF8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x23,0x0F,0xC3,0x18,0xDB,0x27,0x11,0xF0,0x2E,0x4A,0xE7,0x7E,0x44,0x5A,0x56,0x71,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xD4,0xA9,0x82,0xA1,0x0C,0x32,0x70,0xEC,0x91,0x80,0x5E,0x75,0x94,0x43,0x49,0x17,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0x60,0x92,0x86,0x13,0x0C,0x3C,0x47,0xA5,0xBB,0x2F,0x1F,0xBE,0x0D,0xCB,0x93,0x47,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x9B,0xD5,0x82,0x4B,0xBD,0x6E,0xEC,0x45,0x5D,0x8E,0x38,0xF5,0x82,0x5F,0xA6,0xA1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xBB,0x5C,0x05,0x6B,0xCF,0x13,0x5F,0x28,0x87,0x21,0x72,0xA8,0x7F,0xA0,0x8F,0x41,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0xE6,0x69,0x86,0xCD,0x51,0x77,0xD6,0xDF,0x24,0x73,0xA7,0x49,0xCC,0x1E,0xAE,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0xBB,0xEB,0x64,0x1F,0xE3,0xFF,0x1C,0x8B,0x90,0x3F,0xA8,0x51,0x8F,0x5A,0xC3,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x32,0xDD,0x64,0x41,0x0F,0xD7,0x5D,0x52,0x3A,0x4E,0x9B,0x8E,0xFF,0xE3,0x1F,0x48,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0x89,0x49,0xF5,0x80,0x9E,0xCE,0x41,0x7B,0x7E,0x29,0xF0,0x0B,0x5A,0x52,0x5E,0xB2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0xF2,0x0A,0x56,0xD3,0xDC,0xBE,0xCC,0x87,0x7D,0x7F,0xAC,0xBD,0x92,0x8F,0x43,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xB7,0x50,0x24,0x94,0x52,0x8B,0x51,0x60,0xF5,0xEE,0x0F,0x65,0xC3,0xA7,0xB4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x09,0x68,0xEF,0x7B,0x7A,0x79,0xF7,0x07,0xDC,0x10,0x42,0x88,0x6F,0x7A,0xFD,0x89,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0x8A,0x38,0x32,0xAC,0x71,0x7E,0xBB,0x23,0x06,0x99,0x53,0x0B,0x71,0xA6,0xA1,0x64,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x03,0x27,0x67,0x83,0x30,0xD3,0xCA,0x4D,0xC1,0xCA,0xB3,0x1C,0xBB,0x03,0xA0,0x4B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0xB1,0xDB,0x1C,0xAC,0xDF,0x0B,0xF9,0x9B,0xE5,0xCF,0x76,0x9F,0xCD,0x0E,0x94,0x06,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0xE1,0xF0,0xDB,0x71,0x7C,0x6D,0x2B,0x54,0x16,0x46,0xC4,0x3A,0x09,0x72,0x23,0x1C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xAF,0x2A,0x40,0x69,0x1C,0xFE,0xD4,0x20,0xB3,0xFA,0x85,0x4B,0xC2,0x9B,0x2E,0x28,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0x6E,0xE3,0x2C,0x0B,0xA9,0x03,0x15,0xFC,0x14,0x65,0x4B,0x19,0x89,0x96,0x24,0xBD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xB5,0x90,0x52,0xA3,0x34,0xC0,0x7D,0x08,0x2E,0xA1,0x80,0x21,0x11,0xCF,0x5B,0x98,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x0A,0xD4,0x80,0x1D,0x71,0xCB,0x90,0xEF,0xC3,0x41,0x8B,0x17,0x28,0x89,0x99,0x0E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x75,0x16,0x1D,0x7A,0xD1,0x38,0xDD,0xDD,0x2B,0x0F,0x11,0x51,0x7E,0xA7,0x2B,0x25,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x4D,0xBE,0x27,0x7E,0x4D,0x96,0x33,0x78,0x24,0x2E,0x2C,0x1B,0xE6,0xF4,0x8E,0x1B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x6C,0xA9,0xD4,0x0E,0xB3,0xD3,0x28,0x33,0x98,0x34,0x3C,0xD5,0xDD,0x3D,0x25,0x0D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x47,0x83,0x3A,0x40,0xEE,0x4F,0x87,0x79,0xED,0xF7,0xB6,0x8B,0xF7,0xE8,0x54,0xB9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0xB0,0x57,0xCE,0x7A,0xCC,0x2C,0xC7,0x22,0xD4,0x69,0x15,0x88,0x50,0xC4,0x9E,0x43,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0x14,0x8F,0x48,0x0A,0x99,0x73,0x96,0x6B,0x85,0x28,0x3F,0x42,0x4B,0xD8,0x7D,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0x5A,0xF0,0xC6,0xAF,0x45,0x82,0x41,0x9A,0x33,0x17,0x17,0x6F,0x88,0x7B,0x67,0xB1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0xC1,0xC6,0xA4,0x0C,0x6F,0x49,0xD4,0x56,0xA7,0xDB,0xE5,0x07,0x51,0xBB,0x56,0x3C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xF7,0xA5,0x07,0x0D,0xAB,0xBC,0x60,0x11,0x35,0xFF,0x21,0x83,0x61,0xB2,0x8B,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0x08,0xFC,0x55,0xF8,0x53,0x04,0x03,0x3A,0x93,0xE2,0x87,0xA6,0x70,0xB9,0xBF,0xDD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0x73,0x99,0x1D,0x15,0x89,0x28,0xB1,0x1C,0x80,0x28,0x5D,0x6B,0xAC,0xCE,0x5A,0x28,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB1,0xCC,0x48,0xA7,0xDC,0x27,0x98,0xE9,0x64,0x8A,0x78,0xE8,0x16,0x5C,0xD9,0x74,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0xDE,0x7E,0x9E,0xCC,0x5F,0x35,0xC1,0x01,0xCD,0x96,0xFA,0x17,0x2E,0x5F,0xD4,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x92,0x1C,0xB5,0xEF,0x3B,0xF1,0x7D,0x95,0x39,0x63,0x68,0xC0,0x6F,0x1A,0x10,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xAC,0x57,0x81,0x6F,0x16,0xE9,0x71,0xDE,0x6A,0x49,0xD8,0x70,0xF0,0x25,0x88,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0x0D,0x7B,0x3D,0xB8,0x95,0xB2,0x88,0xF4,0x39,0xBA,0x4B,0x74,0x13,0xD5,0x5B,0x5A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0x60,0x04,0x7C,0x01,0x5E,0xFB,0xC3,0xC3,0xD8,0xFB,0xE2,0x7D,0x53,0x71,0xE4,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x8A,0x9D,0xFD,0x1B,0x2B,0xDA,0x76,0x8B,0x38,0x0B,0xA4,0x12,0x4A,0xD2,0xFB,0xF0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0x98,0xA7,0xD4,0x99,0x68,0x2D,0xC1,0x87,0x98,0x64,0x90,0x05,0x64,0x0A,0x83,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0xEA,0xC1,0x20,0xBE,0xF5,0xA9,0x4A,0x6E,0xDA,0xCB,0xC4,0xF4,0xF1,0x84,0x01,0xF0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x56,0x04,0xA8,0x14,0xAC,0x60,0xD0,0xEF,0x6A,0x85,0x81,0xCD,0x5D,0xBB,0x73,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x46,0x7F,0x3C,0xFA,0x2A,0xDB,0x8C,0xBA,0xE0,0xB2,0x72,0xC0,0x79,0x00,0x62,0x3F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x12,0x57,0x02,0xF4,0xF6,0x8C,0xB3,0x21,0x49,0x2B,0xC1,0x47,0xDC,0x90,0x2F,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0x60,0x05,0x7B,0x93,0x7A,0x5E,0x00,0x2F,0x48,0x8D,0xC1,0x9D,0xB7,0xB0,0x11,0xCF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0x46,0xF4,0x9B,0xFE,0xE2,0x44,0xA6,0x0A,0xD6,0xF7,0x09,0x90,0x2F,0x15,0x07,0x1D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0x72,0xA4,0x7C,0x50,0xC6,0x36,0xB7,0xA0,0x84,0x41,0xA1,0x85,0x10,0x90,0xC1,0xFA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC8,0x1F,0x9A,0x3C,0x40,0xD5,0xED,0xD0,0x24,0x66,0x84,0x10,0xCD,0xE6,0x25,0xAB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xFE,0xEC,0x4E,0x9B,0xBD,0x96,0xAD,0xB5,0x42,0x95,0xE1,0x20,0xEC,0xB2,0x48,0xA7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xF2,0x39,0xF4,0xEB,0x51,0xF8,0xEB,0x08,0x84,0x31,0x11,0x64,0x64,0x57,0x0B,0xCB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x30,0xFA,0x73,0x7E,0xFE,0x73,0x25,0xB2,0x89,0xB9,0xE8,0x1C,0x5A,0xAF,0xAF,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0xBB,0x2A,0xE8,0x78,0x9D,0xF5,0x43,0xA5,0xFB,0xBD,0x62,0x56,0x8E,0x72,0x3C,0x0B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x91,0x57,0x8B,0xDA,0x44,0x5F,0x09,0x1C,0x50,0x5F,0x2D,0x44,0x7D,0x4E,0xA7,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0x27,0x87,0x6E,0xD2,0x0E,0x29,0x9A,0x2C,0x5E,0xD0,0x22,0x00,0x21,0xC2,0xCB,0xAF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x24,0xF3,0x5A,0x06,0x6A,0x45,0xD3,0x62,0x54,0xA4,0x48,0xE4,0x78,0xDD,0x80,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC8,0x1C,0x4B,0x4F,0xF1,0x8C,0x6C,0xFF,0x5E,0xCD,0x4B,0xDA,0xE1,0xA9,0xF2,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0x16,0x9A,0xCA,0xF1,0xC7,0xAF,0xD2,0xE2,0xE5,0x78,0xC8,0x11,0xCF,0x0E,0xF9,0xE2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0x08,0x03,0x26,0x2A,0xD2,0xDD,0x06,0x5A,0xDB,0x02,0xB5,0x28,0x27,0xFA,0x0C,0x0E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0xAF,0xCD,0x5E,0xA3,0x2D,0x14,0x69,0x48,0xBE,0xBB,0x1F,0x87,0x7D,0xBA,0x61,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0xAF,0x47,0x42,0x6D,0x63,0xE6,0x24,0xA3,0x80,0xC5,0x30,0xAE,0xE6,0x86,0x4E,0xB4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xE0,0xF0,0x0B,0x83,0x02,0x96,0x40,0x4A,0x13,0xC2,0x60,0x7D,0x34,0x43,0x6D,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0xCA,0x01,0xCB,0x7E,0x7B,0x38,0x04,0x30,0xBF,0xA3,0x1F,0x59,0xF7,0x2B,0xBD,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x74,0xB7,0x73,0xFD,0xBF,0x6E,0xE8,0x0F,0xC6,0xE3,0x09,0x70,0x5F,0x6E,0x7C,0x72,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0x6C,0x1D,0xEC,0xB6,0x3D,0xAD,0xF2,0x3C,0xEE,0xA1,0x10,0xEA,0x76,0x12,0xC7,0x50,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x8D,0x45,0xAF,0x07,0xA3,0xCA,0x13,0x54,0xDC,0x63,0x68,0x29,0x45,0x2F,0xF3,0x1A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0xC5,0xC0,0x9A,0x8F,0x06,0xDB,0x6D,0xB5,0xB7,0xFD,0x14,0x76,0xF8,0x8E,0xD2,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xE3,0xBA,0xFB,0x7E,0xE5,0x3E,0xC8,0x9C,0x1E,0x49,0x85,0xCA,0x18,0xDB,0xF5,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xD4,0x88,0xE4,0xF8,0xE0,0xCC,0xFC,0x92,0x8F,0x34,0xAA,0x6A,0xBF,0x4D,0x5B,0xCF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0x86,0xCF,0x5D,0x59,0x76,0xBD,0x07,0x57,0x20,0x9A,0x4A,0xC6,0xAB,0x9F,0x8E,0x27,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0xB5,0x68,0xA8,0x80,0xF0,0x86,0xFC,0xDB,0xE7,0x2D,0xB7,0x55,0x62,0x08,0x4C,0xD0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0xC7,0x58,0xE5,0xB7,0xBF,0xA7,0xC4,0xF7,0x27,0x19,0xBA,0x49,0x3C,0x85,0x46,0xB5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x33,0x12,0x7E,0x7E,0xF7,0x3A,0x45,0x98,0xA9,0xB8,0xA3,0xEB,0xE1,0x10,0xCB,0x91,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0xEE,0x6F,0xC9,0x82,0xE4,0xE3,0x4D,0x3D,0x71,0x55,0x8E,0x7C,0x97,0xBE,0xD5,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0xC5,0xB3,0xD1,0xD5,0x30,0x5E,0xD4,0x86,0xC8,0x91,0x9E,0x3F,0xA8,0xB0,0x20,0x62,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0xE7,0x24,0x65,0xC0,0x2C,0x4D,0xA8,0x44,0x85,0x76,0x3F,0xBF,0xD9,0xA0,0xED,0x04,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xF4,0xC5,0x9C,0x94,0xC8,0x53,0xF9,0xE7,0x6C,0xE1,0x19,0x03,0xEE,0xA3,0x1B,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0x45,0xFA,0xEA,0x4A,0x42,0x9E,0x04,0xC4,0xDA,0x63,0x02,0xF7,0xA4,0x7F,0x75,0x77,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xEF,0xCC,0x82,0x48,0x47,0xBE,0xEC,0x15,0x10,0x4B,0xD7,0x5E,0x19,0x47,0xD3,0x6B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0xE9,0xA2,0x2A,0xC4,0x63,0xA4,0x6D,0xA1,0xA9,0xCC,0x2D,0xF0,0xE6,0xAF,0xAA,0xAE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x3D,0x28,0xAF,0xC5,0x3D,0xBF,0x3D,0x7A,0xFA,0x46,0x86,0x28,0x2D,0x92,0x96,0x13,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x78,0x79,0x55,0x77,0x7E,0xBA,0xD1,0x0F,0xC1,0x9C,0x8E,0x10,0x16,0x6A,0x9A,0xC3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x3A,0x83,0xB7,0x9B,0x6F,0xA6,0x36,0x68,0xAD,0xF2,0x5A,0xB3,0xC9,0xF0,0x66,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0xA5,0x6F,0xC1,0x5A,0xBB,0x35,0x39,0x73,0x9D,0x55,0xDC,0xBD,0xEB,0x3C,0x3B,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0xD5,0xD9,0xE6,0x36,0x33,0x5B,0xB7,0x88,0xE3,0x0B,0xBA,0x5D,0x44,0xFD,0xB3,0x1A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0xF9,0x3E,0x90,0xC5,0x2C,0x18,0xB4,0xB5,0x85,0xA8,0x44,0x38,0x0B,0xA8,0x04,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x56,0x4F,0x13,0x27,0x37,0x50,0xD1,0x2F,0xDD,0xAA,0x6E,0xE0,0x2C,0x34,0x23,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x32,0xBC,0xA6,0x5E,0xF9,0x88,0xBE,0xC4,0xF6,0xC4,0x3E,0xD5,0x1C,0x6F,0x78,0xB4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x08,0x5E,0xC0,0x25,0x76,0x48,0x9C,0xAA,0x6C,0xCB,0x11,0x8D,0xC4,0x80,0xC6,0xE0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x60,0xBC,0xDF,0x8B,0xD4,0xAE,0x74,0x13,0x14,0xC5,0x22,0x18,0x04,0xC7,0x88,0x72,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x97,0x24,0x4D,0xC3,0x2B,0x1F,0x6B,0xD4,0xBC,0x9B,0xE7,0xB0,0x98,0x1A,0x4B,0xBC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0xCB,0xF4,0xD8,0x16,0x85,0x41,0xCC,0xCE,0x92,0xFC,0xC5,0x60,0xEC,0x03,0xAB,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xB7,0x1B,0x14,0xFB,0xE3,0x45,0x5C,0x7F,0xDB,0x0B,0xA0,0x38,0xFE,0xC1,0x44,0xAB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x22,0x6C,0x30,0xFC,0xBC,0xC9,0xD7,0x78,0x6A,0xCA,0x92,0xF5,0x9B,0xAB,0x10,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xF8,0x03,0x7B,0x59,0x60,0x34,0xA5,0x53,0x68,0x52,0xC0,0xA9,0xBA,0xC7,0x89,0x6A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x29,0x3F,0xE4,0xBC,0x8C,0x7D,0x35,0x45,0x37,0x99,0x61,0x80,0x47,0xFD,0xB8,0x01,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0x89,0x4A,0x00,0x28,0x28,0x21,0x32,0xCB,0xC9,0x24,0xD5,0xE2,0x08,0x89,0x12,0xA8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x80,0xAC,0xF3,0x6B,0xFC,0x3D,0x16,0xBA,0x9D,0x00,0xAE,0xAB,0x63,0xA2,0x18,0x10,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0x39,0xB3,0x6A,0xE5,0x23,0x51,0x39,0x82,0x9C,0x39,0xEC,0x7E,0x4C,0xC1,0x7D,0xA0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xC4,0x27,0xFD,0x32,0xB1,0x51,0x10,0x3F,0x38,0x64,0x6D,0xC1,0xB8,0x3E,0x11,0xAB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x3A,0x8D,0xB2,0xCA,0xE4,0x49,0x99,0x58,0x68,0xFB,0x14,0x4F,0xC0,0x81,0x35,0x2D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0x31,0xBF,0xB5,0xFC,0x28,0x3A,0xF4,0x9E,0xE1,0x6B,0x65,0xF8,0x1D,0x9C,0x87,0x67,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7C,0xD9,0x85,0xBE,0x29,0xAE,0x5E,0xD9,0x24,0x32,0x01,0x66,0xF5,0xCC,0x68,0x02,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0xEE,0xA1,0xC4,0x7B,0xD1,0xA0,0x65,0x4D,0x9B,0x62,0x8D,0xF3,0x20,0xC4,0x63,0x8C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0xFB,0x65,0xC8,0x89,0x7E,0xAC,0xAA,0x6E,0x35,0x76,0xA7,0x53,0xD9,0xC6,0x76,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x3C,0x57,0x69,0x05,0x1B,0xE4,0xBE,0xCE,0x5B,0x33,0x9C,0x9D,0xC6,0xBD,0x93,0x4A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x90,0xE1,0x89,0x65,0x97,0x08,0x2F,0x37,0x31,0xC7,0xF0,0x55,0xE2,0xDE,0x85,0xED,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xAB,0xC6,0x91,0x64,0x79,0x58,0x21,0x84,0xB4,0xCF,0x4C,0xA8,0x0E,0xAD,0xA1,0x52,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x85,0x72,0x60,0xBB,0xF6,0x44,0x94,0x65,0x8D,0x93,0xB7,0xE4,0x77,0x2D,0xB3,0x01,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0x88,0x32,0xB6,0x78,0x6E,0x01,0x30,0xCC,0xF2,0xDB,0xCD,0x2F,0xE5,0x20,0xFE,0xAC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0xD5,0x5A,0x74,0xEF,0x96,0xE8,0x87,0x59,0x76,0xC7,0xB6,0xB3,0xD2,0xA6,0x4B,0x59,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xF4,0x64,0x3A,0xC5,0xAB,0x8B,0x89,0x03,0x25,0x29,0xCC,0x8D,0x2F,0x17,0xEF,0x0C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0x2D,0xE4,0xFE,0xAE,0x7C,0xBC,0x5D,0xF1,0xB4,0x73,0xA6,0x74,0x60,0x4A,0x2B,0x27,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xC8,0xCE,0x78,0xBB,0xC7,0x19,0xD8,0x5A,0x27,0x89,0xBC,0xE9,0xC2,0x7B,0xD9,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0x38,0xA9,0xED,0xD6,0x14,0x6B,0x2B,0xA4,0x4E,0x90,0xB8,0x1C,0xA0,0xD5,0x79,0x3B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0xCF,0x47,0x7A,0x65,0xCC,0xD3,0x9F,0x62,0xC7,0x54,0x77,0x35,0x68,0xB3,0xEB,0x1D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x86,0xD4,0x2A,0xEC,0xD2,0xEC,0x2D,0x06,0x90,0x88,0x65,0xB3,0x63,0x3C,0xA0,0x2C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0xCF,0xF0,0x9C,0xE1,0x74,0xEA,0x3E,0xF7,0x3F,0x16,0xA6,0xCD,0x67,0xF1,0xDA,0x43,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x7D,0xF2,0x90,0x7E,0xC5,0xCC,0xB1,0xE1,0xC3,0x96,0x33,0x0F,0x49,0x70,0x58,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0xB7,0x03,0x53,0x23,0x0B,0xE0,0x5E,0x6D,0x09,0x97,0x04,0x44,0xDD,0x08,0xD4,0xEF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xFD,0x8B,0x8E,0xAB,0x8E,0x6B,0x99,0x8D,0xF3,0x81,0xF2,0xDF,0x42,0x2F,0x9D,0x0B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0xF7,0x6D,0x51,0x97,0x1C,0xEF,0xDC,0x04,0x51,0xFE,0x9D,0x5B,0xC1,0x7A,0xCF,0x6B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0xC9,0x8D,0x65,0x39,0x7D,0x37,0x8E,0x7C,0x02,0x15,0xF5,0x67,0x81,0xAB,0xC9,0x99,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0xD9,0xDE,0x1F,0x51,0xE2,0x24,0xE4,0xAC,0xEB,0xDD,0x5E,0xA9,0xD3,0x04,0xB6,0xEF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xDE,0xC2,0x7B,0xE9,0x3B,0x35,0x43,0xE8,0x3B,0xF0,0x14,0xF0,0x7F,0xE7,0xAB,0xA7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0x01,0xB1,0x3D,0x22,0xD7,0xD1,0x47,0x14,0xB6,0x0A,0x72,0x0B,0xBE,0xEF,0x55,0xC9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x08,0x37,0x76,0x52,0x86,0x30,0xE9,0xBF,0x77,0x7F,0xE9,0xFA,0x37,0xBE,0x21,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0x63,0x52,0x4D,0x41,0x2A,0x3B,0x31,0xC2,0xCF,0xC5,0x57,0x5B,0x62,0x26,0xB2,0xDF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0x44,0x81,0x8C,0x17,0x23,0x7F,0xEC,0x10,0xF7,0x6F,0x21,0x95,0xE9,0x71,0xF1,0x5D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0xBA,0x8F,0xA8,0x69,0x61,0x9B,0xF7,0x26,0xAF,0x80,0xFE,0xE6,0xAC,0xDB,0xF8,0x6A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0xF6,0x1B,0x3E,0xC0,0xB0,0x5F,0xE7,0x98,0xD4,0x85,0x81,0xF6,0x91,0x88,0xDE,0x97,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0xF9,0xBF,0xA3,0x74,0xF3,0x44,0x8D,0x3D,0xD2,0x8A,0x5D,0xAB,0x81,0x35,0xE7,0xEA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x20,0x49,0x5B,0x27,0x96,0x5E,0x8F,0x7E,0xAB,0xC4,0x00,0x8A,0xE2,0x72,0x1A,0x69,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x0A,0xC0,0x6F,0xB9,0x7F,0x28,0xEB,0x8B,0x0C,0x0A,0x23,0xD4,0xFB,0xCC,0xC6,0x56,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x46,0xD6,0x69,0xB7,0x11,0x0B,0xFF,0x3A,0xF1,0xA6,0x05,0x9B,0xD0,0x21,0x39,0x25,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0xA2,0xC0,0xF3,0x75,0xB8,0xDE,0x4A,0x97,0x5D,0xC8,0x8B,0x2D,0xB3,0x87,0x94,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0xBB,0xE8,0x1B,0xC0,0x39,0x8F,0x88,0xDB,0x5A,0xA7,0xB4,0x4A,0x83,0x6F,0x6A,0xBA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0xDD,0x06,0xF2,0xFC,0xE3,0xCA,0x70,0x72,0xE8,0x11,0xE4,0x5A,0xF5,0xC4,0x91,0x0F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xE9,0xD0,0xBA,0x90,0x07,0x87,0x67,0xEE,0x26,0xE9,0x8A,0x06,0xD4,0x0F,0xE6,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0x13,0x43,0xFF,0x73,0x48,0x98,0x05,0x2D,0x70,0x5B,0x51,0x1F,0x1B,0xE8,0xAD,0x4E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x68,0xC1,0x31,0x95,0xBC,0xD0,0xC5,0x46,0xA5,0xB3,0x8D,0xED,0x90,0x99,0x7C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0xF0,0xA3,0xF5,0x4F,0xAF,0x6A,0x0B,0x1B,0xE8,0x61,0x0B,0x76,0xAF,0xA7,0x78,0xEF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x49,0x55,0x3B,0xD8,0xE2,0xE5,0x2C,0xC7,0x51,0xF6,0x06,0xF5,0xB8,0xEC,0x64,0x6D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x97,0xDD,0x92,0x9C,0x6C,0x43,0x20,0x00,0xC6,0x66,0xE7,0xF3,0xD1,0xD3,0x76,0x2B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xA9,0xED,0x42,0x2B,0x35,0x92,0x92,0x83,0x97,0x80,0x05,0x91,0x74,0x8F,0xDB,0xB2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x02,0x2C,0x3C,0xB8,0xE6,0xD6,0xAE,0x77,0x56,0x16,0xDF,0xE1,0xCB,0xB2,0xD1,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x07,0xB6,0x95,0x99,0x15,0x7C,0xE9,0x90,0x8D,0x48,0x78,0xB8,0x95,0x06,0xEF,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0xFA,0x08,0xFF,0x64,0xB7,0xBC,0xC7,0x51,0x90,0x68,0x54,0xA4,0xFE,0x40,0x5A,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0xF2,0x51,0x6D,0x1D,0x0D,0x0F,0x3A,0xD8,0x14,0x7F,0x88,0x58,0xE8,0xCD,0x59,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0x33,0x70,0x66,0x09,0x95,0xED,0x3A,0x70,0x93,0xF9,0x5F,0x70,0x81,0x11,0xDD,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x53,0x14,0xD5,0xE5,0x6B,0xAE,0xA0,0x3E,0x41,0xE9,0x8D,0xC4,0xE2,0x41,0x14,0xC3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x89,0x30,0xA9,0xBD,0xCB,0x9C,0x11,0x41,0x09,0x71,0x7D,0xFB,0x32,0x8C,0x71,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xBB,0x25,0x3A,0x75,0xDB,0xC4,0x73,0x82,0x9E,0x77,0x1D,0x40,0xCB,0x53,0xC1,0x59,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x44,0x77,0xFB,0xD5,0x87,0xB3,0x98,0x87,0x29,0x7C,0x89,0xF4,0x61,0xF2,0xBB,0xDF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0xA9,0x78,0xF0,0xF2,0x1F,0x9A,0x01,0xCF,0x49,0x8A,0xD9,0x55,0x47,0xCD,0x9B,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xF7,0x94,0x2C,0xAE,0xA4,0xAA,0xC3,0xA5,0x4E,0xCE,0xE3,0x66,0x29,0x48,0x29,0x7E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0x30,0x8F,0xCA,0xE2,0x4D,0xE3,0x99,0x98,0x98,0x2B,0x90,0xCA,0xD9,0xB8,0x46,0xC6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0x69,0x13,0x6A,0xE6,0xB5,0x88,0x5E,0x9A,0x94,0x19,0x4A,0x1D,0xBC,0xC8,0x1D,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0x14,0x8D,0x0C,0x75,0xE9,0xAB,0xED,0x7E,0x95,0xF5,0xF2,0xEE,0x23,0x4B,0x80,0x98,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0x01,0x04,0x20,0xC9,0x48,0x94,0x48,0x14,0x8A,0x70,0xF8,0x31,0xB4,0x97,0xE4,0x0F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x88,0xF0,0xD9,0xA0,0xE9,0x15,0x3A,0x80,0x3A,0x7C,0x41,0xD8,0x1D,0x8D,0x19,0x65,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x7A,0x98,0xE3,0x15,0x15,0x1C,0xA8,0xAE,0xFE,0x6D,0xA1,0xE8,0xC3,0x8D,0xA1,0xD3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0x2F,0x12,0xFA,0x45,0xC2,0x73,0xCC,0x69,0x75,0x95,0x57,0xF9,0xA7,0xC7,0x52,0x50,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0xF5,0x23,0x25,0x0D,0xF8,0xE0,0xF5,0x5E,0xF0,0x26,0x74,0x42,0xF6,0x60,0xCF,0x39,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0x67,0xE1,0xDE,0x06,0xB4,0x9F,0x20,0x86,0x21,0x78,0x83,0xC0,0xA5,0x39,0x6D,0x06,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xCD,0x18,0x53,0x92,0x1A,0x9A,0xF6,0x28,0xD7,0x68,0x4C,0xFA,0x18,0xF8,0x59,0xAD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x62,0xDD,0xD8,0xF7,0x66,0x0E,0x7A,0xBF,0x3B,0x0A,0x11,0x5E,0x79,0x63,0xCD,0x88,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xB2,0x5B,0x0C,0xD6,0x94,0x1D,0x0B,0x6D,0xBD,0xA5,0x7A,0xA2,0xBE,0x93,0x00,0x45,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0x0F,0x9C,0x58,0x14,0x83,0xBE,0xC7,0xCE,0xA3,0x01,0x90,0x83,0x0D,0xE3,0xA4,0xA4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x80,0x7D,0x63,0x76,0x53,0x5A,0x1D,0xA6,0x75,0x3C,0xE5,0x24,0xBE,0x52,0xF0,0x04,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0x4C,0x15,0x8A,0xCE,0xC6,0x10,0x14,0xDE,0x82,0xC7,0xFE,0x79,0x45,0x54,0x2F,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0xB2,0x49,0x43,0x39,0x6C,0x54,0xDB,0x0C,0x2B,0x51,0xC9,0x30,0x5F,0x89,0xB8,0xE0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0x9A,0xA3,0x08,0x80,0xE5,0xDF,0x26,0x89,0x52,0xA4,0xE8,0xD4,0x06,0xFA,0x2E,0xAB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x72,0xF6,0x90,0x9D,0x91,0x8A,0xDF,0x30,0x6A,0x15,0xE8,0x6A,0x7E,0xEE,0x4A,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0xC8,0x94,0xC6,0x74,0x64,0x9F,0x96,0x58,0x50,0x34,0x29,0x90,0x28,0x1D,0x21,0x51,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x21,0x73,0x35,0xF6,0x77,0xFB,0xA2,0xF2,0xF9,0x3C,0xB6,0x3F,0x22,0xBF,0xEE,0x75,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0x83,0x4E,0xFA,0x9E,0xFA,0x96,0x8F,0xF8,0x64,0xB6,0x23,0xFD,0xFE,0x86,0xB2,0xC1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0x72,0x7D,0xF9,0x2A,0xD0,0xA7,0x7D,0xDD,0x2A,0xD9,0xEB,0xDA,0x91,0xEA,0x6F,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x27,0xCA,0x7A,0xCA,0xBD,0xEF,0xBA,0xC9,0xBE,0x60,0x51,0x15,0x85,0x23,0xD3,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0xE1,0xF7,0x04,0xD9,0xE7,0xF1,0x5F,0xEE,0x71,0xE9,0x3C,0xB1,0xEB,0xFF,0x5A,0xD4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5E,0x75,0x87,0x81,0x6F,0xCF,0x23,0x2A,0xDD,0x95,0x07,0x96,0x29,0x2A,0x2E,0x4C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x9F,0x21,0x11,0xE3,0x42,0x59,0x69,0xBD,0x3F,0xB5,0x0B,0xE6,0x7E,0xF9,0x6F,0x71,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0x8F,0x71,0xAF,0x41,0x74,0x3E,0x38,0x95,0x29,0x8D,0x93,0x86,0x79,0x94,0x06,0x3F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0x08,0xD4,0xF8,0x1C,0xC0,0x31,0x91,0xCF,0x06,0x9D,0xEF,0x55,0xA9,0x28,0x7D,0xB5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x76,0xF5,0x3E,0x5F,0x71,0x72,0x69,0x69,0xB4,0x90,0x96,0x58,0x66,0x9E,0x2A,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x65,0xEE,0x25,0x80,0xD2,0x12,0x5D,0xD4,0xA1,0x2C,0xF4,0xEA,0xB7,0x9F,0xBE,0x63,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0xC5,0x36,0x87,0xE1,0xC3,0x11,0xC1,0x62,0xD3,0xFB,0xC0,0x2E,0x9D,0x75,0x09,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0xEB,0x30,0xA0,0x51,0x47,0xD8,0xA0,0x5D,0xEE,0x1E,0x81,0x60,0x3E,0x7F,0xF0,0x37,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0xBB,0x05,0xBC,0x1D,0x47,0x7B,0x04,0xA9,0x41,0x76,0x5B,0xB3,0x7B,0x1E,0x04,0xC7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0xFF,0x57,0xD4,0xAA,0x71,0x3F,0x00,0xF1,0x7A,0xD0,0x26,0x63,0xC3,0x37,0xD4,0xA0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0xEA,0x3E,0x02,0x1F,0x8B,0x8C,0x0E,0x7E,0x12,0x17,0xBE,0xC6,0x8B,0x8B,0xC6,0xB6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x3F,0x79,0x43,0x67,0x04,0x05,0x1D,0xF9,0xB1,0x93,0xD9,0xF8,0x2C,0x11,0xE9,0xB9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xC9,0x46,0xB3,0xBA,0xFE,0x36,0x67,0xCD,0x0F,0xAD,0xC2,0x41,0x4A,0x27,0xD1,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x90,0xAF,0x41,0x1C,0x1B,0xC8,0x6D,0x3F,0x32,0xD6,0x5A,0x3A,0x03,0x9B,0x5F,0xB7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xD4,0xFB,0x9F,0x45,0xDE,0x04,0xA0,0x8F,0x5A,0xB5,0x49,0xA1,0x0E,0x0D,0x42,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xF2,0xEB,0xF7,0xC4,0x53,0xEF,0xD7,0x77,0xBD,0x9A,0xAA,0xDF,0x02,0x4F,0xC8,0x46,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0x19,0x1F,0x23,0x7F,0xE8,0xF9,0xAF,0x1B,0x7A,0x9A,0xC5,0xFB,0x37,0x38,0x40,0xDC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD8,0xFF,0xF1,0x94,0x9F,0x21,0xF5,0x12,0x37,0xF0,0x22,0x96,0x6D,0xEB,0x51,0x03,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0xAD,0xC1,0x37,0x50,0xDD,0xC2,0xD1,0xA0,0x13,0x26,0x8F,0xEB,0xBD,0x55,0xAB,0xFA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x33,0xF9,0x31,0xC3,0x79,0x56,0xA6,0x35,0x2D,0x61,0xD7,0xCD,0x69,0xCC,0xDA,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x09,0x49,0xAB,0x69,0x69,0x3E,0x6F,0x31,0xCA,0x4E,0x5E,0x73,0x86,0x0A,0x59,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x4B,0x34,0x6C,0xAC,0x88,0x85,0x6C,0x7D,0x24,0xA2,0xF5,0xFB,0x80,0xB8,0xB9,0x6E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0xC4,0x44,0x0A,0x24,0xA0,0xF5,0xBC,0x88,0xBA,0x31,0x38,0x01,0xD4,0xB7,0xD8,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x7A,0xB3,0x1F,0x64,0x13,0x8C,0xD3,0x0F,0x32,0xD6,0x48,0xE3,0xDB,0xE9,0x74,0x82,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xEA,0xBF,0x63,0x57,0x34,0x4C,0x59,0x9C,0x5A,0x16,0xF1,0x26,0x13,0xF6,0x82,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xDF,0xE2,0x13,0x14,0x0C,0xDF,0xCA,0x2B,0xD2,0xA5,0x3F,0x8C,0xD5,0x56,0x9D,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x0B,0xB4,0x55,0x63,0xBD,0x0C,0xB7,0x54,0xDA,0x5B,0xBA,0x66,0x69,0xD4,0xD0,0xDF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0xD2,0x0F,0xED,0x59,0x0B,0x7F,0x32,0x0E,0x3F,0xA8,0x7D,0xE6,0xA4,0x01,0xB7,0x7C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xBE,0x85,0x21,0xBA,0xBC,0xBA,0xE4,0x3A,0x93,0xBE,0x9A,0x8D,0x81,0x58,0x3B,0x75,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0x1F,0x73,0xC9,0xDE,0xE6,0x42,0xF2,0x31,0xBD,0x05,0x4B,0x64,0xAC,0x34,0xC4,0xA4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0xEE,0x4E,0x2B,0x15,0xBF,0x1F,0x7C,0xB9,0x5A,0x37,0x44,0xF7,0xE3,0x95,0x3C,0x62,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x8B,0x3E,0x67,0x34,0x9C,0x90,0x43,0x17,0x38,0x5C,0x41,0xAD,0x5C,0x83,0x82,0x7E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0xE7,0x2D,0x92,0x21,0x9E,0xBA,0x5D,0xED,0x3B,0x65,0x9B,0xDE,0x35,0x4E,0x31,0x66,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0x2C,0x8D,0x9B,0x32,0x06,0x78,0xC5,0x18,0x92,0xDE,0xFD,0xAD,0x16,0x1C,0x4C,0x60,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xA7,0x57,0xC2,0x23,0xE6,0x52,0x75,0x8D,0xCE,0x00,0x5A,0xEB,0x1B,0x24,0x43,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0xD5,0xA0,0xD2,0x61,0x74,0xA2,0x2C,0x73,0x1E,0xCB,0x49,0x7A,0x58,0xD6,0x2E,0x07,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0xBE,0x3C,0xBE,0x04,0xD8,0x92,0x0B,0xD3,0x02,0xC4,0x1F,0xBF,0x63,0x1B,0xB3,0xC7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0xE4,0x6C,0xC4,0x17,0xE0,0xC0,0x36,0x50,0x60,0x37,0x08,0xBB,0x3A,0xE9,0x5C,0x4B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x12,0x8F,0xA8,0xBE,0x6D,0xD7,0x81,0xD0,0xED,0x54,0x3F,0x36,0x41,0xC4,0x62,0xBC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0xA7,0xD3,0x0C,0x05,0xB0,0xD6,0x15,0x19,0x7F,0xE0,0x87,0x2A,0x8E,0x4F,0x59,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0xEE,0xC5,0x36,0xCA,0x3D,0xAC,0x9A,0xF3,0x1F,0xE6,0x6A,0x8A,0x4A,0xB9,0x1C,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x27,0x54,0x96,0x17,0x84,0x83,0x2A,0x87,0xD4,0x22,0x95,0x34,0xF8,0x86,0x7B,0x13,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0xD9,0xC0,0x6A,0x4D,0xD6,0x91,0xAB,0x77,0xD6,0xA4,0xEC,0xA7,0xCD,0xD1,0xA2,0xDD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0x3B,0x7E,0xA7,0xFD,0xEC,0x55,0x7B,0xE1,0x41,0xA5,0xB5,0x16,0x6F,0xF0,0x61,0x7F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x04,0x61,0x2C,0x07,0x54,0x31,0xDE,0x9E,0x0B,0xEB,0x44,0x2C,0x92,0xF0,0x62,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0x8F,0xD9,0x9A,0x0D,0xE7,0xFF,0x94,0xF3,0xFA,0x7E,0xF3,0x02,0xEA,0x7E,0x4F,0x97,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x1D,0x14,0x33,0xF5,0xAF,0x98,0x9F,0xBB,0x6E,0x0F,0xBF,0x6E,0x8E,0xE2,0x8C,0xAC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x83,0xDA,0xD2,0x10,0x3C,0x7E,0xE5,0xF7,0x7F,0x44,0x5C,0x4B,0xD9,0x83,0x88,0x49,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0x14,0x6F,0x60,0xA9,0x6E,0xC5,0xBF,0x62,0x3A,0xA4,0xDD,0x2C,0x62,0x0D,0x2C,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0x22,0x80,0xD5,0xA6,0xED,0xFC,0xBC,0xB5,0x6D,0xF3,0x82,0x9E,0xA9,0x1F,0xBE,0xEC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x77,0x84,0xCB,0x56,0x5F,0x15,0x66,0x96,0xC8,0x9E,0xC2,0x2F,0x68,0xF0,0xD3,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0xB8,0x26,0x2A,0x29,0x11,0xCB,0xC8,0xEA,0x81,0x55,0x1B,0x02,0x5D,0x50,0x78,0xB9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x29,0xA4,0x01,0xEB,0xC7,0xEE,0xD4,0xD0,0x5C,0xAC,0xFD,0x2B,0xBF,0xF8,0xF1,0xFC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0x7F,0xC8,0x8C,0x67,0x46,0x9F,0x74,0xEF,0xCF,0xB6,0x39,0x47,0x80,0xFD,0x96,0xCE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x88,0x22,0x9A,0xAC,0x8E,0x35,0x58,0x5F,0x6C,0x8B,0x93,0x69,0x4B,0xFE,0x22,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x8C,0x9D,0xDC,0xC3,0x88,0x1B,0xFD,0x6B,0x8F,0xA2,0x31,0x7A,0xE9,0xD4,0x7D,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0xC1,0xAB,0x73,0x0D,0xAF,0x46,0x50,0xF5,0x40,0x47,0x3F,0xF8,0x28,0x02,0xA3,0x58,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0x4A,0x3E,0x8E,0x24,0x9E,0x5B,0x4E,0x89,0x80,0x28,0x59,0xB8,0x93,0x8C,0xAC,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x7A,0x63,0xD3,0x4A,0x53,0x94,0x85,0x0A,0xBA,0x5F,0xE9,0x6F,0xDC,0x74,0x3E,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0xED,0xC7,0xE6,0x13,0xA4,0xCB,0x72,0xBB,0xDA,0x93,0x44,0x88,0xA0,0x4A,0x38,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0x0C,0x91,0x44,0x23,0x74,0x33,0xEE,0xD0,0x67,0xC6,0xE6,0x97,0xE0,0xEA,0x8C,0xC6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0x92,0xAC,0x94,0xA3,0xCD,0xDC,0x3B,0xC9,0xB8,0x45,0xA9,0x11,0x82,0x8A,0x82,0x90,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0xB4,0x33,0x74,0xFA,0x89,0x62,0x0C,0x8C,0x78,0x59,0x92,0x49,0x4A,0x2E,0xBE,0xAC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0x56,0x22,0x56,0xF8,0x6F,0x85,0x55,0xF0,0x08,0x04,0xAF,0xC2,0xFD,0x75,0x80,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0x77,0x9F,0x5D,0x50,0x05,0x96,0x13,0xF4,0xDE,0x3A,0x16,0x49,0xBB,0xC3,0x75,0xC0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0xAB,0x83,0xD2,0x29,0xCB,0x33,0x01,0x87,0xEE,0xFE,0xFE,0x6C,0x5B,0xAC,0x13,0xDD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0x9A,0x27,0x04,0x64,0x20,0xC9,0x93,0xDD,0x95,0xB5,0xE8,0x83,0x9F,0x27,0x4D,0x7B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x44,0xFD,0x44,0x9A,0x15,0xDC,0x19,0x53,0x77,0xD1,0x1B,0xE6,0xE9,0x70,0x56,0x38,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xA2,0xD1,0x95,0x6B,0x30,0x7D,0x3E,0xC8,0xEB,0xC2,0x35,0xE7,0x86,0x3C,0xDF,0xD1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x0A,0xAC,0x3B,0xC1,0xD8,0xB0,0x7F,0x5D,0x91,0xDB,0xB8,0x59,0xE2,0x54,0x27,0x27,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x1E,0x56,0x79,0xDC,0x7C,0xFA,0x0B,0x5A,0xBC,0x67,0x38,0xC1,0x6D,0xE6,0xE9,0x13,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x93,0x8B,0x05,0x65,0x6B,0x37,0x9A,0xB7,0x10,0xF3,0xA5,0xA5,0x05,0xBD,0x05,0xFE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0x7E,0x8C,0x99,0x4C,0x0D,0x06,0xD5,0x80,0x04,0x42,0x57,0x75,0xE6,0x09,0x6A,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x3C,0x7C,0x54,0x3A,0x8A,0xC3,0x7B,0xEE,0xAD,0xBE,0x50,0xD5,0x5A,0x25,0xC0,0x1B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xCB,0xCD,0x3F,0x70,0x3D,0x84,0x44,0x4E,0x72,0x12,0x61,0x5B,0xE0,0x7A,0x23,0xAE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8E,0x2B,0x90,0x39,0xD8,0xB8,0xC1,0xB9,0xBD,0x09,0x14,0x99,0x72,0xC4,0x83,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0x50,0x18,0x5F,0x5C,0xA1,0x28,0x96,0x18,0xAC,0x07,0xE7,0xC1,0x10,0x92,0x56,0xAD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x14,0x6D,0xFD,0xF7,0x68,0xEC,0x38,0x0E,0xB8,0xC1,0x4E,0xF9,0xA5,0x72,0x7A,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0x8E,0xC0,0x44,0x3F,0x1A,0x62,0xEF,0xB8,0x85,0x5D,0x71,0xA5,0x2A,0xBB,0xA0,0x9D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x39,0x19,0x8F,0x26,0xF6,0xA9,0x69,0xFD,0x6F,0x62,0x5E,0xD7,0x62,0x2F,0xE2,0x81,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x90,0x01,0xBF,0x8F,0x66,0x52,0x29,0x76,0xBF,0x22,0x69,0x7D,0xEA,0x20,0xD1,0x1D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x8A,0x33,0x60,0x02,0xBD,0x72,0xC1,0xCA,0xBD,0x32,0xA9,0x0A,0x9D,0xB5,0xA3,0x56,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0x9C,0x97,0x2B,0x2A,0x82,0x6C,0x7D,0xA3,0xBB,0xEF,0xB3,0xFD,0x40,0x84,0x99,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x3A,0x44,0x0A,0x49,0x2D,0xCC,0x55,0xD1,0xF8,0xFE,0xE2,0x22,0xB8,0x62,0x94,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x18,0x99,0x5A,0x07,0x8F,0x2F,0x2F,0xBE,0xC8,0x6C,0x72,0xDD,0x89,0x32,0x6E,0xF7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x5C,0x96,0xC1,0x0B,0xF7,0x46,0xD9,0x3D,0xF3,0x68,0x05,0x80,0x85,0x02,0xE6,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0xD3,0x5F,0xB5,0xA5,0x5E,0xC2,0x4C,0xD3,0x72,0xB0,0x5E,0x3D,0xC5,0xB1,0xAE,0x34,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0x23,0x27,0x88,0x97,0x04,0x84,0x42,0xA5,0x3B,0x40,0x58,0x0E,0xEB,0x14,0xAD,0xAA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x5A,0x39,0x49,0x07,0x9B,0x87,0x0E,0x73,0x7E,0x59,0x74,0x1F,0x07,0x4B,0xB7,0x79,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x68,0x0D,0x24,0x94,0xC5,0x2F,0xE3,0x73,0x2F,0x5D,0x85,0xD4,0x4E,0x25,0xDF,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0xE1,0xAC,0xB6,0x6C,0xDF,0x51,0xE6,0xB1,0xA2,0xB9,0xBC,0x23,0x79,0x60,0x5E,0xAC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x6F,0x2F,0xF6,0x7A,0x84,0xC4,0x8F,0xC6,0x24,0xC0,0xB0,0x2D,0xE4,0x2B,0x31,0x30,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x8A,0x09,0x28,0x49,0xF2,0x4A,0xEE,0x87,0xD2,0x3E,0x98,0x41,0x1C,0xBA,0x2D,0xDC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0xDF,0xE2,0x3F,0x16,0x42,0x50,0x82,0x90,0xD6,0x82,0x45,0x27,0x95,0x58,0xB2,0xFB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x26,0xD5,0x19,0x36,0xCD,0xC2,0x15,0x51,0xFE,0x53,0x4D,0xDD,0xE3,0x71,0xB3,0xE3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x7B,0x90,0xCC,0xE6,0x5F,0x1A,0x59,0x99,0x25,0x6A,0x81,0x4A,0xD4,0x31,0x57,0x4E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x55,0x89,0x7D,0x7D,0xEC,0xB2,0x77,0xCE,0x9C,0xD3,0xA9,0x18,0xD5,0xB3,0xED,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0x4B,0x48,0x0E,0x62,0xB6,0xD6,0xB3,0x66,0xBA,0xC4,0xBC,0x19,0x4D,0x33,0x49,0xC4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0x74,0x2A,0x9D,0x55,0xC1,0x60,0xA2,0xF6,0xE5,0xB4,0x9E,0xBC,0x78,0x1E,0x47,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x5F,0x42,0x3D,0xEC,0xB6,0x8D,0xCE,0xBB,0x35,0xE4,0xBF,0x01,0x8F,0x90,0x73,0xC3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0x70,0x00,0xD9,0x4D,0x44,0x39,0x65,0x0E,0x90,0x77,0xCD,0x70,0x60,0xA3,0xBC,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0x74,0x08,0xF3,0xEB,0x32,0x1D,0x1D,0x42,0xCC,0xA0,0x8B,0xEC,0xD4,0xD7,0x47,0x66,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xF9,0x44,0x58,0x4B,0xAF,0x58,0x74,0x64,0x8F,0xC1,0x26,0xC4,0x57,0x25,0x76,0x64,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x37,0x81,0x83,0x11,0xCB,0x6F,0x64,0x1A,0x22,0x10,0x44,0xFB,0xF3,0xD3,0xC6,0x06,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x1E,0x11,0x4C,0xD6,0xB5,0x48,0x4C,0x3B,0x41,0x36,0x31,0x43,0x58,0x21,0x5D,0xC8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0xDB,0x16,0xA0,0xD3,0x84,0xC7,0xBC,0xF4,0x97,0xAD,0x20,0xC0,0xF8,0x59,0xB7,0x5A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0xFF,0xB5,0xA0,0x5B,0x57,0x8C,0x80,0x2E,0x22,0x26,0x60,0x0A,0x33,0xA6,0x90,0x3F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x47,0x22,0x4F,0xE6,0x72,0x68,0x0D,0x1B,0xEB,0xE0,0x71,0xA3,0xDA,0x58,0xFB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0x8A,0x16,0xCF,0x75,0x21,0x6F,0x60,0xE4,0xCF,0x96,0x39,0x8D,0x66,0x5C,0x38,0x56,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0xE0,0x2D,0xCA,0xC2,0x2B,0x55,0xD7,0xB4,0xC7,0x3C,0x69,0xB9,0x89,0xDC,0x25,0xDF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xFF,0x6A,0x1B,0xFE,0x5A,0x61,0xD6,0xF7,0xE4,0x0A,0xFC,0xE4,0x4C,0xA5,0xE4,0xCB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0x72,0xE1,0x98,0x6D,0xF8,0x40,0x8B,0x2D,0x63,0x42,0xED,0xCB,0xE8,0x0B,0xC7,0x77,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0xDB,0xC9,0x8E,0x82,0x08,0x84,0x90,0xD6,0x97,0x9B,0x2F,0xD6,0xEA,0xC0,0x62,0xB5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x42,0xA8,0x6A,0xDD,0x55,0x41,0x82,0xC4,0x0F,0xC6,0x79,0x4B,0x54,0x9F,0x5A,0xFF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x2D,0x36,0xC2,0x7C,0x9C,0xEB,0xB1,0x2D,0x54,0xA4,0xB8,0x58,0xEB,0x6A,0xDF,0x18,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0x2D,0x02,0xCA,0xF0,0x0C,0xB9,0x28,0xF2,0xB3,0x85,0xBC,0x00,0x1E,0x07,0xC2,0x46,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0x6F,0x4A,0x50,0xB1,0x66,0x6A,0x8B,0x4B,0x0D,0x67,0x4E,0x4E,0x4B,0x9C,0xAE,0xD5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x0B,0x2C,0x9B,0x70,0x83,0x72,0x39,0xB8,0xB0,0x80,0x6C,0x79,0xBD,0x2F,0xFE,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0xD8,0x06,0x7C,0x44,0xCB,0xB9,0x8C,0x33,0xB6,0x42,0xD7,0xFF,0x0D,0xF9,0x49,0xEC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xCC,0x45,0xFA,0x13,0x6F,0x72,0xC7,0xC7,0x61,0x69,0xCA,0xCE,0x60,0x57,0x61,0x8B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x81,0xE5,0x4A,0x2B,0xF4,0x07,0x50,0x29,0xCA,0xC6,0x4B,0x78,0x7B,0x20,0x8C,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0x4B,0xC0,0x66,0x6E,0x43,0xFF,0x77,0x21,0xB4,0x8E,0x69,0x1A,0x72,0xB0,0xCF,0xB7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x16,0x2D,0x7C,0x02,0x3F,0x97,0xF3,0xF6,0x34,0x74,0x7A,0xCF,0x92,0x17,0x86,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x2D,0x54,0xC7,0x85,0x77,0x2F,0x0B,0x17,0x02,0xBC,0x7B,0x5F,0xB8,0x19,0x99,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x29,0xCD,0xD0,0x15,0x25,0x71,0x00,0xD9,0x00,0xAC,0x5D,0x2F,0xDF,0x96,0x97,0x6F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x07,0xC8,0xA3,0xC9,0xA8,0x51,0xD4,0x20,0x6D,0x3D,0x62,0xC4,0xA5,0xB3,0x2A,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x96,0x5A,0xF8,0xEA,0x7D,0xDE,0xBC,0x25,0xB1,0xB4,0xAD,0xC7,0xFD,0x70,0x7A,0xF2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0x50,0x7F,0xC7,0xDF,0x7C,0xD9,0xD3,0xB0,0xE3,0x28,0x74,0x78,0xC8,0x29,0x95,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0x8F,0x77,0xB5,0x15,0x39,0x01,0x18,0x72,0x8F,0xD5,0xC5,0x01,0x01,0xED,0xEA,0xB3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0xF6,0x6A,0xA7,0xBE,0x71,0x36,0x2F,0x90,0xCD,0xD2,0x95,0xFE,0x55,0x50,0x6E,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0x0A,0x37,0x40,0x02,0x15,0x1C,0x0F,0xFE,0x07,0x53,0x3D,0x41,0x49,0xC5,0x7B,0xBA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0xC2,0x57,0x54,0xA3,0xF4,0x2B,0x81,0x4A,0x6E,0x8C,0x3D,0x2E,0xC5,0x23,0x00,0xDD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0x06,0xCF,0x95,0x75,0x50,0xC6,0xBE,0x9E,0x15,0x6E,0x92,0x33,0x96,0x4E,0xF0,0xDA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0x1D,0x83,0xAC,0xB9,0x59,0x1E,0x69,0x2D,0xFC,0x05,0xF4,0xFD,0x7E,0x0F,0xF8,0x07,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x44,0x53,0x30,0xAC,0x19,0x2B,0x85,0x4D,0x75,0x48,0x73,0x03,0x24,0x24,0xF0,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x20,0xE1,0xA6,0x93,0x7E,0x4A,0xE2,0x4C,0x72,0x78,0x84,0x1B,0xA1,0x66,0x7F,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0x81,0x3B,0xAF,0xB3,0xF5,0x4C,0xD0,0x3F,0xCF,0x17,0x49,0x95,0x6D,0xA4,0x7B,0x98,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x5F,0x7C,0x7A,0xD2,0x48,0x99,0x04,0xCE,0xB4,0x54,0x91,0x0F,0xE3,0xE5,0xFB,0x07,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0x7A,0x8F,0xE5,0xBA,0xF9,0x35,0xB8,0x2D,0x72,0x6B,0x4B,0xF0,0xA6,0x0A,0x49,0xC3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0xF7,0xEC,0x30,0x65,0xE9,0x48,0x98,0x94,0x64,0xDF,0xAB,0x98,0x22,0xE6,0x3B,0xBB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x97,0xF0,0x5C,0xCA,0x5C,0xBE,0xB4,0x1A,0x7D,0xAD,0x59,0x32,0x38,0x3C,0x68,0xC0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0xA3,0xC3,0xD7,0xFB,0xE2,0xDF,0xDF,0x02,0x1E,0xDC,0x29,0x32,0x17,0xD9,0xEA,0xCC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x85,0x45,0x69,0xE0,0x7E,0xD9,0x78,0xE4,0xC4,0x50,0x80,0x38,0xF4,0x94,0x7F,0x58,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xEB,0x09,0x59,0x3B,0x01,0xD7,0x2A,0xA6,0x09,0x11,0x9B,0x9B,0x40,0x0F,0xF3,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xF1,0x04,0x78,0x73,0x79,0x1F,0x7E,0x79,0x63,0x33,0x4D,0xEA,0x1E,0x4F,0xE7,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x3C,0xFB,0x44,0xE9,0xFC,0xBD,0x1B,0x2F,0x62,0xBD,0x10,0x0E,0xCA,0xB6,0xA3,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x49,0xCD,0xE4,0xE3,0x61,0x0F,0xD8,0x39,0x68,0xF8,0xFB,0x11,0x7C,0x1F,0x3D,0x46,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0x82,0xD8,0xE2,0x97,0x86,0x6D,0xEE,0x2D,0x56,0x7F,0xDF,0xE3,0x46,0x2A,0x4B,0xD2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x36,0xF6,0x75,0xC2,0xB0,0x11,0x7F,0x74,0x49,0x76,0x12,0xF5,0x2B,0xC0,0x48,0x55,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x92,0x0D,0xF7,0x58,0x99,0xFE,0x2E,0xB8,0xC8,0x37,0xCA,0xAF,0x9E,0x08,0xF0,0xC0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x19,0x6D,0x47,0x2A,0xD2,0x71,0xCD,0xB6,0xFD,0x2E,0x87,0x96,0x5C,0x34,0x99,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xB0,0x6D,0xC7,0xD1,0x9A,0x24,0xB3,0x3C,0x5D,0x56,0xA0,0xB7,0x36,0x70,0xBF,0xA8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x3F,0xAF,0x24,0xB3,0x33,0x14,0x7C,0xE4,0x74,0x10,0x8A,0x59,0x55,0x3F,0x75,0xBC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0x8F,0xAC,0x58,0x53,0xCC,0xF4,0x0F,0xAA,0x49,0x08,0xA7,0xDA,0xB8,0x21,0xE3,0x34,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xE0,0x6A,0xAA,0xB4,0x37,0x4D,0xC5,0xC0,0xA8,0x95,0x1B,0xFC,0x6C,0x1B,0x78,0x3B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0x1B,0x8B,0x17,0xD1,0xAC,0xF5,0x97,0x52,0xEB,0x99,0x0B,0xA3,0x05,0xD3,0x6B,0xFF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0x82,0x29,0x38,0x58,0x52,0xEA,0xCF,0x41,0x09,0x25,0x51,0xD4,0x24,0x03,0x93,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x8F,0xC7,0x3B,0xBD,0xF0,0x60,0xA2,0x2F,0xDE,0x44,0x2D,0xF9,0xE7,0x2C,0x68,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0xBD,0xA9,0xAB,0x85,0x4B,0xE7,0x62,0x61,0x22,0x2C,0x43,0x62,0xB9,0x2E,0xB2,0x7D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xAC,0xB5,0x7E,0xD8,0x7F,0x6F,0x6D,0x74,0x2B,0xCE,0xD4,0x9B,0xD8,0x73,0xA5,0x2C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0x40,0x6A,0x93,0xA9,0x60,0x97,0xCB,0x9D,0x1A,0xFC,0x9D,0xC6,0xC0,0x54,0x8B,0x50,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x69,0x3F,0x18,0xF6,0xB5,0x22,0x5F,0xBA,0x75,0xB7,0xAB,0x28,0x11,0x04,0x60,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x69,0x07,0x54,0x28,0xCC,0x31,0xD8,0xB1,0xD1,0x8C,0xF1,0xB4,0x92,0x59,0xEC,0x14,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x7B,0x53,0x1F,0x2C,0x1D,0x78,0x9B,0x55,0x14,0xF3,0x4D,0x30,0x16,0xA7,0x19,0x2E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x28,0x47,0x79,0xBE,0xBC,0x2A,0x76,0xF3,0x78,0x14,0x32,0x5E,0xA0,0x0F,0x8C,0x6C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x9C,0x14,0x01,0x24,0xDB,0x7C,0xFC,0xA5,0x93,0x60,0x64,0xA8,0xE7,0x03,0xB1,0x21,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0x1D,0xED,0x5F,0xEB,0xD1,0x1E,0x53,0x70,0x56,0x76,0xD6,0x25,0xF7,0x99,0x15,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0xCE,0x02,0x92,0xC9,0x1F,0x3F,0x8A,0xD3,0xEC,0xE1,0x9F,0xE8,0xB2,0xF5,0xD2,0x85,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xEF,0x1B,0xA8,0x14,0x05,0x8E,0xA3,0xF6,0x6C,0xC6,0x32,0x9D,0x7B,0x7C,0xAF,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0xEF,0x3B,0x59,0x3E,0x31,0x12,0xC1,0x49,0x64,0xBE,0xE3,0xEE,0x8B,0x4C,0xFF,0x44,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x1C,0x6F,0x2C,0x8E,0xD1,0x79,0xD4,0x88,0x3C,0x78,0xAC,0x71,0x4E,0x87,0x0E,0x9B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0x96,0xE5,0xCF,0x25,0xD7,0x4D,0xAF,0x28,0x36,0x87,0x45,0x2C,0x3D,0xEF,0x91,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0xB1,0x9A,0x8A,0x50,0x91,0x2C,0xC6,0x59,0xF8,0xE0,0x21,0x45,0x78,0x62,0x1F,0x7F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xC3,0x3B,0x46,0x33,0x67,0xB4,0xD9,0x0E,0x2D,0x69,0x40,0x83,0xF4,0x94,0x17,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x52,0x71,0x40,0x1B,0x74,0xEA,0xA8,0xA1,0xD4,0x69,0x1E,0xC0,0xAB,0x10,0x71,0xC9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xD1,0xCD,0xA5,0x80,0x61,0x04,0x91,0x6C,0xFE,0x86,0x8F,0xF2,0x9A,0x27,0xB6,0xEF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x65,0x8F,0xCB,0x1F,0xC0,0xEF,0x7B,0x33,0x33,0x14,0x6F,0xB1,0xD2,0x10,0x32,0x02,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0x7B,0x1F,0x59,0x8A,0xE2,0xF9,0xC3,0xF9,0x80,0x07,0x09,0xE8,0xA2,0xD4,0x70,0xE6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xF1,0xF7,0x87,0x7F,0x1F,0x84,0x83,0x32,0x27,0xE9,0xC6,0x88,0xE8,0xA6,0x7E,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xFE,0x61,0x43,0x28,0xE2,0xFC,0x5B,0x62,0xFF,0x33,0x37,0x39,0x54,0x7D,0xD6,0x45,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xE3,0x8C,0xCE,0x38,0x6E,0xE9,0xDB,0x7C,0x3B,0xEA,0xB8,0x1E,0x86,0xDB,0x39,0xA3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0xBB,0xB7,0xF4,0xE9,0x02,0x9A,0x6B,0x2C,0x60,0x20,0x45,0xAA,0x5D,0xB9,0x23,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xDD,0xE1,0x32,0x42,0x89,0x67,0xE3,0xA9,0x06,0xFA,0x24,0x74,0x14,0x33,0x6E,0x70,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0x98,0x19,0xAA,0xEC,0x41,0xE1,0xCB,0x7A,0xAF,0xA0,0x62,0x3E,0xCF,0x34,0xC1,0xDD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0x8D,0x9F,0x94,0xE5,0xB0,0x67,0xD3,0xF4,0x76,0xE3,0x08,0x28,0x49,0xC2,0x57,0xD4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xB0,0xA1,0x5E,0x10,0x01,0x16,0x93,0x58,0x05,0x03,0x15,0x68,0x00,0xC7,0x75,0x02,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xDA,0xA5,0xCA,0x9A,0x42,0x17,0x1B,0x81,0x97,0xE0,0xCA,0x0F,0x31,0xEA,0x2B,0x49,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0x00,0x22,0x08,0xB3,0xB5,0x54,0x83,0x53,0x14,0x83,0x01,0xB2,0x4D,0x66,0xEB,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x23,0x38,0xCE,0xFE,0x9D,0x83,0x59,0x82,0xAE,0x6A,0x9E,0x01,0xCC,0x95,0x7A,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0x0D,0xDA,0x7C,0xBB,0xE1,0x95,0xA5,0x61,0x2F,0x0D,0x57,0x12,0x74,0x46,0x7E,0xF4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0xB1,0x2E,0xA7,0x2D,0xC2,0x00,0x4C,0xC2,0x70,0x8A,0x0D,0x03,0xD7,0xF1,0x99,0xFD,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0xEE,0x25,0x94,0x5C,0xDD,0x21,0xCA,0xB1,0x5B,0x40,0x98,0x02,0xF5,0xD5,0x25,0x0A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x88,0xCD,0x8B,0x8F,0xB3,0xB9,0x29,0xD0,0x4F,0x36,0x21,0xAE,0x6D,0x98,0xF3,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x67,0x7F,0x63,0x96,0x3A,0x08,0x8B,0x82,0x3F,0xF5,0x48,0x29,0x43,0xB7,0xF1,0x7E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0xA5,0x22,0x89,0x91,0xCB,0xEF,0x2D,0x8D,0xE9,0x31,0x41,0x36,0x84,0x56,0x0D,0x26,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD5,0x05,0xA3,0x31,0xCB,0xC6,0xA6,0xF8,0x62,0x2C,0x25,0x31,0xC9,0xB3,0x79,0x59,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF0,0x68,0x0F,0xB8,0x7C,0x43,0xC3,0x70,0xEC,0x16,0xA7,0x4D,0x2C,0xC7,0x66,0x03,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x24,0x40,0x4B,0x23,0x17,0x8E,0xC3,0x5F,0x9F,0x22,0xED,0x8E,0x2D,0xFD,0x69,0x26,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x6B,0xAA,0x60,0x96,0xAC,0xF8,0xE3,0x43,0x0B,0x04,0xD9,0xD2,0xD4,0x1E,0x98,0x59,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0x98,0xFE,0xF4,0x32,0xB1,0xAB,0xEA,0xB1,0xC1,0x64,0xD5,0x39,0x95,0x9C,0xD7,0xF1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x7F,0xB4,0x94,0x15,0x2A,0x70,0xDA,0x6F,0x25,0x0D,0xA1,0x32,0x84,0x9F,0x56,0xB7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xDD,0x8F,0x60,0x15,0xC2,0xD9,0x10,0xDD,0x62,0x73,0x2B,0x07,0xF0,0x82,0x90,0x26,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0xB3,0xD9,0xA0,0xAB,0x95,0xD8,0xE4,0x08,0x5C,0x36,0x15,0xA1,0xEF,0xB7,0xEA,0x95,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0x93,0xB3,0x58,0xC7,0x09,0x24,0xF3,0x50,0x7A,0x7D,0x33,0xF1,0x57,0x3C,0x10,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0x09,0x27,0x40,0xCF,0xA0,0x57,0x9A,0x30,0x61,0xFF,0xB0,0x29,0x63,0x6C,0x83,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x98,0xA9,0x45,0x26,0x58,0x2A,0x95,0xFE,0x93,0x3E,0xB9,0xDE,0xBA,0x51,0x65,0x43,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x08,0xB9,0x9D,0x15,0x1E,0xFE,0x14,0x08,0x04,0x2A,0xE1,0x1D,0xCE,0xCD,0x8B,0xDA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0x5D,0x02,0xE2,0x14,0xB0,0x20,0xB2,0xD1,0x79,0x3F,0x40,0x66,0xA9,0xB2,0x20,0x22,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x76,0x4B,0x09,0xA2,0xC0,0x7F,0x65,0x13,0x40,0x03,0x1B,0x12,0x8C,0x82,0x17,0x5E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0x50,0x0E,0x88,0xF3,0xF6,0xEF,0x86,0x7D,0xE9,0xEB,0x4E,0x14,0xC1,0x1B,0x46,0xD7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0xEC,0xBA,0x72,0xAE,0x87,0x21,0xEB,0x52,0xD4,0xA9,0x98,0xF8,0x5C,0x12,0x48,0x8D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0xF8,0xDD,0x90,0xFF,0xC2,0x46,0xA3,0x20,0x22,0x9F,0x81,0x4C,0x5C,0x50,0x04,0x8F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0xA1,0xA8,0xC6,0x5B,0x9D,0x16,0xE1,0xE0,0xE3,0x5F,0xA2,0xB4,0xE1,0x05,0xA9,0x18,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x1B,0x53,0xA0,0x37,0x63,0xEA,0x15,0x2F,0xAE,0x1F,0xF0,0xF9,0xFF,0xB4,0xEB,0x2A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0x28,0x68,0x21,0x75,0x6E,0x71,0xD0,0xB9,0x4F,0x41,0xDB,0xEF,0xA4,0x86,0x8C,0xFA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x0D,0x05,0xA1,0xA3,0xCC,0x7E,0xCF,0xE9,0xBE,0xF6,0xE1,0xE4,0x03,0x78,0x1C,0xD6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0x65,0xD2,0x82,0x3F,0xFB,0xED,0xA1,0x04,0x20,0x7D,0x29,0xA0,0x12,0x52,0xF2,0xB6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0x82,0x7C,0x29,0x9D,0xE8,0xEF,0x01,0xF1,0xB9,0x41,0x5C,0x0E,0x7C,0xBB,0x28,0xF5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0xE5,0x9E,0x95,0x46,0x6B,0x69,0x35,0x6D,0xA1,0x53,0x9B,0xCA,0xF6,0xC4,0x8E,0x74,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0xEE,0x88,0x59,0x53,0x58,0x13,0xD2,0x77,0xC5,0x5D,0xB0,0x40,0x5D,0xF8,0x38,0x8C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x56,0x9F,0xA1,0xBE,0x3B,0x5E,0x69,0x6F,0x09,0x38,0x2A,0xD1,0x4C,0xEB,0x3A,0x72,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xD6,0xB2,0x2A,0x70,0xD1,0xFB,0x13,0x14,0xCC,0x4B,0xE0,0x7E,0x78,0x00,0x0D,0xBB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x53,0xD0,0xDD,0x65,0xEA,0x0F,0x91,0x8C,0x31,0xD9,0x0E,0x06,0x57,0x4B,0x7E,0xA2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0x3E,0x92,0x55,0xAC,0x2E,0xD6,0xEE,0x3F,0x80,0xD3,0x13,0x4F,0x17,0xFC,0xE9,0x7F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0xC0,0x3D,0x1C,0x1A,0x1E,0x3D,0x1F,0xBC,0x34,0xC1,0x03,0xDF,0x48,0x0C,0x17,0xE2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x83,0x8B,0x1C,0x0B,0xC7,0x97,0xC9,0x2E,0x1C,0x91,0x07,0x87,0x56,0xBF,0x35,0xE6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x27,0x4F,0x2C,0x5B,0x15,0x12,0xEC,0x28,0x31,0xEA,0xA6,0xDF,0x2B,0x09,0x19,0xC7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0x4C,0x70,0x16,0x8F,0x45,0xCE,0x7D,0x8F,0x33,0xB7,0xEE,0xB7,0xBC,0x62,0xCD,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x8C,0x80,0x9B,0xD4,0x9F,0x81,0x22,0x6F,0xB6,0x7F,0xE5,0xEB,0x8D,0xB0,0x50,0x82,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0xC3,0xAC,0xD7,0x15,0xC4,0x41,0x41,0x70,0x0A,0xD3,0xB3,0x47,0x6B,0x2A,0x14,0x00,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x18,0x1E,0xDE,0xF2,0x44,0xAC,0x07,0x6B,0x29,0x43,0x73,0x30,0x32,0x18,0xE6,0x41,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x5E,0x62,0xAA,0xA7,0x4C,0xF8,0x39,0x44,0x7E,0xEA,0x92,0x0D,0xD1,0x81,0x1D,0x79,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x75,0x60,0x73,0x67,0xC9,0xD2,0xEC,0xF5,0xD8,0xF7,0xA3,0x8B,0xFC,0x4D,0x72,0x58,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0xC0,0xE9,0xA9,0x21,0x85,0x84,0x69,0x26,0xAF,0x59,0x79,0x07,0x13,0x1A,0x65,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x21,0x17,0x0C,0x0D,0x6A,0x0D,0x1F,0xAA,0x17,0x68,0x2C,0x26,0x00,0xA3,0x1A,0x98,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0xA0,0xC0,0x24,0x93,0xBD,0x94,0x2C,0x0A,0xFC,0x6E,0x28,0xA6,0x66,0x47,0xDE,0x92,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x25,0xD1,0x89,0xA5,0x3C,0x7A,0x7A,0x56,0x3F,0xE8,0x79,0xC4,0x14,0x5D,0x86,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0xD2,0x3E,0x04,0xF3,0x82,0x99,0x53,0x29,0x19,0x11,0x5A,0x04,0xE4,0x44,0xED,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0xC3,0x63,0x43,0xBF,0x89,0x55,0x14,0xDA,0xAC,0x87,0xBC,0xC5,0xEC,0x00,0x80,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x71,0x25,0x50,0x9B,0x8C,0xD8,0xEB,0x1C,0xF3,0x34,0xFD,0xE0,0xF0,0x5F,0x6C,0x47,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xAF,0xE2,0xA1,0x05,0x05,0x2A,0xAD,0xE8,0xE7,0x92,0xC2,0xCB,0xFE,0x94,0x77,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0x89,0x6E,0x57,0xF9,0xD8,0x42,0x61,0xA0,0x8A,0xA1,0x5B,0x41,0x2A,0x62,0x66,0x5B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0xB6,0x09,0x9B,0xC0,0x76,0xEF,0xFF,0x0F,0x43,0x1B,0xE8,0x14,0x9A,0x21,0x8B,0x2E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x3F,0x83,0xE1,0x85,0x8E,0xC9,0x25,0x6F,0xAD,0x44,0x5C,0x5E,0xAB,0x83,0x18,0x38,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0x8C,0x0C,0x1A,0xDC,0x5A,0xB5,0x86,0xF7,0xEB,0xE2,0x21,0xF2,0x87,0xFF,0xA0,0xBE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0x84,0x0F,0x72,0x74,0x50,0xB6,0x0C,0xD8,0x9B,0x86,0x77,0x18,0x28,0x9B,0x94,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0xD9,0xE2,0x71,0xBD,0x1C,0x5C,0x9B,0x7B,0x97,0x09,0x45,0xE0,0xB0,0x98,0xA7,0x0B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0x7B,0xE4,0x9C,0xFA,0x2D,0x6B,0xCC,0x7B,0x0E,0x90,0x67,0x54,0x5C,0x3D,0x96,0xF6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0xCB,0x7B,0x05,0x5B,0x59,0xF3,0x5D,0xFD,0x9F,0xFD,0x9B,0x21,0xEE,0xDF,0x3B,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x60,0x68,0x8D,0xC7,0xC4,0x86,0x35,0x58,0x4F,0x10,0x25,0x11,0x34,0x51,0x1C,0xC1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0x5D,0x85,0xA4,0xF3,0xBC,0x74,0xA8,0xFC,0x41,0x2A,0xA0,0x52,0x7F,0x46,0xC6,0x79,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xC5,0xFB,0x20,0x74,0x12,0x39,0xFA,0x0E,0xB1,0xC7,0xF7,0x3D,0xC5,0x24,0x90,0x39,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x63,0xDE,0x2E,0x20,0x03,0x6D,0x60,0xF5,0xB3,0x5C,0xEA,0xB1,0x19,0x4D,0x9B,0x89,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xF9,0xE4,0xC3,0xAD,0x5A,0xCF,0x5B,0x35,0x97,0x75,0x17,0x53,0xF9,0xAF,0x7F,0x7A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xDF,0x52,0x6F,0xEF,0x46,0x60,0xBE,0x4A,0xCC,0xE2,0x10,0xC5,0x8F,0xC7,0xE5,0x6C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x84,0x3A,0x08,0xB5,0x55,0x7A,0xDE,0x17,0x50,0xCB,0x89,0xC7,0xE9,0x9A,0x40,0x64,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7A,0x5E,0x45,0x48,0xB8,0x4F,0xF8,0x43,0xA8,0xCE,0x94,0xF3,0x5E,0x6E,0x54,0x54,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0xDF,0xD0,0x9D,0x5A,0xE8,0x3C,0xBA,0xB1,0x4B,0x3D,0xF4,0xE8,0x27,0x2F,0xAD,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0xE9,0x41,0x73,0x88,0xBF,0x86,0xBF,0x74,0xC8,0xE8,0x8F,0x20,0xE2,0x1B,0xDD,0x08,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0x07,0xFD,0x43,0x48,0x3D,0x6E,0xBF,0x93,0xC0,0x91,0x68,0x04,0x40,0x49,0x9A,0xBC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x7E,0xE0,0xBB,0x85,0x06,0x85,0x1C,0xF4,0xD5,0x2E,0x21,0x29,0x2D,0x72,0xD3,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1C,0x11,0x70,0x29,0x83,0xA6,0x24,0x4C,0x4A,0xD3,0xBC,0xB1,0x80,0xDD,0xB1,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x28,0x97,0x3A,0x3A,0x5C,0x10,0xA1,0x38,0xCD,0xF6,0x5D,0x44,0x43,0xFC,0x2A,0x42,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0xF9,0x5B,0x52,0xCC,0xE4,0x1A,0x37,0x58,0xA5,0x76,0x00,0x26,0x22,0x78,0x7B,0x69,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0x08,0x3D,0x1F,0xB3,0x8F,0xE2,0x26,0x29,0x33,0x1E,0xA6,0xB9,0x88,0xE1,0xF9,0xD0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0x40,0x29,0x48,0xF6,0x0D,0x6A,0x20,0x3C,0xF7,0x78,0x48,0xFC,0x0A,0x4F,0x70,0xF8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0xFB,0x61,0xD0,0x56,0xFA,0x44,0xD1,0xB5,0xCF,0xD3,0xBF,0x75,0x58,0xE4,0xC5,0xB0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0xDB,0xF2,0x50,0x1B,0xFC,0x7E,0x88,0xEA,0x82,0x7C,0x8E,0x57,0x26,0xBF,0x85,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0xE4,0x7C,0x0D,0xE3,0x7F,0x16,0x30,0x9C,0xE4,0x18,0xA7,0xA8,0xE5,0xD4,0x1A,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0xF0,0x4C,0x52,0xB8,0x20,0xE7,0x9F,0xEB,0x28,0x7B,0x34,0xF0,0x8B,0x5C,0x2A,0x82,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xE8,0x2D,0xB5,0x60,0x57,0x32,0xBE,0xF8,0x17,0x19,0xA9,0xDD,0x74,0xAA,0x34,0x21,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0xBA,0x2C,0x9A,0x23,0xB1,0x55,0xF2,0x1D,0x8A,0x2C,0xC7,0xA8,0x54,0xA5,0x2A,0x27,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x2F,0xC0,0xEF,0x80,0x9C,0x37,0xB8,0x59,0x58,0xA4,0xD1,0x3C,0x3F,0x0A,0xBC,0x89,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0xF4,0x79,0x58,0x92,0xC0,0x3A,0x0C,0x27,0x04,0x6F,0x91,0xD9,0x77,0xD6,0x77,0xB6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0xD1,0xE5,0x46,0xF1,0x63,0x7B,0x3B,0x40,0xA2,0x10,0xB3,0x46,0x0C,0xDE,0xE0,0xA3,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0xD5,0xAE,0xD0,0x1B,0xF3,0x80,0x29,0xB8,0xD8,0x54,0x3C,0xB5,0x8B,0x51,0xBF,0x08,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0x70,0x5B,0x4E,0xA1,0x64,0x40,0x55,0x7A,0x8F,0x5A,0x8E,0x93,0x0F,0x51,0xCE,0x6D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0xF7,0x31,0x2F,0x42,0x25,0x9C,0x83,0x80,0x32,0x82,0xFE,0xBE,0x16,0x8F,0xF7,0x69,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0xDD,0xC6,0x03,0x86,0x91,0xA4,0x81,0xB8,0xBA,0xA9,0x1D,0x0C,0xE1,0x04,0xD3,0x53,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0x79,0xCB,0x83,0xD8,0x37,0xA8,0x25,0x3A,0x2D,0x92,0x37,0xA9,0x18,0xA4,0xB0,0x21,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0xC5,0x87,0x3F,0xD7,0x7C,0x29,0xD3,0x0E,0xC3,0x36,0x3A,0x17,0xC7,0xD1,0x05,0x15,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x17,0xA1,0x84,0x71,0x1B,0x81,0x0A,0x82,0x1D,0xBD,0x71,0x04,0x55,0x67,0x8E,0x80,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0x97,0x8D,0xDF,0x95,0x64,0xB2,0xED,0x2F,0x36,0xA1,0xC9,0xD4,0x28,0xB5,0x59,0xE8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0xEB,0xBB,0x7D,0xC9,0x00,0x90,0xE1,0x36,0x2C,0xE2,0xAF,0x55,0xFF,0x83,0x50,0x33,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0x9A,0xCB,0x93,0x4D,0x5C,0xCB,0x08,0x5D,0xC4,0xCA,0x69,0x21,0x11,0xFF,0x3B,0x19,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x53,0xFA,0x9C,0xC8,0xE5,0x44,0xB5,0x22,0xD0,0x86,0xF2,0x94,0x2E,0x19,0x22,0xDB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0xD8,0x95,0x71,0x2E,0xDC,0x08,0x9D,0x09,0xD2,0xDC,0x4F,0xE8,0x2C,0x41,0xB3,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x50,0x90,0x05,0xA4,0xAC,0xF8,0x0A,0xA3,0x0D,0x48,0xCE,0xCD,0x3E,0xC6,0xC6,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0x42,0x48,0xD7,0xE7,0x4C,0x4A,0x70,0x63,0x29,0x18,0x68,0xA6,0xA6,0x8E,0x17,0x5C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0xE1,0x31,0x74,0x2A,0x91,0xD5,0x80,0x80,0xAF,0xC7,0x35,0xB9,0x4A,0x36,0x08,0x25,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0x6F,0x48,0x2D,0xFD,0x5B,0x19,0xA2,0x68,0x54,0xC8,0xD0,0xB1,0x85,0x71,0x27,0xCE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0xBF,0x22,0x6B,0x8F,0x0B,0xD7,0xCC,0x1F,0xE1,0x68,0xB1,0x08,0x1F,0x77,0x92,0xEA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x75,0x91,0xDD,0x94,0xF2,0xD0,0x97,0xC7,0x2E,0x70,0x11,0x7D,0x2E,0xCC,0x30,0xF1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0x3A,0x7A,0x30,0x33,0xF4,0x25,0x7A,0xB0,0x74,0x86,0xE1,0xC3,0x99,0xE4,0x1A,0xE7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0xEB,0x86,0x5A,0x6E,0xFF,0x03,0xF4,0x1C,0x89,0x68,0x9F,0x53,0x2A,0x2B,0xE3,0x7D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0xB3,0xCC,0x29,0xD5,0xC2,0x15,0x5F,0xBF,0x90,0x27,0x17,0x51,0xFE,0xCB,0x96,0x25,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0xF1,0x1D,0x12,0x2A,0x6F,0x6E,0x46,0x66,0x06,0xEB,0xB3,0x39,0xE0,0x91,0x53,0x7C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x5A,0x72,0xDD,0x53,0xB1,0xF4,0xE8,0xF3,0x1F,0x81,0x05,0x85,0x26,0x78,0x53,0xF4,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xC3,0x11,0xA5,0xA2,0x8A,0x55,0x2F,0x2C,0x9F,0xD4,0x1F,0xA1,0xED,0x1F,0x4A,0x35,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0x0F,0x4E,0x91,0x38,0x18,0x27,0xDF,0xB1,0x1F,0x5C,0x1B,0x15,0x6F,0xF9,0xA0,0xEB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x55,0xC0,0xD1,0x11,0x67,0xFB,0x8D,0xD4,0x5B,0x19,0x2D,0x0A,0x65,0x7D,0x90,0x52,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0xE4,0x3F,0x2F,0x4C,0xE7,0xE4,0x2F,0xB9,0x9E,0x19,0xA9,0x65,0x1E,0x86,0x32,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x0D,0xC2,0x6C,0x4D,0x0C,0xFE,0x7F,0xC3,0xD7,0xD5,0xEB,0xD4,0x82,0x9A,0x05,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x4F,0x42,0x0F,0xBD,0xF1,0xD2,0xC3,0x9F,0x1D,0xED,0xDC,0x29,0x3F,0x95,0x55,0x20,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x10,0xA3,0xE6,0x6D,0x54,0x49,0x25,0x93,0x3F,0x53,0xBA,0x78,0x95,0x86,0x9B,0xDE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x44,0x09,0x54,0xA1,0xC0,0x81,0x1D,0xEA,0x23,0x4B,0x82,0x53,0x45,0xBB,0x4A,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x24,0x48,0x1A,0x7D,0x65,0x02,0xA1,0xDF,0x80,0x6F,0xBB,0x63,0xB7,0x1D,0x70,0xB1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xA1,0xC7,0x42,0x4C,0x9C,0xEF,0x01,0x1F,0xB2,0xF5,0xC3,0x67,0xCD,0xB8,0x7E,0x9D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xDE,0x00,0xEE,0x08,0x66,0x26,0x8E,0xFF,0xB6,0xF5,0xA3,0x98,0x43,0xC8,0xC2,0x8F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0xB5,0x2A,0xC6,0xA2,0x1D,0x4A,0x2A,0x82,0x8B,0x73,0x1C,0x18,0x47,0xA5,0x3D,0x15,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xAF,0xF8,0x9C,0xD0,0x56,0x9B,0x12,0xD6,0xEE,0xB8,0x40,0x97,0x99,0xF2,0xAA,0x5B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0xC7,0x34,0xCF,0x5A,0xE9,0x62,0x4D,0xAB,0xBA,0x20,0x14,0xD2,0x2E,0xB3,0x96,0xE5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x65,0x96,0x24,0x5F,0x7A,0x88,0xC6,0x6D,0x1A,0x4A,0xE3,0x8D,0x13,0x1C,0xC6,0x8A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0x82,0xCD,0xB2,0xF6,0xE2,0xDC,0xAC,0xA8,0x78,0x7A,0x9F,0xF5,0x73,0xB2,0x85,0x5E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x5D,0xCF,0x7B,0x89,0xEF,0xA3,0x39,0x3F,0x9D,0x95,0x04,0x8C,0xB9,0x43,0x4D,0xD2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x78,0x2F,0x33,0x30,0xBB,0x30,0x7D,0xAB,0x4A,0x13,0x30,0xD2,0x81,0x62,0xCF,0xE0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x65,0x6B,0x91,0x41,0xFE,0x0B,0x59,0x14,0x11,0x83,0x1A,0xA5,0x95,0x51,0x80,0x40,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0xD2,0x5C,0x58,0xE0,0x25,0x3B,0x3B,0x7A,0x80,0x2D,0xD6,0x4B,0x09,0x99,0xD2,0x32,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x34,0xBC,0xEB,0x08,0x48,0x4D,0x23,0x2E,0x6C,0x30,0xFF,0xFE,0x23,0x32,0x8C,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0xA8,0x94,0x7A,0xC1,0x3C,0x2C,0x2E,0x08,0x4E,0xBF,0x94,0x00,0x93,0x71,0x1B,0x5B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0xB1,0x58,0x4F,0x12,0x8E,0xE1,0xAD,0x0B,0xD3,0xA8,0x30,0xF4,0xEC,0x51,0x6E,0x5E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x12,0x58,0x74,0x90,0xE3,0xD7,0x4F,0x98,0xC7,0x9B,0xA8,0x65,0x5F,0xC8,0x46,0x00,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xB3,0x86,0x3F,0xD1,0x0D,0x13,0x73,0xDC,0x67,0x78,0x6F,0x57,0x51,0x57,0xE3,0xC6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xEE,0x8F,0xB7,0xB6,0xCE,0x74,0xE0,0x5B,0x0B,0x5F,0xCA,0x0E,0x4C,0x50,0x60,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0xDF,0x3D,0x1A,0xCA,0x24,0x40,0xCE,0xCD,0x1D,0x4F,0xAD,0x4A,0xB5,0x91,0xA2,0x86,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x67,0x68,0x50,0xBA,0xE4,0x1E,0xB5,0x18,0x0B,0x5B,0x87,0xA5,0xA4,0x32,0x0A,0xF7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x68,0x5D,0x93,0x93,0x80,0x76,0xA5,0x59,0xEB,0xE4,0x6D,0x66,0x5F,0xC6,0x97,0x89,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0x65,0x56,0x1D,0xC1,0x83,0x3C,0x8E,0x83,0x04,0x56,0x95,0x09,0x9A,0xDB,0x79,0x91,0xD9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0x56,0xE2,0x67,0xF8,0x8D,0x48,0xAC,0x0E,0xB2,0x64,0x24,0xDE,0x35,0x86,0x9C,0xFC,0xF0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0x5D,0x81,0xEB,0x40,0x2B,0x7D,0xBB,0x01,0xED,0x1C,0xF8,0x70,0x59,0x18,0x05,0x1D,0x50,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x17,0xB4,0x61,0xF5,0x14,0x91,0xEF,0x87,0xA0,0x14,0xE3,0x13,0xEC,0xAB,0x7D,0x49,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xE5,0x2A,0xAB,0x9B,0x5C,0xFE,0x9E,0x12,0x1F,0x5E,0x09,0xAD,0xB8,0xBB,0x2C,0xAA,0xDC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0xFC,0x52,0x16,0x8C,0x33,0x9E,0x35,0x89,0x2D,0x17,0x1F,0xE5,0x04,0xFA,0x55,0x3B,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x02,0x71,0xE1,0x4D,0x68,0x1B,0x25,0x14,0x38,0xEB,0x10,0x9F,0x83,0xA8,0x98,0xEE,0xAE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0x09,0xFE,0x37,0x91,0xF9,0xA5,0xDD,0xB9,0xCD,0xEC,0x48,0xAE,0x17,0x0F,0x5D,0x3C,0x31,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x9C,0xAA,0xEC,0x40,0xEF,0x88,0x7F,0xA7,0xA3,0x55,0x03,0x09,0x8B,0x91,0xAD,0x8B,0x26,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x9A,0xD7,0x1F,0x86,0x21,0xB2,0x8A,0x92,0x3E,0x0E,0xEF,0xB3,0x1C,0x3A,0x23,0xAB,0xFF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x79,0xF9,0x2F,0x31,0xD7,0x1A,0xCA,0xF2,0xCC,0xD5,0xD2,0xEC,0x15,0x19,0x70,0x89,0x1F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x93,0xF9,0x52,0x98,0x8B,0x94,0x68,0xA5,0x6E,0xE2,0x90,0x5F,0x83,0x7B,0x72,0xBE,0xFB,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x54,0x63,0x58,0x0E,0x52,0x2E,0xF0,0x4C,0xC5,0x4A,0xA5,0xBE,0xCA,0x7B,0x4B,0x01,0xE6,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xD3,0x5D,0x7C,0xD3,0xFE,0x0E,0x78,0x7C,0x36,0xD9,0x82,0x92,0x74,0x8B,0x21,0xDB,0x71,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xA5,0xD3,0x06,0x95,0xB6,0xEB,0xC0,0x76,0x2A,0xA3,0x3A,0xDB,0x6F,0x75,0xCC,0xA7,0x8E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0x0A,0x92,0xF9,0x3C,0x61,0xC1,0xEA,0x08,0x70,0x02,0x4D,0x4D,0x66,0x4C,0x67,0x6A,0x3F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xC4,0x0C,0xBE,0x9F,0x9F,0x43,0xA8,0x4E,0x59,0xF3,0x4D,0x4B,0x32,0x36,0xFA,0x8F,0x44,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0x0A,0x16,0xEE,0x1B,0xE1,0x21,0xB9,0x2D,0x53,0x45,0x43,0x3D,0xAA,0xCD,0x46,0xEB,0xF7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x31,0x05,0x9C,0x90,0xD4,0x5F,0x22,0x26,0x5E,0x79,0x8D,0x0A,0xE9,0x23,0x2C,0x02,0xA7,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0xC6,0x3A,0x68,0xB2,0x97,0x79,0x82,0xAE,0x9A,0xF2,0x67,0x03,0x90,0xE9,0x19,0xD0,0xC5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x3F,0xD4,0xDF,0x9F,0xD2,0xBC,0x4F,0xEC,0xCD,0x03,0x24,0x64,0x0A,0x1F,0xBC,0xF4,0x27,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x75,0xA5,0x30,0x33,0x19,0xD0,0x1A,0xC1,0xD9,0x07,0x9A,0x81,0x55,0xBC,0x5F,0x35,0xA0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x15,0x60,0x89,0x2F,0x66,0x2D,0xD7,0x99,0xF1,0x01,0x8F,0xBD,0x99,0x20,0x3E,0x39,0xA5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0x69,0xB2,0x82,0xCE,0x17,0x6D,0xAB,0x5E,0x65,0x12,0x11,0x6E,0x32,0x61,0x31,0x75,0xE1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x1F,0x91,0xF0,0x6D,0x92,0xAF,0xDD,0x60,0x7E,0x93,0x8A,0x27,0x4A,0x90,0x52,0x97,0xD5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0x0E,0x3D,0xDB,0x48,0xFD,0x27,0x96,0x3A,0xAE,0x96,0x73,0xF2,0x35,0x3F,0xB5,0x77,0x0F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0x58,0x88,0xA4,0xF5,0x8D,0xA7,0x28,0x95,0x4A,0x1F,0x4B,0xD4,0x65,0xF6,0x38,0xE5,0xCA,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x19,0x93,0xF8,0x55,0x78,0x1E,0xCC,0xA9,0x74,0x72,0x34,0x45,0x69,0x39,0x9E,0xA9,0xB8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xD4,0xDC,0xDE,0xA6,0x73,0xEF,0x6E,0x22,0xE6,0x3B,0x15,0x3D,0x58,0xD0,0x3B,0xF6,0x2F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xB4,0x9F,0x62,0x3D,0xC9,0xDA,0xB9,0x54,0xF6,0x98,0xAD,0x25,0x76,0x95,0x61,0x8B,0x36,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0x88,0x78,0x06,0xC7,0x8B,0xE5,0xA7,0x33,0xF4,0x38,0x73,0xBC,0x02,0x51,0x27,0xED,0xE8,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x0E,0xC3,0x58,0x73,0xE1,0x7F,0xCB,0x61,0x78,0x4D,0x3C,0xED,0x77,0xCA,0xF2,0x90,0xCF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x13,0x64,0x11,0x78,0x93,0x7D,0xCC,0xC5,0x30,0xFF,0x10,0xCB,0xAF,0x7A,0x88,0x19,0x98,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![],vec![0x7F,0x8A,0x2C,0xF4,0xF5,0x2A,0xA4,0xD6,0xB2,0xE7,0x41,0x05,0xC3,0x0A,0x27,0x77,0xB9,0xD0,0xC8,0xAE,0xFD,0xD5,0x55,0xDE,0x35,0x86,0x1B,0xD3,0x01,0x1F,0x65,0x2F,0x72,0x56,0x45,0x6F,0xA9,0x35,0xAC,0x34,0xBB,0xF5,0x5A,0xE1,0x35,0xF3,0x32,0x57,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,],vec![0xD4,0x4A,0xEA,0x92,0xB6,0xE9,0x90,0xBB,0xB7,0xEC,0x92,0xC8,0xD5,0x4D,0x90,0x63,0x80,0xD9,0x45,0xCF,0x66,0x75,0x93,0xED,0xED,0xA5,0x95,0xF7,0x7E,0x59,0x56,0x4D,0x10,0x1E,0xD9,0xBB,0x4A,0xB9,0x2D,0x77,0xF4,0xD6,0x24,0x4A,0x54,0x8B,0xFA,0xC2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,],vec![0x8D,0x72,0x78,0x2E,0x41,0xDB,0x97,0x32,0xA1,0x62,0x1F,0x57,0x1E,0x94,0x1F,0xA8,0xA8,0xFC,0x72,0xF9,0x24,0x6D,0x3E,0x95,0xC6,0x37,0x0D,0x7A,0x63,0x4B,0x5D,0x42,0x0E,0xB6,0x7D,0xAF,0xF8,0x96,0x91,0x1B,0x9D,0xAB,0x71,0xA7,0xFA,0xE9,0x30,0xA0,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,],vec![0xBC,0x63,0xC2,0xDB,0x99,0x52,0xBA,0x4D,0xB0,0x49,0x6A,0x6F,0x0F,0x7E,0x79,0xE2,0x35,0xC2,0x33,0xD0,0x8D,0x5F,0xD3,0xB0,0x74,0xC4,0x8B,0x2C,0x4A,0xB2,0x17,0x67,0x42,0xF9,0x45,0x57,0xB0,0x75,0x2C,0x1B,0xEB,0xA3,0x4C,0x7E,0xCE,0xD0,0xB4,0x7D,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,],vec![0x64,0x35,0x94,0x3E,0xD3,0x25,0x08,0x76,0xA7,0x70,0x30,0x99,0xAD,0x26,0xB3,0x11,0x94,0xC7,0x4F,0x82,0x60,0x78,0xD0,0x3F,0xE1,0xE5,0xBC,0xAE,0x3C,0xB3,0xE5,0xC7,0xB4,0x3A,0x96,0x79,0x25,0x87,0xBC,0x00,0x77,0x0D,0x86,0x7A,0xC7,0x36,0x35,0xEC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,],vec![0x1F,0x66,0x49,0xAE,0x35,0xBC,0xB8,0x51,0x1B,0x3F,0x60,0x02,0x0C,0xEF,0xEE,0xE9,0x95,0xE4,0x0C,0x04,0xC5,0xB7,0x5A,0xF4,0xCE,0x52,0x6E,0x02,0x70,0x4E,0xFC,0x96,0x80,0x2A,0x83,0x06,0xF3,0xE8,0xAD,0xA9,0x57,0x93,0x55,0x5E,0xAD,0x0D,0xFC,0xE9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,],vec![0x48,0x4D,0x35,0x17,0xB7,0xA9,0x47,0x71,0x4B,0xB6,0x1E,0x14,0x8A,0xEA,0xF4,0x49,0xF3,0x8D,0x03,0x71,0x44,0xD3,0x53,0x39,0xD2,0x50,0xCA,0xC6,0x0B,0x7F,0x02,0x9E,0x1D,0x1F,0xF8,0xAB,0xC2,0x22,0xAD,0x01,0x4E,0xB3,0xC5,0xA4,0x7B,0x10,0x05,0x76,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,],vec![0xD4,0x49,0xBB,0x41,0x9E,0x03,0x83,0x98,0x3F,0x9F,0xBB,0xA8,0x3C,0x19,0x25,0x2A,0xF0,0x1B,0x3C,0x82,0x7C,0x97,0x55,0x4D,0x5D,0xD6,0x16,0x39,0x65,0x21,0x09,0x14,0xFB,0x0A,0x41,0x70,0xEF,0xB0,0xA1,0x63,0xAB,0x71,0x96,0xEE,0x01,0xE6,0xAD,0xF9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,],vec![0x69,0xA7,0xB8,0x36,0xDC,0xBB,0x0B,0x40,0x3F,0x3F,0x35,0xE4,0x6F,0xFF,0xC9,0xA7,0xA6,0x87,0x2B,0x8D,0xF1,0x19,0x1C,0x6E,0x82,0x89,0x29,0xCC,0x14,0x1F,0x9C,0x44,0x01,0x9D,0x8C,0xD3,0xB1,0x4B,0xB0,0xDB,0x6B,0x91,0x89,0x30,0x5E,0xF0,0xB4,0xFC,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,],vec![0x77,0xDF,0x80,0x1F,0xBC,0x82,0x48,0xE0,0xF1,0xA6,0x6F,0xDC,0x53,0x0D,0x19,0x38,0x51,0xE3,0x44,0xDE,0x49,0x4A,0x31,0xCB,0x5F,0xC7,0xB6,0xC8,0x73,0xC7,0x9A,0xE6,0x20,0x71,0xB7,0xF4,0x9A,0x02,0xA3,0x06,0xF6,0x4C,0xA8,0xBA,0x12,0xF7,0x30,0x8B,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,],vec![0xC0,0x49,0xBA,0x47,0x1E,0x9E,0x50,0x53,0x85,0x33,0x0C,0x9F,0xC1,0xBD,0x01,0x82,0x16,0x17,0x54,0x52,0x18,0x50,0x8A,0x7E,0x08,0x28,0x4B,0x05,0x01,0x0F,0x79,0x25,0xEC,0xF6,0xA2,0xC0,0xF8,0xE3,0xF4,0xFE,0xE3,0x2D,0xEF,0x19,0xDF,0x78,0x97,0x10,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,],vec![0x3D,0x80,0x99,0x47,0xF9,0x7B,0xEF,0x5A,0x4E,0x50,0xFB,0xB9,0xD2,0x38,0x7E,0xE0,0x6A,0xC3,0x9A,0x46,0xFD,0x10,0x8E,0xC8,0xD4,0x13,0xFE,0x4B,0x48,0x0B,0x93,0xDC,0xC0,0xA6,0x26,0x7B,0x57,0xD4,0x00,0x5D,0xC1,0x5F,0x42,0xE6,0xA5,0x51,0xE9,0xB5,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,],vec![0x2F,0x1F,0x1B,0xFE,0x06,0x36,0xC0,0x5C,0x1A,0x15,0xFD,0x9F,0xDE,0x3F,0x2B,0xE4,0x2E,0xE9,0x8A,0x28,0xD1,0x9B,0x7C,0x8D,0xC8,0x7F,0x1E,0xD8,0xEC,0x68,0x54,0x47,0x9A,0x0D,0x6C,0xDB,0x44,0xCB,0xB2,0x25,0xDF,0xA5,0x93,0xD0,0x65,0x70,0x97,0x9F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,],vec![0x1D,0xC6,0xE7,0x56,0x38,0x9C,0x70,0x85,0xDF,0xAE,0x61,0xC9,0x4A,0xA9,0xEA,0x63,0x20,0xF3,0x58,0xD0,0xBB,0x24,0xE9,0xB8,0x31,0x4C,0xA5,0x80,0xAA,0x84,0xD3,0x2C,0x53,0xE2,0x81,0xBE,0xEA,0x0E,0xFA,0x05,0x2C,0xFC,0x9C,0xF2,0xE9,0xAE,0xBB,0xB2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,],vec![0xBA,0x47,0xFE,0x03,0x35,0x88,0x1F,0x1E,0xAA,0x0A,0x36,0x93,0xC6,0x30,0xAE,0x6B,0x51,0x02,0xF1,0xA5,0xBD,0x9D,0x22,0x5C,0xF6,0xAD,0x14,0xC1,0x1C,0x3A,0xA5,0x30,0x16,0xB9,0xB2,0xA8,0x49,0xF8,0x34,0x62,0xF3,0xA8,0x3F,0x46,0x64,0x71,0x3E,0xA2,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,],vec![0x1A,0x25,0x9C,0x7E,0x82,0xBF,0x80,0x48,0x5E,0x65,0xD7,0xEF,0xCE,0x7C,0x35,0x03,0xDE,0x63,0x21,0x66,0xF0,0xCA,0xA7,0xBE,0xD0,0xB1,0xE1,0x36,0x30,0xF0,0x0A,0x2C,0x27,0x68,0x06,0x46,0x7E,0x20,0x2F,0xC0,0xE1,0xB7,0x49,0x13,0x97,0x51,0xB9,0x3C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,],vec![0x9A,0x93,0xDE,0xC6,0x80,0xCA,0x51,0x4C,0x36,0xE7,0xDD,0x94,0xE6,0xC7,0x41,0x7A,0xF5,0xF2,0xB8,0xD8,0x4F,0x39,0x10,0x79,0x8E,0x5A,0xDE,0x9F,0xEA,0x0C,0xC4,0xC0,0x38,0x3B,0x23,0x23,0xED,0x91,0x4C,0x59,0xCE,0x9B,0xDC,0x4E,0x02,0x12,0xF6,0xEE,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,],vec![0x70,0x46,0xE3,0x68,0xAA,0xF7,0x1B,0x67,0x4E,0x9C,0x56,0xF0,0x0F,0xCF,0xC5,0x63,0x57,0x69,0x73,0xC2,0x77,0xF1,0x1B,0x28,0x0F,0x98,0xA6,0x49,0xFD,0x82,0x0A,0x61,0xAF,0x02,0xB4,0xD3,0xE6,0xB9,0xB6,0xD8,0xD3,0x39,0xF7,0x62,0xA5,0xCF,0xDC,0x18,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,],vec![0xA1,0x4C,0x5C,0x11,0x7D,0xB2,0xB0,0x2E,0x6F,0x7B,0x8E,0x2E,0x88,0x1C,0xCD,0x1B,0xFB,0x4C,0xF9,0x5F,0xDC,0xA5,0xDC,0x6C,0x75,0xD7,0xDA,0x86,0x4E,0xA1,0x31,0xBD,0xD4,0x3F,0xCB,0xC7,0xC4,0x1E,0xA4,0xAA,0x93,0xF7,0x1C,0x9D,0xC2,0x3D,0x64,0x3E,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,],vec![0x38,0x72,0x90,0x60,0x17,0x8A,0x8D,0xB8,0x9A,0x51,0x43,0xAA,0xB5,0x21,0xF2,0xF5,0xCF,0xED,0x52,0x25,0x36,0xF9,0xC8,0xCD,0xDF,0x8A,0x1F,0xBE,0xFD,0x8E,0xC6,0x5E,0xE3,0x13,0xF3,0xB2,0x32,0x47,0x6C,0x1B,0xEF,0x5D,0x4B,0xB4,0x2D,0x04,0x34,0xBF,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,],vec![0x40,0x5F,0x3C,0x3F,0x45,0x1E,0x62,0x33,0xB8,0xB3,0xE0,0xD7,0xBB,0xC8,0x65,0x3C,0xCB,0x2E,0xFB,0x12,0xF0,0xD6,0x07,0x7B,0xEA,0x87,0xD3,0x73,0x6E,0x3D,0x3F,0x6B,0x22,0xCA,0x6D,0xF8,0x24,0x99,0x68,0x01,0x3C,0x2D,0x94,0xBF,0xC4,0xD3,0xFE,0xC9,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,],vec![0x52,0x85,0xC6,0x22,0x15,0x12,0xE8,0x65,0xF1,0xC1,0x80,0x19,0x2E,0xC3,0xA4,0x35,0xB5,0x96,0xD0,0x9E,0xB0,0x77,0x5E,0x88,0x2B,0x4F,0xC0,0xC5,0x76,0xFF,0x75,0xC9,0x55,0xA2,0x72,0xA9,0x9A,0xAD,0x4D,0xFE,0xCE,0xA2,0xA5,0x51,0xBC,0xEB,0x1D,0x42,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,],vec![0x2F,0xEB,0xB5,0x3D,0xFC,0x47,0xE2,0xA2,0xD4,0x83,0xE8,0x45,0x84,0x26,0x46,0x91,0x63,0xD7,0x04,0x2D,0x38,0x2E,0x1A,0x29,0x17,0xF9,0xC2,0xD6,0xC6,0x8B,0x15,0x4E,0x10,0xF4,0xC2,0xF2,0xF3,0xDA,0x9C,0x3C,0x8B,0x28,0x19,0x51,0xC6,0x30,0x8E,0x05,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,],vec![0x55,0x86,0x5A,0x2C,0xC2,0xB2,0x4C,0x42,0xE7,0xCF,0x1C,0xDC,0x59,0x57,0x6F,0x0A,0x4B,0xAA,0xE5,0x32,0xDE,0x20,0xC9,0x03,0xD1,0x8F,0xDE,0x67,0xBC,0xB5,0x69,0xFD,0x94,0x8D,0xF1,0x3E,0x5A,0x1C,0xE5,0x46,0xC8,0x24,0xCB,0xC7,0x8B,0x5E,0x89,0x4F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,],vec![0x0F,0x17,0x81,0x90,0x37,0xC9,0x54,0x31,0xD1,0x74,0x58,0x4B,0xF3,0xB8,0x17,0xF5,0xE7,0xD6,0x7B,0x6F,0xEC,0x3A,0x1B,0xBB,0x66,0xD9,0xA0,0x63,0x20,0x77,0x1F,0x12,0x1E,0x91,0xE7,0x74,0x65,0xE0,0x5A,0x85,0x85,0xE5,0xBB,0xC9,0x2C,0xBA,0x4C,0x1A,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,],vec![0xFE,0x23,0xC0,0xB6,0x3B,0x08,0xB0,0x95,0x57,0x10,0x66,0xE0,0x47,0x05,0xB5,0xDB,0xDF,0x49,0x3A,0x38,0x10,0x73,0x2B,0x82,0xF4,0xEE,0xC8,0x5C,0xBE,0x6A,0x0E,0x61,0x44,0x51,0x02,0x85,0x42,0x69,0x57,0xA1,0xB0,0x3B,0x72,0x9F,0xA7,0x77,0x4C,0x7F,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,],vec![0xD9,0x8F,0xA9,0xB2,0x68,0xC2,0xE8,0x97,0xC1,0x6E,0xD2,0xF6,0xB1,0x68,0x79,0x23,0xC1,0x23,0x8A,0x49,0xE0,0x5D,0x9E,0x42,0x2B,0xCF,0x11,0xE7,0xC9,0x5F,0x58,0x33,0x9B,0xCA,0x69,0x1A,0x1D,0x04,0x62,0xF2,0xE2,0x16,0xAE,0x0F,0xEF,0x39,0xB0,0x79,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,],vec![0xCB,0x6E,0xB0,0x1A,0x8C,0x06,0xEC,0x8E,0x15,0xCC,0x87,0xA9,0xCC,0x6E,0x82,0xB6,0x24,0x45,0x2E,0xF1,0x80,0xD6,0xB4,0x66,0x86,0xB9,0xE1,0x0A,0x2E,0xB0,0x19,0x62,0xF6,0xBB,0x35,0xCB,0xF8,0x14,0xF7,0x8C,0xAF,0x28,0xBA,0x40,0xE9,0x0C,0xF6,0xB1,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,],vec![0x25,0xA2,0x4D,0x80,0x92,0xEB,0x6F,0x97,0x23,0x42,0x8E,0x31,0x5E,0xF6,0x29,0x1F,0xC6,0x1A,0x4B,0xF5,0xA3,0xF3,0x1E,0x4E,0x02,0x3D,0x7B,0xBF,0x7E,0x0F,0xD4,0x6B,0xF7,0x6B,0xAE,0x3E,0xFC,0x0E,0x63,0xDF,0x0E,0x9C,0xDE,0xF5,0xA8,0xDF,0xCF,0x41,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,],vec![0x1E,0x5A,0x70,0x93,0x37,0x6A,0x60,0xE0,0x8E,0xBD,0x76,0xBF,0x34,0x0E,0x0D,0x98,0x2B,0x87,0xDE,0x88,0x08,0x63,0x8A,0x56,0x9D,0x5E,0x3D,0x22,0x91,0xFC,0xB4,0xB4,0xF8,0xCB,0x2F,0xAE,0xFA,0xAF,0xD6,0xB8,0x8F,0x91,0x8E,0x18,0x85,0xD1,0xC0,0x16,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,],vec![0x68,0x15,0xB4,0xA0,0xEC,0xDA,0xD0,0x15,0x96,0xEA,0xD8,0x7D,0x9E,0x69,0x06,0x97,0x47,0x5D,0x23,0x4C,0x6A,0x13,0xD1,0x0A,0x89,0xEB,0x89,0x9A,0x37,0x9A,0x88,0xD8,0xC7,0xC8,0x92,0x3D,0xBB,0xB7,0xC7,0x17,0xF8,0x12,0x71,0x8B,0xF6,0xE9,0x4E,0x4C,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,],vec![0x7A,0x28,0x85,0x62,0x77,0xCA,0x04,0x77,0xAF,0xF3,0x51,0x44,0xB2,0xE6,0x20,0xFA,0x1F,0xB1,0xE4,0x2F,0x16,0xAA,0xE5,0x67,0xB7,0xA2,0x1D,0x7C,0xE8,0x7A,0x0E,0x14,0xC7,0x09,0x26,0x95,0x40,0xE6,0x9A,0x7A,0x24,0xE4,0xE9,0x68,0x59,0x5A,0xCD,0x63,]),
+    (vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0A,0x0B,0x0C,0x0D,0x0E,0x0F,0x10,0x11,0x12,0x13,0x14,0x15,0x16,0x17,0x18,0x19,0x1A,0x1B,0x1C,0x1D,0x1E,0x1F,],vec![0x76,0x6B,0x3B,0x5E,0x77,0x88,0x27,0x2D,0x39,0xED,0xAD,0x2B,0xCE,0xBA,0xF4,0x16,0x06,0xE6,0x20,0x76,0xA0,0xFD,0x14,0x94,0xB9,0x95,0x27,0xBF,0x45,0xDC,0x13,0x8F,0x1A,0x96,0x06,0xDB,0x25,0x59,0x37,0xB6,0x8E,0x02,0xFE,0xC8,0x3E,0x2C,0x54,0xB9,]),]
+}
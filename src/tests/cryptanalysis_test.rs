@@ -0,0 +1,51 @@
+use crate::gimli_common::{gimli, gimli_rounds, Key, Nonce};
+use crate::gimli_cryptanalysis::{reduced_round_aead_decrypt, reduced_round_aead_encrypt, reduced_round_hash};
+use crate::gimli_encrypt::encrypt_in_place_detached;
+use crate::gimli_error::GimliError;
+use crate::GimliHasher;
+
+#[test]
+fn test_full_rounds_match_gimli() {
+    let message: Vec<u8> = (0..100).collect();
+    let ad: Vec<u8> = (0..20).collect();
+    let nonce = Nonce::new([7; 16]);
+    let key = Key::new([9; 32]);
+
+    let mut hasher = GimliHasher::new();
+    hasher.update(&message);
+    assert_eq!(reduced_round_hash(&message, 40, 24, 1), hasher.finalize(40));
+
+    let mut expected = message.clone();
+    let tag = encrypt_in_place_detached(&key, &nonce, &ad, &mut expected);
+    expected.extend_from_slice(tag.as_bytes());
+    assert_eq!(reduced_round_aead_encrypt(&key, &nonce, &ad, &message, 24, 1), expected);
+}
+
+#[test]
+fn test_reduced_rounds() {
+    let message: Vec<u8> = (0..50).collect();
+    let nonce = Nonce::new([7; 16]);
+    let key = Key::new([9; 32]);
+
+    let mut full = [3; 12];
+    gimli(&mut full);
+    let mut reduced = [3; 12];
+    gimli_rounds(&mut reduced, 24, 17);
+    assert_ne!(full, reduced);
+
+    assert_ne!(reduced_round_hash(&message, 32, 24, 17), reduced_round_hash(&message, 32, 24, 1));
+
+    let mut cipher_text = reduced_round_aead_encrypt(&key, &nonce, b"ad", &message, 24, 17);
+    assert_eq!(reduced_round_aead_decrypt(&key, &nonce, b"ad", &cipher_text, 24, 17).expect("Error in test decryption"), message);
+    cipher_text[3] ^= 1;
+    match reduced_round_aead_decrypt(&key, &nonce, b"ad", &cipher_text, 24, 17) {
+        Err(GimliError::TagMismatch) => {}
+        other => panic!("Expected tag mismatch, got {:?}", other),
+    }
+}
+
+#[test]
+#[should_panic(expected = "gimli rounds run from start_round down to end_round")]
+fn test_reduced_rounds_out_of_order() {
+    reduced_round_hash(b"message", 32, 3, 5);
+}
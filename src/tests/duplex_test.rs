@@ -0,0 +1,133 @@
+use crate::gimli_common::{Key, Nonce};
+use crate::gimli_duplex::{GimliDuplex, GimliPadding, RATE};
+use crate::gimli_encrypt::{encrypt_batch_in_place_detached, encrypt_in_place_detached, GimliAeadBatchItem};
+use crate::GimliHasher;
+
+const KEY: Key = Key::new([0x42; 32]);
+const NONCE: Nonce = Nonce::new([0x24; 16]);
+
+#[test]
+fn test_duplex_hash(){
+    let mut duplex = GimliDuplex::new();
+    duplex.absorb(b"Speak words we can all understand!");
+    duplex.pad(GimliPadding::HASH);
+    let mut output = [0; 32];
+    duplex.squeeze(&mut output);
+    assert_eq!("8dd4d132059b72f8e8493f9afb86c6d86263e7439fc64cbb361fcbccf8b01267",
+               output.iter().map(|x| format!("{:02x?}", x)).collect::<String>());
+}
+
+#[test]
+fn test_duplex_aead(){
+    // The batch path runs the permutation outside the duplex, on several states at once
+    for len in [0, 1, 15, 16, 17, 100].iter() {
+        let message: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+        let associated_data = vec![0xAD; *len / 2];
+
+        let mut expected = message.clone();
+        let expected_tag = encrypt_batch_in_place_detached(&mut [GimliAeadBatchItem{
+            key: &KEY,
+            nonce: &NONCE,
+            associated_data: &associated_data,
+            buffer: &mut expected,
+        }])[0];
+
+        let mut cipher_text = message.clone();
+        assert_eq!(expected_tag, encrypt_in_place_detached(&KEY, &NONCE, &associated_data, &mut cipher_text));
+        assert_eq!(expected, cipher_text);
+
+        let mut duplex = GimliDuplex::keyed(&KEY, &NONCE);
+        duplex.absorb(&associated_data);
+        duplex.pad(GimliPadding::AEAD);
+        duplex.decrypt(&mut cipher_text);
+        duplex.pad(GimliPadding::AEAD);
+        let mut tag = [0; 16];
+        duplex.squeeze(&mut tag);
+        assert_eq!(message, cipher_text);
+        assert_eq!(expected_tag.as_bytes(), &tag);
+    }
+}
+
+#[test]
+fn test_split_calls(){
+    let message: Vec<u8> = (0..100u8).collect();
+    for split in [0, 1, 7, RATE, RATE + 3, 99].iter() {
+        let mut whole = GimliDuplex::new();
+        let mut pieces = GimliDuplex::new();
+        whole.absorb(&message);
+        pieces.absorb(&message[..*split]);
+        pieces.absorb(&message[*split..]);
+
+        let mut expected = message.clone();
+        let mut cipher_text = message.clone();
+        whole.encrypt(&mut expected);
+        let (first, second) = cipher_text.split_at_mut(*split);
+        pieces.encrypt(first);
+        pieces.encrypt(second);
+        assert_eq!(expected, cipher_text);
+
+        let mut expected = [0; 50];
+        let mut output = [0; 50];
+        whole.squeeze(&mut expected);
+        pieces.squeeze(&mut output[..split % 50]);
+        pieces.squeeze(&mut output[split % 50..]);
+        assert_eq!(expected[..], output[..]);
+    }
+
+    // The streaming hasher is the same as one duplex call
+    let mut hasher = GimliHasher::new();
+    for chunk in message.chunks(7) {
+        hasher.update(chunk);
+    }
+    let mut duplex = GimliDuplex::new();
+    duplex.absorb(&message);
+    duplex.pad(GimliPadding::HASH);
+    let mut expected = [0; 32];
+    duplex.squeeze(&mut expected);
+    assert_eq!(expected[..], hasher.finalize(32)[..]);
+}
+
+#[test]
+fn test_padding_separates_domains(){
+    let squeeze = |padding: GimliPadding| {
+        let mut duplex = GimliDuplex::new();
+        duplex.absorb(b"input");
+        duplex.pad(padding);
+        let mut output = [0; 16];
+        duplex.squeeze(&mut output);
+        output
+    };
+    let domain = GimliPadding{ domain: 0x02, ..GimliPadding::AEAD };
+    assert_ne!(squeeze(GimliPadding::HASH), squeeze(GimliPadding::AEAD));
+    assert_ne!(squeeze(GimliPadding::AEAD), squeeze(domain));
+}
+
+#[test]
+fn test_ratchet(){
+    let mut sender = GimliDuplex::keyed(&KEY, &NONCE);
+    let mut receiver = sender.clone();
+    let mut plain = sender.clone();
+    sender.ratchet();
+    receiver.ratchet();
+
+    let mut expected = [0; 16];
+    let mut output = [0; 16];
+    plain.squeeze(&mut expected);
+    sender.clone().squeeze(&mut output);
+    assert_ne!([0; 16], output);
+    assert_ne!(expected, output);
+
+    let mut message = *b"a ratcheted message";
+    sender.encrypt(&mut message);
+    assert_ne!(message[..RATE], b"a ratcheted message"[..RATE]);
+    receiver.decrypt(&mut message);
+    assert_eq!(b"a ratcheted message", &message);
+
+    let mut sender_tag = [0; 16];
+    let mut receiver_tag = [0; 16];
+    sender.pad(GimliPadding::AEAD);
+    receiver.pad(GimliPadding::AEAD);
+    sender.squeeze(&mut sender_tag);
+    receiver.squeeze(&mut receiver_tag);
+    assert_eq!(sender_tag, receiver_tag);
+}
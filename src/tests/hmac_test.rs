@@ -0,0 +1,112 @@
+use crate::gimli_digest::GimliHash256;
+use crate::gimli_error::GimliError;
+use crate::gimli_hmac::{hkdf, hkdf_expand, hkdf_extract, GimliHmac, MAX_HKDF_OUTPUT};
+use hkdf::SimpleHkdf;
+use hmac::{Mac, SimpleHmac};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{:02x?}", x)).collect()
+}
+
+// Our own vectors. They were generated by a short Python script written from the Gimli specification,
+// RFC 2104 and RFC 5869, which shares no code with this crate and reproduces the Gimli-Hash vectors in lib.rs.
+// SimpleHmac and SimpleHkdf below run on GimliHasher, so they only check the HMAC and HKDF constructions.
+// The inputs are those of RFC 4231.
+// (Key, message, HMAC)
+fn hmac_vectors() -> Vec<(Vec<u8>, &'static [u8], &'static str)> {
+    vec![
+        (vec![0x0b; 20], b"Hi There", "40aa28f29594b4130fad7e9bd971e07a9afa929a29afa88b93b7a177bcd988cc"),
+        (b"Jefe".to_vec(), b"what do ya want for nothing?", "acc44c3b8026d8e74fa9b6bdb7e5f4075298960d93b07cc6865d58a5d1238208"),
+        (vec![0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First", "dc6e6cd231339592999c8c88f7285de83d08453025573ea56802753c0f0963a7"),
+        (vec![], b"", "305f596f09b3a8964a3396db08ea300fb1079925bf04bd694ac24e0154f203ff"),
+    ]
+}
+
+// The inputs are those of RFC 5869 test cases 1 to 3.
+// (IKM, salt, info, PRK, OKM)
+type HkdfVector = (Vec<u8>, Vec<u8>, Vec<u8>, &'static str, &'static str);
+
+fn hkdf_vectors() -> Vec<HkdfVector> {
+    vec![
+        (vec![0x0b; 22], (0x00..=0x0c).collect(), (0xf0..=0xf9).collect(),
+         "9d8da0623a4d6a31bf2f677832fa606b380389bb5de25e51fed25d0cfe196e70",
+         "bb8839e6ed690a2886de181d1e102e966f6ff7a492fc3f19024d29b69437f7cdc6ed69db030f1292b27f"),
+        ((0x00..=0x4f).collect(), (0x60..=0xaf).collect(), (0xb0..=0xff).collect(),
+         "8d857a5dfe41cf04809266d8d57f8fe3d2adfad8efad65220d18b66883aa0b16",
+         "3d19f235955752e42e94adee35f974d62a9c633fb44cb61291b7891f30055579d8f2b6e143224c33222f8ff5ff0d05a4bdd069b871bbc0a5b9e0f35265e5f644fc145eb333dc1c14521ab88bcf1d9f80e014"),
+        (vec![0x0b; 22], vec![], vec![],
+         "d048a286324ad66a485e072b43464b0d03f497edefcbe93f87df4f51944b9655",
+         "0abf38705701a90084621d9df42e2f24cb5bdf6a8e3a86f5b64cfcd2e245ad83415aba7f7da0f41b89b3"),
+    ]
+}
+
+#[test]
+fn test_hmac_vectors(){
+    for (key, message, expected) in hmac_vectors() {
+        let mut hmac = GimliHmac::new(&key);
+        hmac.update(&message[..message.len() / 2]);
+        hmac.update(&message[message.len() / 2..]);
+        let output = hmac.clone().finalize();
+        assert_eq!(expected, hex(&output));
+        hmac.verify(&output).expect("Tag should verify");
+
+        let mut reference = <SimpleHmac<GimliHash256> as Mac>::new_from_slice(&key).expect("Error creating hmac");
+        reference.update(message);
+        assert_eq!(expected, hex(&reference.finalize().into_bytes()));
+    }
+}
+
+#[test]
+fn test_hmac_verify(){
+    let mut hmac = GimliHmac::new(b"key");
+    hmac.update(b"message");
+    let tag = hmac.clone().finalize();
+    for i in 0..tag.len() * 8 {
+        let mut bad_tag = tag;
+        bad_tag[i / 8] ^= 1 << (i % 8);
+        match hmac.clone().verify(&bad_tag) {
+            Err(GimliError::TagMismatch) => {},
+            other => panic!("Expected tag mismatch, got {:?}", other),
+        }
+    }
+    assert!(hmac.clone().verify(&tag[..16]).is_err());
+    assert!(hmac.verify(&[]).is_err());
+}
+
+#[test]
+fn test_hkdf_vectors(){
+    for (ikm, salt, info, prk, okm) in hkdf_vectors() {
+        let pseudo_random_key = hkdf_extract(&salt, &ikm);
+        assert_eq!(prk, hex(&pseudo_random_key));
+        let mut output = vec![0; okm.len() / 2];
+        hkdf_expand(&pseudo_random_key, &info, &mut output).expect("Error in test expand");
+        assert_eq!(okm, hex(&output));
+        let mut output = vec![0; okm.len() / 2];
+        hkdf(&salt, &ikm, &info, &mut output).expect("Error in test hkdf");
+        assert_eq!(okm, hex(&output));
+
+        let salt = match salt.is_empty() {
+            true => None,
+            false => Some(&salt[..]),
+        };
+        let (reference_prk, reference) = SimpleHkdf::<GimliHash256>::extract(salt, &ikm);
+        assert_eq!(prk, hex(&reference_prk));
+        let mut output = vec![0; okm.len() / 2];
+        reference.expand(&info, &mut output).expect("Error in reference expand");
+        assert_eq!(okm, hex(&output));
+    }
+}
+
+#[test]
+fn test_hkdf_output_length(){
+    let mut output = vec![0; MAX_HKDF_OUTPUT + 1];
+    match hkdf(b"salt", b"ikm", b"info", &mut output) {
+        Err(GimliError::InvalidLength{ expected: MAX_HKDF_OUTPUT, actual }) => assert_eq!(MAX_HKDF_OUTPUT + 1, actual),
+        other => panic!("Expected invalid length, got {:?}", other),
+    }
+
+    hkdf(b"salt", b"ikm", b"info", &mut output[..MAX_HKDF_OUTPUT]).expect("Error in test hkdf");
+    let mut short = [0; 40];
+    hkdf(b"salt", b"ikm", b"info", &mut short).expect("Error in test hkdf");
+    assert_eq!(short[..], output[..40]);
+}
@@ -0,0 +1,66 @@
+use crate::gimli_common::{gimli, gimli_scalar};
+use rand::Rng;
+
+fn random_states() -> Vec<[u32; 12]> {
+    let mut rng = rand::thread_rng();
+    (0..1000).map(|_| rng.gen()).collect()
+}
+
+#[test]
+fn test_dispatch_matches_scalar() {
+    for state in random_states() {
+        let mut expected = state;
+        let mut actual = state;
+        gimli_scalar(&mut expected);
+        gimli(&mut actual);
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_x86_64_matches_scalar() {
+    use crate::gimli_simd::{gimli_avx2, gimli_sse2};
+
+    let avx2 = is_x86_feature_detected!("avx2");
+    for state in random_states() {
+        let mut expected = state;
+        gimli_scalar(&mut expected);
+
+        let mut actual = state;
+        unsafe { gimli_sse2(&mut actual) };
+        assert_eq!(actual, expected);
+
+        if avx2 {
+            let mut actual = state;
+            unsafe { gimli_avx2(&mut actual) };
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+#[test]
+fn test_batches_match_scalar() {
+    use crate::gimli_common::{gimli_x2, gimli_x4, gimli_x8};
+
+    let states = random_states();
+    let mut expected = states.clone();
+    expected.iter_mut().for_each(gimli_scalar);
+
+    for (batch, expected) in states.chunks_exact(2).zip(expected.chunks_exact(2)) {
+        let mut actual = [batch[0], batch[1]];
+        gimli_x2(&mut actual);
+        assert_eq!(actual, expected);
+    }
+    for (batch, expected) in states.chunks_exact(4).zip(expected.chunks_exact(4)) {
+        let mut actual = [batch[0], batch[1], batch[2], batch[3]];
+        gimli_x4(&mut actual);
+        assert_eq!(actual, expected);
+    }
+    for (batch, expected) in states.chunks_exact(8).zip(expected.chunks_exact(8)) {
+        let mut actual = [[0; 12]; 8];
+        actual.copy_from_slice(batch);
+        gimli_x8(&mut actual);
+        assert_eq!(actual, expected);
+    }
+}
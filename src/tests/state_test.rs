@@ -0,0 +1,115 @@
+use crate::gimli_common::{gimli, gimli_inverse, GimliState};
+use rand::Rng;
+
+// Test vector from the gimli paper: state[i] = i * i * i + i * 0x9e3779b9
+const PERMUTATION_INPUT: [u32; 12] = [
+    0x00000000, 0x9e3779ba, 0x3c6ef37a, 0xdaa66d46,
+    0x78dde724, 0x1715611a, 0xb54cdb2e, 0x53845566,
+    0xf1bbcfc8, 0x8ff34a5a, 0x2e2ac522, 0xcc624026,
+];
+const PERMUTATION_OUTPUT: [u32; 12] = [
+    0xba11c85a, 0x91bad119, 0x380ce880, 0xd24c2c68,
+    0x3eceffea, 0x277a921c, 0x4f73a0bd, 0xda5a9cd8,
+    0x84b673f0, 0x34e52ff7, 0x9e2bef49, 0xf41bb8d6,
+];
+
+#[test]
+fn test_permutation_vector() {
+    let mut words = PERMUTATION_INPUT;
+    gimli(&mut words);
+    assert_eq!(words, PERMUTATION_OUTPUT);
+
+    let mut state = GimliState::from_words(PERMUTATION_INPUT);
+    state.permute();
+    assert_eq!(state.words(), &PERMUTATION_OUTPUT);
+}
+
+#[test]
+fn test_byte_order() {
+    let mut state = GimliState::new();
+    state.xor_byte(0, 0x01);
+    state.xor_byte(7, 0x80);
+    state.xor_byte(47, 0xff);
+    assert_eq!(state.words()[0], 0x00000001);
+    assert_eq!(state.words()[1], 0x80000000);
+    assert_eq!(state.words()[11], 0xff000000);
+    assert_eq!(state.byte(7), 0x80);
+
+    let state = GimliState::from_words(PERMUTATION_OUTPUT);
+    let bytes = state.to_bytes();
+    assert_eq!(bytes[..4], [0x5a, 0xc8, 0x11, 0xba]);
+    assert_eq!(bytes[44..], [0xd6, 0xb8, 0x1b, 0xf4]);
+    assert!(GimliState::from_bytes(&bytes) == state);
+}
+
+#[test]
+fn test_duplex_bytes() {
+    let message: Vec<u8> = (0..16).collect();
+    let mut encryptor = GimliState::from_words(PERMUTATION_INPUT);
+    let mut decryptor = encryptor.clone();
+
+    let mut buffer = message.clone();
+    encryptor.encrypt_bytes(0, &mut buffer);
+    let mut rate = [0; 16];
+    encryptor.extract_bytes(0, &mut rate);
+    assert_eq!(buffer, rate);
+
+    decryptor.decrypt_bytes(0, &mut buffer);
+    assert_eq!(buffer, message);
+    assert!(encryptor == decryptor);
+}
+
+#[test]
+fn test_inverse_permutation() {
+    let mut words = PERMUTATION_OUTPUT;
+    gimli_inverse(&mut words);
+    assert_eq!(words, PERMUTATION_INPUT);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let state: [u32; 12] = rng.gen();
+        let mut words = state;
+        gimli(&mut words);
+        gimli_inverse(&mut words);
+        assert_eq!(words, state);
+
+        gimli_inverse(&mut words);
+        gimli(&mut words);
+        assert_eq!(words, state);
+    }
+}
+
+#[test]
+fn test_round_ranges() {
+    use crate::gimli_common::{gimli_inverse_rounds, gimli_rounds};
+
+    let mut words = PERMUTATION_INPUT;
+    gimli_rounds(&mut words, 24, 1);
+    assert_eq!(words, PERMUTATION_OUTPUT);
+
+    let mut words = PERMUTATION_INPUT;
+    gimli_rounds(&mut words, 24, 13);
+    gimli_rounds(&mut words, 12, 5);
+    gimli_rounds(&mut words, 4, 1);
+    assert_eq!(words, PERMUTATION_OUTPUT);
+
+    gimli_inverse_rounds(&mut words, 6, 1);
+    gimli_inverse_rounds(&mut words, 24, 7);
+    assert_eq!(words, PERMUTATION_INPUT);
+
+    gimli_rounds(&mut words, 24, 17);
+    gimli_inverse_rounds(&mut words, 24, 17);
+    assert_eq!(words, PERMUTATION_INPUT);
+}
+
+#[test]
+#[should_panic(expected = "gimli rounds run from start_round down to end_round")]
+fn test_rounds_out_of_order() {
+    crate::gimli_common::gimli_rounds(&mut [0; 12], 3, 5);
+}
+
+#[test]
+#[should_panic(expected = "gimli rounds run from start_round down to end_round")]
+fn test_inverse_rounds_out_of_order() {
+    crate::gimli_common::gimli_inverse_rounds(&mut [0; 12], 3, 5);
+}
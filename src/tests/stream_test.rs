@@ -0,0 +1,273 @@
+use crate::gimli_common::{Key, Nonce};
+use crate::gimli_decrypt::GimliAeadDecryptIter;
+use crate::gimli_encrypt::gimli_aead_encrypt;
+use crate::gimli_error::GimliError;
+use crate::gimli_stream::{GimliDecryptReader, GimliEncryptWriter, GimliStreamDecryptIter, GimliStreamDecryptor, GimliStreamEncryptIter, GimliStreamEncryptor, SEGMENT_SIZE};
+use std::io::{self, Read, Write};
+
+const KEY: Key = Key::new([0x42; 32]);
+const NONCE_PREFIX: [u8; 11] = [0x24; 11];
+
+fn encrypt(message: Vec<u8>, ad: &[u8]) -> Vec<Vec<u8>> {
+    GimliStreamEncryptIter::new(&KEY, &NONCE_PREFIX, Box::new(message.into_iter().map(Ok)), ad)
+        .collect::<Result<_, _>>()
+        .expect("Error in test encryption")
+}
+
+fn decrypt(cipher_text: Vec<u8>, ad: &[u8]) -> Vec<Result<Vec<u8>, GimliError>> {
+    GimliStreamDecryptIter::new(&KEY, &NONCE_PREFIX, Box::new(cipher_text.into_iter().map(Ok)), ad).collect()
+}
+
+#[test]
+fn test_stream_roundtrip(){
+    for len in [0, 1, 15, SEGMENT_SIZE - 1, SEGMENT_SIZE, SEGMENT_SIZE + 1, 3 * SEGMENT_SIZE + 5].iter() {
+        let message: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+        let segments = encrypt(message.clone(), b"ad");
+        assert_eq!(std::cmp::max(1, len.div_ceil(SEGMENT_SIZE)), segments.len());
+
+        let plain_text: Vec<u8> = decrypt(segments.concat(), b"ad")
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Error in test decryption")
+            .concat();
+        assert_eq!(message, plain_text);
+    }
+}
+
+#[test]
+fn test_stream_rejects_modification(){
+    let message = vec![7u8; 3 * SEGMENT_SIZE];
+    let segments = encrypt(message.clone(), b"ad");
+    assert_eq!(3, segments.len());
+
+    // Tampering with the second segment releases only the first
+    let mut tampered = segments.clone();
+    tampered[1][10] ^= 1;
+    let results = decrypt(tampered.concat(), b"ad");
+    assert_eq!(2, results.len());
+    assert_eq!(message[..SEGMENT_SIZE], results[0].as_ref().expect("First segment should verify")[..]);
+    match results[1] {
+        Err(GimliError::TagMismatch) => {},
+        ref other => panic!("Expected tag mismatch, got {:?}", other),
+    }
+
+    // Dropping the final segment is detected
+    let results = decrypt(segments[..2].concat(), b"ad");
+    assert!(results.last().expect("Expected a result").is_err());
+
+    // Reordering segments is detected
+    let reordered = [segments[1].clone(), segments[0].clone(), segments[2].clone()].concat();
+    assert!(decrypt(reordered, b"ad")[0].is_err());
+
+    // Wrong associated data is detected
+    assert!(decrypt(segments.concat(), b"other")[0].is_err());
+
+    match decrypt(segments[0][..10].to_vec(), b"ad")[0] {
+        Err(GimliError::TruncatedCiphertext) => {},
+        ref other => panic!("Expected truncated cipher text, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_decrypt_iter_withholds_unverified_plaintext(){
+    let message = vec![1u8; 100];
+    let mut cipher_text = gimli_aead_encrypt(&KEY, &Nonce::new([0; 16]), &[], message.clone().into_iter().map(Ok), message.len())
+        .expect("Error in test encryption");
+    let last = cipher_text.len() - 1;
+    cipher_text[last] ^= 1;
+
+    let mut plain_text = GimliAeadDecryptIter::new(&KEY, &Nonce::new([0; 16]), &[], Box::new(cipher_text.clone().into_iter().map(Ok)), cipher_text.len())
+        .expect("Error in test decryption");
+    match plain_text.next() {
+        Some(Err(GimliError::TagMismatch)) => {},
+        other => panic!("Expected tag mismatch before any plaintext, got {:?}", other),
+    }
+    assert!(plain_text.next().is_none());
+}
+
+#[test]
+fn test_writer_reader_roundtrip(){
+    for len in [0, 10, SEGMENT_SIZE, SEGMENT_SIZE + 1, 5 * SEGMENT_SIZE - 3].iter() {
+        let message: Vec<u8> = (0..*len).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = GimliEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, b"ad");
+        for chunk in message.chunks(777) {
+            writer.write_all(chunk).expect("Error in test encryption");
+        }
+        let cipher_text = writer.finish().expect("Error in test encryption");
+        assert_eq!(encrypt(message.clone(), b"ad").concat(), cipher_text);
+
+        let mut reader = GimliDecryptReader::new(&cipher_text[..], &KEY, &NONCE_PREFIX, b"ad");
+        let mut plain_text = Vec::new();
+        io::copy(&mut reader, &mut plain_text).expect("Error in test decryption");
+        assert_eq!(message, plain_text);
+
+        let mut reader = GimliDecryptReader::new(&cipher_text[..], &KEY, &NONCE_PREFIX, b"ad");
+        let mut byte = [0; 1];
+        let mut plain_text = Vec::new();
+        while reader.read(&mut byte).expect("Error in test decryption") == 1 {
+            plain_text.push(byte[0]);
+        }
+        assert_eq!(message, plain_text);
+    }
+}
+
+#[test]
+fn test_reader_surfaces_authentication_failure(){
+    let message = vec![3u8; 2 * SEGMENT_SIZE + 1];
+    let mut writer = GimliEncryptWriter::new(Vec::new(), &KEY, &NONCE_PREFIX, &[]);
+    io::copy(&mut &message[..], &mut writer).expect("Error in test encryption");
+    let mut cipher_text = writer.finish().expect("Error in test encryption");
+    cipher_text[SEGMENT_SIZE + 20] ^= 1;
+
+    let mut reader = GimliDecryptReader::new(&cipher_text[..], &KEY, &NONCE_PREFIX, &[]);
+    let mut plain_text = Vec::new();
+    let error = reader.read_to_end(&mut plain_text).expect_err("Tampered stream should fail");
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(message[..SEGMENT_SIZE], plain_text[..]);
+    assert!(reader.read(&mut [0; 16]).is_err());
+
+    // A stream cut off before its last segment
+    let mut unfinished = Vec::new();
+    for segment in encrypt(message.clone(), &[]).iter().take(2) {
+        unfinished.extend_from_slice(segment);
+    }
+    let mut reader = GimliDecryptReader::new(&unfinished[..], &KEY, &NONCE_PREFIX, &[]);
+    assert!(reader.read_to_end(&mut Vec::new()).is_err());
+}
+
+#[test]
+fn test_counter_overflow_ends_stream(){
+    let message = vec![6u8; 32];
+
+    let mut encryptor = GimliStreamEncryptor::with_counter(&KEY, &NONCE_PREFIX, u32::MAX);
+    let mut buffer = message.clone();
+    match encryptor.encrypt_segment(&mut buffer, &[], false) {
+        Err(GimliError::StreamFinished) => {},
+        other => panic!("Expected finished stream, got {:?}", other),
+    }
+    assert_eq!(message, buffer);
+    assert!(encryptor.encrypt_segment(&mut buffer, &[], true).is_err());
+    assert_eq!(message, buffer);
+
+    // The final counter value is still available to the last segment
+    let mut encryptor = GimliStreamEncryptor::with_counter(&KEY, &NONCE_PREFIX, u32::MAX);
+    let tag = encryptor.encrypt_segment(&mut buffer, &[], true).expect("Error in test encryption");
+    let mut decryptor = GimliStreamDecryptor::with_counter(&KEY, &NONCE_PREFIX, u32::MAX);
+    assert!(decryptor.decrypt_segment(&mut buffer.clone(), &[], &tag, false).is_err());
+    let mut decryptor = GimliStreamDecryptor::with_counter(&KEY, &NONCE_PREFIX, u32::MAX);
+    decryptor.decrypt_segment(&mut buffer, &[], &tag, true).expect("Error in test decryption");
+    assert_eq!(message, buffer);
+}
+
+#[test]
+fn test_decryptor_stops_after_failure(){
+    let mut encryptor = GimliStreamEncryptor::new(&KEY, &NONCE_PREFIX);
+    let mut first = vec![1u8; 20];
+    let mut second = vec![2u8; 20];
+    let first_tag = encryptor.encrypt_segment(&mut first, &[], false).expect("Error in test encryption");
+    let second_tag = encryptor.encrypt_segment(&mut second, &[], true).expect("Error in test encryption");
+
+    let mut decryptor = GimliStreamDecryptor::new(&KEY, &NONCE_PREFIX);
+    assert!(decryptor.decrypt_segment(&mut first.clone(), &[], &second_tag, false).is_err());
+    match decryptor.decrypt_segment(&mut first, &[], &first_tag, false) {
+        Err(GimliError::StreamFinished) => {},
+        other => panic!("Expected finished stream, got {:?}", other),
+    }
+}
+
+// Accepts at most `limit` bytes per call and fails the call after `fail_after` bytes have been written
+struct FlakyWriter{
+    written: Vec<u8>,
+    limit: usize,
+    fail_after: Option<usize>,
+}
+
+impl Write for FlakyWriter{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>{
+        if let Some(fail_after) = self.fail_after {
+            if self.written.len() >= fail_after {
+                self.fail_after = None;
+                return Err(io::ErrorKind::WouldBlock.into())
+            }
+        }
+        let take = std::cmp::min(buf.len(), self.limit);
+        self.written.extend_from_slice(&buf[..take]);
+        Ok(take)
+    }
+
+    fn flush(&mut self) -> io::Result<()>{
+        Ok(())
+    }
+}
+
+#[test]
+fn test_writer_retries_after_inner_failure(){
+    let message: Vec<u8> = (0..3 * SEGMENT_SIZE + 10).map(|i| (i % 251) as u8).collect();
+    for fail_after in [0, 1000, SEGMENT_SIZE + 16, SEGMENT_SIZE + 100].iter() {
+        let flaky = FlakyWriter{ written: Vec::new(), limit: 1000, fail_after: Some(*fail_after) };
+        let mut writer = GimliEncryptWriter::new(flaky, &KEY, &NONCE_PREFIX, b"ad");
+        let mut failures = 0;
+        let mut remaining = &message[..];
+        while !remaining.is_empty() {
+            match writer.write(remaining) {
+                Ok(n) => remaining = &remaining[n..],
+                Err(e) => {
+                    assert_eq!(io::ErrorKind::WouldBlock, e.kind());
+                    failures += 1;
+                },
+            }
+        }
+        assert_eq!(1, failures);
+        let cipher_text = writer.finish().expect("Error in test encryption").written;
+        assert_eq!(encrypt(message.clone(), b"ad").concat(), cipher_text);
+    }
+}
+
+// Returns at most `limit` bytes per call and fails the call after `fail_after` bytes have been read
+struct FlakyReader<'a>{
+    data: &'a [u8],
+    read: usize,
+    limit: usize,
+    fail_after: Option<usize>,
+}
+
+impl Read for FlakyReader<'_>{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>{
+        if let Some(fail_after) = self.fail_after {
+            if self.read >= fail_after {
+                self.fail_after = None;
+                return Err(io::ErrorKind::TimedOut.into())
+            }
+        }
+        let take = std::cmp::min(std::cmp::min(buf.len(), self.limit), self.data.len() - self.read);
+        buf[..take].copy_from_slice(&self.data[self.read..self.read + take]);
+        self.read += take;
+        Ok(take)
+    }
+}
+
+#[test]
+fn test_reader_retries_after_inner_failure(){
+    let message: Vec<u8> = (0..2 * SEGMENT_SIZE + 10).map(|i| (i % 251) as u8).collect();
+    let cipher_text = encrypt(message.clone(), b"ad").concat();
+    for fail_after in [0, 500, SEGMENT_SIZE + 16, SEGMENT_SIZE + 17, cipher_text.len()].iter() {
+        let flaky = FlakyReader{ data: &cipher_text, read: 0, limit: 500, fail_after: Some(*fail_after) };
+        let mut reader = GimliDecryptReader::new(flaky, &KEY, &NONCE_PREFIX, b"ad");
+        let mut failures = 0;
+        let mut plain_text = Vec::new();
+        let mut chunk = [0; 700];
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => plain_text.extend_from_slice(&chunk[..n]),
+                Err(e) => {
+                    assert_eq!(io::ErrorKind::TimedOut, e.kind());
+                    failures += 1;
+                },
+            }
+        }
+        assert_eq!(1, failures);
+        assert_eq!(message, plain_text);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::gimli_common::MIN_TAG_SIZE;
+use crate::gimli_error::GimliError;
+use crate::gimli_strobe::{GimliStrobe, GimliStrobeRole};
+
+const PROTOCOL: &[u8] = b"strobe test protocol";
+const KEY: [u8; 32] = [0x42; 32];
+
+fn keyed_pair() -> (GimliStrobe, GimliStrobe) {
+    let mut alice = GimliStrobe::new(PROTOCOL);
+    let mut bob = GimliStrobe::new(PROTOCOL);
+    alice.key(&KEY, false);
+    bob.key(&KEY, false);
+    (alice, bob)
+}
+
+#[test]
+fn test_two_party_roundtrip(){
+    let (mut alice, mut bob) = keyed_pair();
+    assert_eq!(None, alice.role());
+
+    // Alice sends an encrypted and authenticated message
+    let mut message = *b"hello bob, this is a message longer than one block";
+    alice.send_enc(&mut message, false);
+    assert_ne!(&message[..], &b"hello bob, this is a message longer than one block"[..]);
+    let mut tag = [0; 16];
+    alice.send_mac(&mut tag);
+    bob.recv_enc(&mut message, false);
+    bob.recv_mac(&tag).expect("Tag should verify");
+    assert_eq!(&message[..], &b"hello bob, this is a message longer than one block"[..]);
+    assert_eq!(Some(GimliStrobeRole::Initiator), alice.role());
+    assert_eq!(Some(GimliStrobeRole::Responder), bob.role());
+
+    // Bob replies in the clear in two pieces, then both ratchet and agree on a session key
+    bob.send_clr(b"re", false);
+    bob.send_clr(b"ply", true);
+    alice.recv_clr(b"reply", false);
+    let mut tag = [0; 32];
+    bob.send_mac(&mut tag);
+    alice.recv_mac(&tag).expect("Tag should verify");
+    alice.ratchet();
+    bob.ratchet();
+
+    let mut alice_key = [0; 32];
+    let mut bob_key = [0; 32];
+    alice.prf(&mut alice_key, false);
+    bob.prf(&mut bob_key, false);
+    assert_eq!(alice_key, bob_key);
+}
+
+#[test]
+fn test_diverged_transcripts(){
+    // Different associated data
+    let (mut alice, mut bob) = keyed_pair();
+    alice.ad(b"context", false);
+    bob.ad(b"other", false);
+    let mut tag = [0; 16];
+    alice.send_mac(&mut tag);
+    match bob.recv_mac(&tag) {
+        Err(GimliError::TagMismatch) => {},
+        other => panic!("Expected tag mismatch, got {:?}", other),
+    }
+
+    // A modified message
+    let (mut alice, mut bob) = keyed_pair();
+    let mut message = [7; 20];
+    alice.send_enc(&mut message, false);
+    alice.send_mac(&mut tag);
+    message[3] ^= 1;
+    bob.recv_enc(&mut message, false);
+    assert!(bob.recv_mac(&tag).is_err());
+
+    // Once the roles are settled, the responder sending out of turn does not match the initiator
+    let (mut alice, mut bob) = keyed_pair();
+    alice.send_clr(b"hello", false);
+    bob.recv_clr(b"hello", false);
+    alice.send_mac(&mut tag);
+    let mut other = [0; 16];
+    bob.send_mac(&mut other);
+    assert_ne!(tag, other);
+
+    // Every bit of a long tag counts, and a truncated tag never verifies
+    let (mut alice, bob) = keyed_pair();
+    let mut tag = [0; 40];
+    alice.send_mac(&mut tag);
+    for i in 0..tag.len() * 8 {
+        let mut tampered = tag;
+        tampered[i / 8] ^= 1 << (i % 8);
+        assert!(bob.clone().recv_mac(&tampered).is_err());
+    }
+    bob.clone().recv_mac(&tag).expect("Tag should verify");
+    for len in 0..MIN_TAG_SIZE {
+        let (mut alice, mut bob) = keyed_pair();
+        let mut short = vec![0; len];
+        alice.send_mac(&mut short);
+        match bob.recv_mac(&short) {
+            Err(GimliError::TagMismatch) => {},
+            other => panic!("Expected tag mismatch, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_framing(){
+    let prf = |strobe: &mut GimliStrobe| {
+        let mut output = [0; 16];
+        strobe.prf(&mut output, false);
+        output
+    };
+
+    // A responder may continue a transport operation too
+    let (mut alice, mut bob) = keyed_pair();
+    let mut message = *b"split message";
+    alice.send_enc(&mut message, false);
+    bob.recv_enc(&mut message[..5], false);
+    bob.recv_enc(&mut message[5..], true);
+    assert_eq!(b"split message", &message);
+    alice.send_clr(b"clear", false);
+    bob.recv_clr(b"cl", false);
+    bob.recv_clr(b"ear", true);
+    let mut tag = [0; 16];
+    alice.send_mac(&mut tag);
+    bob.recv_mac(&tag).expect("Tag should verify");
+
+    // Continuing an operation is the same as one call
+    let mut whole = GimliStrobe::new(PROTOCOL);
+    let mut pieces = GimliStrobe::new(PROTOCOL);
+    whole.ad(b"associated data", false);
+    pieces.ad(b"associated", false);
+    pieces.ad(b" data", true);
+    assert_eq!(prf(&mut whole), prf(&mut pieces));
+
+    let mut expected = [0; 16];
+    let mut output = [0; 16];
+    whole.prf(&mut expected, false);
+    pieces.prf(&mut output[..5], false);
+    pieces.prf(&mut output[5..], true);
+    assert_eq!(expected, output);
+
+    // Splitting data across operations, or changing the kind of operation, changes the transcript
+    let mut split = GimliStrobe::new(PROTOCOL);
+    split.ad(b"associated", false);
+    split.ad(b" data", false);
+    let mut meta = GimliStrobe::new(PROTOCOL);
+    meta.meta_ad(b"associated data", false);
+    let mut keyed = GimliStrobe::new(PROTOCOL);
+    keyed.key(b"associated data", false);
+    let mut reference = GimliStrobe::new(PROTOCOL);
+    reference.ad(b"associated data", false);
+    let reference = prf(&mut reference);
+    assert_ne!(reference, prf(&mut split));
+    assert_ne!(reference, prf(&mut meta));
+    assert_ne!(reference, prf(&mut keyed));
+
+    // As does the protocol name
+    let mut other = GimliStrobe::new(b"another protocol");
+    other.ad(b"associated data", false);
+    assert_ne!(reference, prf(&mut other));
+}
+
+#[test]
+#[should_panic(expected = "more requires the same operation")]
+fn test_more_requires_same_operation(){
+    let mut strobe = GimliStrobe::new(PROTOCOL);
+    strobe.ad(b"data", false);
+    strobe.key(&KEY, true);
+}
@@ -0,0 +1,122 @@
+use crate::gimli_aead::GimliCipher;
+use crate::gimli_common::{Key, Nonce, Tag, MIN_TAG_SIZE};
+use crate::gimli_decrypt::{decrypt_in_place_detached, gimli_aead_decrypt, GimliAeadDecryptIter, GimliAeadDecryptor};
+use crate::gimli_encrypt::encrypt_in_place_detached;
+use crate::gimli_error::GimliError;
+use crate::gimli_stream::{GimliStreamDecryptor, GimliStreamEncryptor};
+use crate::GimliMac;
+use aead::{AeadInPlace, KeyInit};
+
+const KEY: Key = Key::new([0x11; 32]);
+const NONCE: Nonce = Nonce::new([0x22; 16]);
+const AD: &[u8] = b"associated data";
+
+fn message() -> Vec<u8> {
+    (0..45).collect()
+}
+
+// Every single bit flip in the tag, at every byte position.
+fn bad_tags(tag: Tag) -> impl Iterator<Item = Tag> {
+    (0..16).flat_map(move |position| (0..8).map(move |bit| {
+        let mut bad_tag = *tag.as_bytes();
+        bad_tag[position] ^= 1 << bit;
+        Tag::new(bad_tag)
+    }))
+}
+
+fn encrypt() -> (Vec<u8>, Tag) {
+    let mut cipher_text = message();
+    let tag = encrypt_in_place_detached(&KEY, &NONCE, AD, &mut cipher_text);
+    (cipher_text, tag)
+}
+
+fn assert_tag_mismatch(result: Result<(), GimliError>) {
+    match result {
+        Err(GimliError::TagMismatch) => {}
+        other => panic!("Expected tag mismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_in_place_rejects_bad_tags() {
+    let (cipher_text, tag) = encrypt();
+    for bad_tag in bad_tags(tag) {
+        let mut buffer = cipher_text.clone();
+        assert_tag_mismatch(decrypt_in_place_detached(&KEY, &NONCE, AD, &mut buffer, &bad_tag));
+        assert!(buffer.iter().all(|b| *b == 0));
+    }
+    let mut buffer = cipher_text;
+    decrypt_in_place_detached(&KEY, &NONCE, AD, &mut buffer, &tag).expect("Error in test decryption");
+    assert_eq!(buffer, message());
+}
+
+#[test]
+fn test_block_api_rejects_bad_tags() {
+    let (cipher_text, tag) = encrypt();
+    for bad_tag in bad_tags(tag) {
+        let mut buffer = cipher_text.clone();
+        let mut decryptor = GimliAeadDecryptor::new(&KEY, &NONCE, AD);
+        decryptor.decrypt_blocks(&mut buffer[..32]);
+        assert_tag_mismatch(decryptor.finalize(&mut buffer, &bad_tag));
+        assert!(buffer.iter().all(|b| *b == 0));
+    }
+}
+
+#[test]
+fn test_vec_and_iter_reject_bad_tags() {
+    let (cipher_text, tag) = encrypt();
+    for bad_tag in bad_tags(tag) {
+        let mut input = cipher_text.clone();
+        input.extend_from_slice(bad_tag.as_bytes());
+
+        let result = gimli_aead_decrypt(&KEY, &NONCE, AD, input.clone().into_iter().map(Ok), input.len());
+        assert_tag_mismatch(result.map(|_| ()));
+
+        let mut iter = GimliAeadDecryptIter::new(&KEY, &NONCE, AD, Box::new(input.clone().into_iter().map(Ok)), input.len())
+            .expect("Error creating test iterator");
+        assert_tag_mismatch(iter.next().expect("Expected an error item").map(|_| ()));
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn test_aead_trait_rejects_bad_tags() {
+    let (cipher_text, tag) = encrypt();
+    let cipher = GimliCipher::new(&(*KEY.as_bytes()).into());
+    for bad_tag in bad_tags(tag) {
+        let mut buffer = cipher_text.clone();
+        assert!(cipher.decrypt_in_place_detached(&(*NONCE.as_bytes()).into(), AD, &mut buffer, &(*bad_tag.as_bytes()).into()).is_err());
+        assert!(buffer.iter().all(|b| *b == 0));
+    }
+}
+
+#[test]
+fn test_stream_rejects_bad_tags() {
+    let prefix = [0x33; 11];
+    let mut cipher_text = message();
+    let tag = GimliStreamEncryptor::new(&KEY, &prefix)
+        .encrypt_segment(&mut cipher_text, AD, true)
+        .expect("Error in test encryption");
+    for bad_tag in bad_tags(tag) {
+        let mut buffer = cipher_text.clone();
+        let mut decryptor = GimliStreamDecryptor::new(&KEY, &prefix);
+        assert_tag_mismatch(decryptor.decrypt_segment(&mut buffer, AD, &bad_tag, true));
+        assert!(buffer.iter().all(|b| *b == 0));
+    }
+}
+
+#[test]
+fn test_mac_rejects_bad_tags() {
+    let mut mac = GimliMac::new(&KEY);
+    mac.update(&message());
+    let mut tag = [0; 16];
+    mac.clone().finalize_into(&mut tag);
+    for bad_tag in bad_tags(Tag::new(tag)) {
+        assert_tag_mismatch(mac.clone().verify(bad_tag.as_bytes()));
+    }
+    // A correct prefix shorter than MIN_TAG_SIZE would be easy to guess
+    for len in 0..MIN_TAG_SIZE {
+        assert_tag_mismatch(mac.clone().verify(&tag[..len]));
+    }
+    mac.verify(&tag).expect("Tag should verify");
+}